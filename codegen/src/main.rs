@@ -0,0 +1,137 @@
+//! Prints a `Row` struct for a table, either described by a server
+//! or defined in a file with a `CREATE TABLE` statement.
+
+use std::{env, fs, process};
+
+use clickhouse::{
+    codegen::{parse_create_table, DecimalCrate, RowCodegen, TimeCrate},
+    error::Result,
+    types::Column,
+    Client,
+};
+
+const USAGE: &str = "\
+Usage: clickhouse-codegen [OPTIONS] <DATABASE.TABLE | FILE.sql>
+
+Options:
+  --url <URL>              ClickHouse URL [default: http://localhost:8123]
+  --user <USER>            ClickHouse user
+  --password <PASSWORD>    ClickHouse password
+  --name <NAME>            The name of the struct [default: the table name in PascalCase]
+  --borrowed               Generate `&'a str` fields instead of `String`
+  --time <CRATE>           time, chrono or jiff [default: time]
+  --decimal <CRATE>        rust_decimal or bigdecimal [default: rust_decimal]";
+
+#[derive(Default)]
+struct Args {
+    source: String,
+    url: Option<String>,
+    user: Option<String>,
+    password: Option<String>,
+    name: Option<String>,
+    borrowed: bool,
+    time_crate: TimeCrate,
+    decimal_crate: DecimalCrate,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut iter = env::args().skip(1);
+
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or(format!("{arg} requires a value"));
+
+        match &*arg {
+            "--url" => args.url = Some(value()?),
+            "--user" => args.user = Some(value()?),
+            "--password" => args.password = Some(value()?),
+            "--name" => args.name = Some(value()?),
+            "--borrowed" => args.borrowed = true,
+            "--time" => {
+                args.time_crate = match &*value()? {
+                    "time" => TimeCrate::Time,
+                    "chrono" => TimeCrate::Chrono,
+                    "jiff" => TimeCrate::Jiff,
+                    other => return Err(format!("unknown time crate {other}")),
+                }
+            }
+            "--decimal" => {
+                args.decimal_crate = match &*value()? {
+                    "rust_decimal" => DecimalCrate::RustDecimal,
+                    "bigdecimal" => DecimalCrate::BigDecimal,
+                    other => return Err(format!("unknown decimal crate {other}")),
+                }
+            }
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {arg}")),
+            _ if args.source.is_empty() => args.source = arg,
+            _ => return Err(format!("unexpected argument {arg}")),
+        }
+    }
+
+    if args.source.is_empty() {
+        return Err("a table or a file is required".into());
+    }
+
+    Ok(args)
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| part[..1].to_ascii_uppercase() + &part[1..])
+        .collect()
+}
+
+async fn columns(args: &Args) -> Result<(String, Vec<Column>)> {
+    if let Some(stem) = args.source.strip_suffix(".sql") {
+        let sql = fs::read_to_string(&args.source).map_err(clickhouse::error::Error::from)?;
+        let table = stem.rsplit(['/', '\\']).next().unwrap_or(stem);
+        return Ok((table.into(), parse_create_table(&sql)?));
+    }
+
+    let (database, table) = args
+        .source
+        .split_once('.')
+        .unwrap_or(("default", &args.source));
+
+    let mut client =
+        Client::default().with_url(args.url.as_deref().unwrap_or("http://localhost:8123"));
+    if let Some(user) = &args.user {
+        client = client.with_user(user);
+    }
+    if let Some(password) = &args.password {
+        client = client.with_password(password);
+    }
+
+    let columns = client.describe_table(database, table).await?;
+    Ok((
+        table.into(),
+        columns.into_iter().map(Column::from).collect(),
+    ))
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let args = parse_args().unwrap_or_else(|err| {
+        if !err.is_empty() {
+            eprintln!("error: {err}\n");
+        }
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    let (table, columns) = columns(&args).await.unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+
+    let name = args.name.clone().unwrap_or_else(|| pascal_case(&table));
+    let code = RowCodegen::new(name, columns)
+        .borrowed(args.borrowed)
+        .with_time_crate(args.time_crate)
+        .with_decimal_crate(args.decimal_crate)
+        .generate();
+
+    print!("{code}");
+}
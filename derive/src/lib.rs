@@ -6,8 +6,10 @@ use serde_derive_internals::{
 };
 use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Error, Fields, Lifetime, Result};
 
+mod migrations;
 #[cfg(test)]
 mod tests;
+mod variant;
 
 fn column_names(data: &DataStruct, cx: &Ctxt, container: &Container) -> Result<TokenStream> {
     Ok(match &data.fields {
@@ -47,6 +49,35 @@ pub fn row(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .into()
 }
 
+/// Orders variants of an enum as the types of ClickHouse `Variant(..)`, so that they are
+/// written with proper discriminators, and renames them to the types, so that they are
+/// read by the types regardless of the order.
+///
+/// The type of a variant is specified by `#[clickhouse(data_type = "..")]`;
+/// otherwise, the name of the variant is used, e.g. `String` or `UInt64`.
+/// The attribute must be placed before `#[derive(Serialize, Deserialize)]`.
+/// Neither the enum nor its variants can be renamed by `#[serde(rename)]`.
+#[proc_macro_attribute]
+pub fn variant(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    variant::variant_impl(attr.into(), input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Embeds migrations from a directory, relative to `Cargo.toml`, into the binary.
+///
+/// Expands to `clickhouse::migrations::Migrator::from_files([..])`.
+#[proc_macro]
+pub fn embed_migrations(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    migrations::embed_migrations_impl(input.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 fn row_impl(input: DeriveInput) -> Result<TokenStream> {
     let cx = Ctxt::new();
     let container = Container::from_ast(&cx, &input);
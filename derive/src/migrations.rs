@@ -0,0 +1,45 @@
+use std::{env, fs, path::PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, LitStr, Result};
+
+pub(crate) fn embed_migrations_impl(input: TokenStream) -> Result<TokenStream> {
+    let dir: LitStr = syn::parse2(input)?;
+
+    let Some(manifest_dir) = env::var_os("CARGO_MANIFEST_DIR") else {
+        let reason = "`CARGO_MANIFEST_DIR` is not set";
+        return Err(Error::new(Span::call_site(), reason));
+    };
+
+    let path = PathBuf::from(manifest_dir).join(dir.value());
+    let read_error = |err| Error::new(dir.span(), format!("cannot read {}: {err}", path.display()));
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(&path).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        if !path.is_file() || path.extension().map_or(true, |ext| ext != "sql") {
+            continue;
+        }
+
+        let (Some(file_name), Some(full_path)) = (
+            path.file_name().and_then(|name| name.to_str()),
+            path.to_str(),
+        ) else {
+            let reason = format!("non UTF-8 path {}", path.display());
+            return Err(Error::new(dir.span(), reason));
+        };
+
+        files.push((file_name.to_string(), full_path.to_string()));
+    }
+
+    files.sort();
+    let file_names = files.iter().map(|(file_name, _)| file_name);
+    let full_paths = files.iter().map(|(_, full_path)| full_path);
+
+    Ok(quote! {
+        clickhouse::migrations::Migrator::from_files([
+            #( (#file_names, ::core::include_str!(#full_paths)), )*
+        ])
+    })
+}
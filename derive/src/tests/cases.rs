@@ -1,10 +1,10 @@
-//! # Snapshot tests for `Row` derive macro.
+//! # Snapshot tests for `Row` derive and `variant` attribute macros.
 //!
 //! - This module contains happy path tests. For failures, see `tests/ui/`.
 //! - These tests are supposed to be used as intermediate checks for complex
 //!   code generation, not as a final integration tests.
 
-use super::render;
+use super::{render, render_variant};
 
 #[test]
 fn simple_owned_row() {
@@ -119,3 +119,33 @@ fn serde_skip_deserializing() {
         }
     };
 }
+
+#[test]
+fn variant_sorted_by_types() {
+    render_variant! {
+        #[derive(Serialize, Deserialize)]
+        enum Sample {
+            UInt64(u64),
+            #[clickhouse(data_type = "Array(UInt16)")]
+            Numbers(Vec<u16>),
+            #[serde(with = "clickhouse::serde::time::date")]
+            Date(time::Date),
+            Int16(i16),
+            Int128(i128),
+        }
+    };
+}
+
+#[test]
+fn variant_with_serde_options() {
+    render_variant! {
+        #[derive(Serialize, Deserialize)]
+        enum Sample {
+            #[serde(rename_all = "camelCase")]
+            #[clickhouse(data_type = "Tuple(a UInt32, b String)")]
+            Pair { a: u32, b: String },
+            #[serde(with = "renamed::date", alias = "rename")]
+            Date(time::Date),
+        }
+    };
+}
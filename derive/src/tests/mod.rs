@@ -5,25 +5,43 @@ mod cases;
 
 macro_rules! render {
     ($($input:tt)*) => {
+        $crate::tests::render_with!($crate::row_impl, $($input)*)
+    };
+}
+
+macro_rules! render_variant {
+    ($($input:tt)*) => {
+        $crate::tests::render_with!(
+            |input| $crate::variant::variant_impl(::proc_macro2::TokenStream::new(), input),
+            $($input)*
+        )
+    };
+}
+
+macro_rules! render_with {
+    ($expand:expr, $($input:tt)*) => {
         ::insta::with_settings!({
             prepend_module_to_snapshot => false,
             omit_expression => true,
         }, {
             let input = ::std::stringify!($($input)*);
-            let output = $crate::tests::_do_render(input);
+            let output = $crate::tests::_do_render(input, $expand);
             ::insta::assert_snapshot!(output);
         })
     };
 }
 
-fn _do_render(input_ugly: &str) -> String {
+fn _do_render(
+    input_ugly: &str,
+    expand: impl FnOnce(syn::DeriveInput) -> syn::Result<TokenStream>,
+) -> String {
     let input_file_ast = syn::parse_file(input_ugly).expect("failed to parse input as file");
     let input_pretty = prettyplease::unparse(&input_file_ast);
     let input_tokens = TokenStream::from_str(input_ugly).expect("invalid input tokens");
     let input_derive_ast = syn::parse2(input_tokens).expect("failed to parse input");
 
-    let output_tokens = crate::row_impl(input_derive_ast)
-        .expect("failed to generate `impl Row`, use tests/ui for such tests");
+    let output_tokens =
+        expand(input_derive_ast).expect("failed to expand the macro, use tests/ui for such tests");
     let output_ugly = output_tokens.to_string();
     let output_file_ast = syn::parse_file(&output_ugly).expect("failed to parse output as file");
     let output_pretty = prettyplease::unparse(&output_file_ast);
@@ -31,4 +49,4 @@ fn _do_render(input_ugly: &str) -> String {
     format!("\n{input_pretty}\n/****** GENERATED ******/\n{output_pretty}")
 }
 
-use render;
+use {render, render_variant, render_with};
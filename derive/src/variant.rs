@@ -0,0 +1,141 @@
+use clickhouse_types::data_types::DataTypeNode;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parenthesized, parse_quote, token, Attribute, Data, DeriveInput, Error, Expr, LitStr, Result,
+    Token,
+};
+
+/// The maximum number of types in `Variant`.
+const MAX_TYPES: usize = 255;
+
+/// The serde name of enums marked by the attribute, so that their variants are read by the types.
+/// Must match `VARIANT_ENUM_NAME` in `clickhouse::rowbinary::de`.
+const ENUM_NAME: &str = "$clickhouse::Variant";
+
+pub(crate) fn variant_impl(attr: TokenStream, mut input: DeriveInput) -> Result<TokenStream> {
+    if !attr.is_empty() {
+        let reason = "`variant` doesn't take arguments";
+        return Err(Error::new_spanned(attr, reason));
+    }
+
+    // Derives placed before the attribute have already seen the original enum.
+    if !input
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("derive"))
+    {
+        let reason = "`variant` must be placed before `#[derive(Serialize, Deserialize)]`";
+        return Err(Error::new(input.ident.span(), reason));
+    }
+
+    for attr in &input.attrs {
+        if is_serde_rename(attr)? {
+            let reason = "`serde(rename)` conflicts with `variant`, \
+                which uses the name of the enum to read variants by their types";
+            return Err(Error::new_spanned(attr, reason));
+        }
+    }
+    input
+        .attrs
+        .push(parse_quote!(#[serde(rename = #ENUM_NAME)]));
+
+    let Data::Enum(data) = &mut input.data else {
+        let reason = "`variant` can only be applied to enums";
+        return Err(Error::new(input.ident.span(), reason));
+    };
+
+    let mut variants = Vec::with_capacity(data.variants.len());
+    for mut variant in std::mem::take(&mut data.variants) {
+        let mut data_type = None;
+        let mut errors = Vec::new();
+        variant.attrs.retain(|attr| {
+            if !attr.path().is_ident("clickhouse") {
+                return true;
+            }
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("data_type") {
+                    data_type = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `data_type = \"..\"`"))
+                }
+            });
+            errors.extend(result.err());
+            false
+        });
+        if let Some(error) = errors.into_iter().next() {
+            return Err(error);
+        }
+
+        for attr in &variant.attrs {
+            if is_serde_rename(attr)? {
+                let reason = "`serde(rename)` conflicts with `variant`, \
+                    which renames variants to their types";
+                return Err(Error::new_spanned(attr, reason));
+            }
+        }
+
+        // Without the attribute, the name of the variant is the type, e.g. `String` or `UInt64`.
+        let hint = if data_type.is_none() {
+            "; use `#[clickhouse(data_type = \"..\")]` to specify the type of the variant"
+        } else {
+            ""
+        };
+        let data_type = data_type
+            .unwrap_or_else(|| LitStr::new(&variant.ident.to_string(), variant.ident.span()));
+        let name = match DataTypeNode::new(&data_type.value()) {
+            Ok(node) => node.to_string(),
+            Err(err) => {
+                let reason = format!(
+                    "invalid ClickHouse type `{}`: {err}{hint}",
+                    data_type.value()
+                );
+                return Err(Error::new(data_type.span(), reason));
+            }
+        };
+
+        variant.attrs.push(parse_quote!(#[serde(rename = #name)]));
+        variants.push((name, data_type, variant));
+    }
+
+    if variants.len() > MAX_TYPES {
+        let reason = format!("`Variant` can have at most {MAX_TYPES} types");
+        return Err(Error::new(input.ident.span(), reason));
+    }
+
+    // ClickHouse orders types of `Variant` by their names, and discriminators are their indices.
+    variants.sort_by(|a, b| a.0.cmp(&b.0));
+    if let Some(pair) = variants.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        let reason = format!("the type `{}` is used by multiple variants", pair[1].0);
+        return Err(Error::new(pair[1].1.span(), reason));
+    }
+
+    data.variants = variants.into_iter().map(|(.., variant)| variant).collect();
+    Ok(quote! { #input })
+}
+
+// Checks if the attribute is `#[serde(..)]` with the `rename` option,
+// e.g. `rename = ".."` or `rename(serialize = "..")`, but not `rename_all`.
+fn is_serde_rename(attr: &Attribute) -> Result<bool> {
+    if !attr.path().is_ident("serde") {
+        return Ok(false);
+    }
+
+    let mut is_rename = false;
+    attr.parse_nested_meta(|meta| {
+        is_rename |= meta.path.is_ident("rename");
+
+        // Skip the value of the option, e.g. `= ".."` or `(..)`.
+        if meta.input.peek(Token![=]) {
+            meta.value()?.parse::<Expr>()?;
+        } else if meta.input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in meta.input);
+            content.parse::<TokenStream>()?;
+        }
+        Ok(())
+    })?;
+
+    Ok(is_rename)
+}
@@ -0,0 +1,72 @@
+use clickhouse_derive::Row;
+use serde::{Deserialize, Serialize};
+
+use clickhouse::sql::Identifier;
+use clickhouse::value::Value;
+use clickhouse::{error::Result, Client};
+
+// Inserting and selecting rows with Dynamic and JSON columns in the binary encoding.
+// See also:
+// * https://clickhouse.com/docs/en/sql-reference/data-types/dynamic
+// * https://clickhouse.com/docs/en/sql-reference/data-types/newjson
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let table_name = "chrs_data_types_dynamic_json";
+    let client = Client::default()
+        .with_url("http://localhost:8123")
+        // Required for ClickHouse versions before 25.3.
+        .with_option("allow_experimental_dynamic_type", "1")
+        .with_option("allow_experimental_json_type", "1");
+
+    client
+        .query(
+            "
+            CREATE OR REPLACE TABLE ?
+            (
+                id       UInt64,
+                dynamic  Dynamic,
+                data     JSON
+            ) ENGINE MergeTree ORDER BY id;
+        ",
+        )
+        .bind(Identifier(table_name))
+        .execute()
+        .await?;
+
+    let row = MyRow {
+        id: 1,
+        dynamic: Value::Array(vec![Value::String("foo".into()), Value::Null]),
+        data: serde_json::json!({
+            "name": "John Doe",
+            "age": 42,
+            "phones": [
+                "+123 456 789",
+                "+987 654 321"
+            ]
+        }),
+    };
+
+    let mut insert = client.insert::<MyRow>(table_name)?;
+    insert.write(&row).await?;
+    insert.end().await?;
+
+    let db_row = client
+        .query("SELECT ?fields FROM ? LIMIT 1")
+        .bind(Identifier(table_name))
+        .fetch_one::<MyRow>()
+        .await?;
+
+    println!("{db_row:#?}");
+    println!("Extracted name from JSON: {}", db_row.data["name"]);
+
+    Ok(())
+}
+
+#[derive(Debug, Row, Serialize, Deserialize)]
+pub struct MyRow {
+    id: u64,
+    dynamic: Value,
+    #[serde(with = "clickhouse::serde::json")]
+    data: serde_json::Value,
+}
@@ -134,32 +134,38 @@ fn get_rows() -> Vec<MyRow> {
     ]
 }
 
-// As the inner Variant types are _always_ sorted alphabetically,
-// Rust enum variants should be defined in the _exactly_ same order as it is in the data type;
-// their names are irrelevant, only the order of the types matters.
+// As the inner Variant types are _always_ sorted alphabetically, the discriminators written
+// by a plain enum are the indices of its variants, so they should be defined in the _exactly_
+// same order as it is in the data type. `clickhouse::variant` sorts the variants by their types
+// instead, so the order doesn't matter; the type is either the name of the variant
+// or specified by `#[clickhouse(data_type = "..")]`.
 // This enum represents Variant(Array(UInt16), Bool, Date, FixedString(6), Float32, Float64, Int128, Int16, Int32, Int64, Int8, String, UInt128, UInt16, UInt32, UInt64, UInt8)
+#[clickhouse::variant]
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 enum MyRowVariant {
+    #[clickhouse(data_type = "Array(UInt16)")]
     Array(Vec<u16>),
+    #[clickhouse(data_type = "Bool")]
     Boolean(bool),
     // attributes should work in this case, too
     #[serde(with = "clickhouse::serde::time::date")]
     Date(time::Date),
     // NB: by default, fetched as raw bytes
+    #[clickhouse(data_type = "FixedString(6)")]
     FixedString([u8; 6]),
     Float32(f32),
     Float64(f64),
-    Int128(i128),
+    Int8(i8),
     Int16(i16),
     Int32(i32),
     Int64(i64),
-    Int8(i8),
+    Int128(i128),
     String(String),
-    UInt128(u128),
-    UInt16(i16),
+    UInt8(u8),
+    UInt16(u16),
     UInt32(u32),
     UInt64(u64),
-    UInt8(i8),
+    UInt128(u128),
 }
 
 #[derive(Debug, PartialEq, Row, Serialize, Deserialize)]
@@ -0,0 +1,444 @@
+//! Contains typed states of aggregate functions, used to read and write
+//! `AggregateFunction(..)` columns.
+//!
+//! ClickHouse stores intermediate states of aggregate functions (e.g. produced by
+//! `countState()` or inserted into `AggregatingMergeTree` tables) in a format specific
+//! to each function, without any length prefix. Thus, a state can be read or written only
+//! by a type knowing its layout. This module provides such types for common functions:
+//!
+//! | ClickHouse type                          | Rust type                  |
+//! |------------------------------------------|----------------------------|
+//! | `AggregateFunction(count)`               | [`CountState`]             |
+//! | `AggregateFunction(sum, T)`              | [`SumState<S>`]            |
+//! | `AggregateFunction(min, T)`              | [`MinState<T>`]            |
+//! | `AggregateFunction(max, T)`              | [`MaxState<T>`]            |
+//! | `AggregateFunction(avg, T)`              | [`AvgState<S>`]            |
+//! | `AggregateFunction(uniqExact, T)`        | [`UniqExactState<T>`]      |
+//! | `AggregateFunction(groupArray, T)`       | [`GroupArrayState<T>`]     |
+//! | `AggregateFunction(argMax, R, V)`        | [`ArgMaxState<R, V>`]      |
+//!
+//! Here `T`, `R` and `V` are integers, floats or `String` matching the arguments of
+//! the function (e.g. `u16` for `Date`), and `S` is the type of the sum, described
+//! in the docs of the corresponding state.
+//!
+//! ```
+//! use clickhouse::aggregate::{AvgState, CountState, MaxState, UniqExactState};
+//!
+//! #[derive(clickhouse::Row, serde::Serialize, serde::Deserialize)]
+//! struct PageStats {
+//!     // AggregateFunction(count)
+//!     views: CountState,
+//!     // AggregateFunction(avg, UInt32)
+//!     avg_duration: AvgState<u64>,
+//!     // AggregateFunction(max, UInt32)
+//!     max_duration: MaxState<u32>,
+//!     // AggregateFunction(uniqExact, UInt64)
+//!     visitors: UniqExactState<u64>,
+//! }
+//! ```
+//!
+//! When the row is validated against the schema, the name of the function is checked,
+//! but its arguments are not; using a wrong type of values leads to corrupted data.
+
+use std::{marker::PhantomData, mem::size_of};
+
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+macro_rules! marker {
+    ($function:literal) => {
+        concat!("$clickhouse::AggregateFunction::", $function)
+    };
+}
+
+/// The prefix of markers requesting `AggregateFunction(..)` states, followed by the function.
+pub(crate) const MARKER: &str = marker!("");
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A value stored in states: an integer, a float or `String`.
+///
+/// This trait is sealed and cannot be implemented outside of the crate.
+pub trait StateValue: Sized + sealed::Sealed {
+    #[doc(hidden)]
+    fn encode(&self, buf: &mut Vec<u8>);
+
+    #[doc(hidden)]
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error>;
+
+    /// Encodes a value of `min`, `max` and `argMax` states.
+    #[doc(hidden)]
+    fn encode_single(value: Option<&Self>, buf: &mut Vec<u8>) {
+        buf.push(value.is_some().into());
+        if let Some(value) = value {
+            value.encode(buf);
+        }
+    }
+
+    #[doc(hidden)]
+    fn decode_single<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Option<Self>, A::Error> {
+        match read_u8(seq)? {
+            0 => Ok(None),
+            _ => Self::decode(seq).map(Some),
+        }
+    }
+}
+
+/// A numeric value stored in states: an integer or a float.
+///
+/// This trait is sealed and cannot be implemented outside of the crate.
+pub trait Number: StateValue {}
+
+macro_rules! impl_number {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl StateValue for $ty {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+                    let mut bytes = [0; size_of::<$ty>()];
+                    for byte in &mut bytes {
+                        *byte = read_u8(seq)?;
+                    }
+                    Ok(<$ty>::from_le_bytes(bytes))
+                }
+            }
+
+            impl Number for $ty {}
+        )*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl sealed::Sealed for String {}
+
+impl StateValue for String {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        put_var_uint(buf, self.len() as u64);
+        buf.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        let len = read_var_uint(seq)?;
+        read_string(seq, len)
+    }
+
+    // Strings are stored with a trailing zero byte and a signed size, where `-1` means no value.
+    fn encode_single(value: Option<&Self>, buf: &mut Vec<u8>) {
+        let Some(value) = value else {
+            buf.extend_from_slice(&(-1i32).to_le_bytes());
+            return;
+        };
+
+        let size = i32::try_from(value.len() + 1).expect("too long string in a state");
+        buf.extend_from_slice(&size.to_le_bytes());
+        buf.extend_from_slice(value.as_bytes());
+        buf.push(0);
+    }
+
+    fn decode_single<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Option<Self>, A::Error> {
+        let size = i32::decode(seq)?;
+        if size < 0 {
+            return Ok(None);
+        }
+
+        let mut value = read_string(seq, size as u64)?;
+        if value.ends_with('\0') {
+            value.pop();
+        }
+        Ok(Some(value))
+    }
+}
+
+/// A state of `count`, the number of rows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CountState(pub u64);
+
+/// A state of `sum`, the sum itself.
+///
+/// The type of the sum is `u64` for `UInt8`..`UInt64`, `i64` for `Int8`..`Int64`,
+/// `f64` for floats and the type of the argument for wider integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SumState<S>(pub S);
+
+/// A state of `min`, the minimal value if any row has been aggregated.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MinState<T>(pub Option<T>);
+
+/// A state of `max`, the maximal value if any row has been aggregated.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MaxState<T>(pub Option<T>);
+
+/// A state of `avg`, the sum of values and their number.
+///
+/// The type of the sum is `u64` for `UInt8`..`UInt64`, `i64` for `Int8`..`Int64`
+/// and `f64` for floats and wider integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AvgState<S> {
+    /// The sum of aggregated values.
+    pub numerator: S,
+    /// The number of aggregated values.
+    pub denominator: u64,
+}
+
+/// A state of `uniqExact`, the set of distinct values in arbitrary order.
+///
+/// Strings and other non-numeric arguments are stored as their 128-bit hashes,
+/// so `UniqExactState<u128>` should be used for them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UniqExactState<T>(pub Vec<T>);
+
+/// A state of `groupArray` (without the size limit), the collected values.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GroupArrayState<T>(pub Vec<T>);
+
+/// A state of `argMax`, the argument for the maximal value if any row has been aggregated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArgMaxState<R, V> {
+    /// The argument for the maximal value, i.e. the result of `argMax`.
+    pub result: Option<R>,
+    /// The maximal value.
+    pub value: Option<V>,
+}
+
+impl<R, V> Default for ArgMaxState<R, V> {
+    fn default() -> Self {
+        Self {
+            result: None,
+            value: None,
+        }
+    }
+}
+
+/// Describes the binary layout of a state.
+trait State: Sized {
+    const MARKER: &'static str;
+
+    fn encode(&self, buf: &mut Vec<u8>);
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error>;
+}
+
+impl State for CountState {
+    const MARKER: &'static str = marker!("count");
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        put_var_uint(buf, self.0);
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        read_var_uint(seq).map(Self)
+    }
+}
+
+impl<S: Number> State for SumState<S> {
+    const MARKER: &'static str = marker!("sum");
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.0.encode(buf);
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        S::decode(seq).map(Self)
+    }
+}
+
+impl<T: StateValue> State for MinState<T> {
+    const MARKER: &'static str = marker!("min");
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        T::encode_single(self.0.as_ref(), buf);
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        T::decode_single(seq).map(Self)
+    }
+}
+
+impl<T: StateValue> State for MaxState<T> {
+    const MARKER: &'static str = marker!("max");
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        T::encode_single(self.0.as_ref(), buf);
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        T::decode_single(seq).map(Self)
+    }
+}
+
+impl<S: Number> State for AvgState<S> {
+    const MARKER: &'static str = marker!("avg");
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.numerator.encode(buf);
+        put_var_uint(buf, self.denominator);
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        Ok(Self {
+            numerator: S::decode(seq)?,
+            denominator: read_var_uint(seq)?,
+        })
+    }
+}
+
+impl<T: Number> State for UniqExactState<T> {
+    const MARKER: &'static str = marker!("uniqExact");
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_values(&self.0, buf);
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        decode_values(seq).map(Self)
+    }
+}
+
+impl<T: StateValue> State for GroupArrayState<T> {
+    const MARKER: &'static str = marker!("groupArray");
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_values(&self.0, buf);
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        decode_values(seq).map(Self)
+    }
+}
+
+impl<R: StateValue, V: StateValue> State for ArgMaxState<R, V> {
+    const MARKER: &'static str = marker!("argMax");
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        R::encode_single(self.result.as_ref(), buf);
+        V::encode_single(self.value.as_ref(), buf);
+    }
+
+    fn decode<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<Self, A::Error> {
+        Ok(Self {
+            result: R::decode_single(seq)?,
+            value: V::decode_single(seq)?,
+        })
+    }
+}
+
+macro_rules! impl_serde {
+    ($ty:ident $(<$($param:ident: $bound:ident),*>)?) => {
+        impl$(<$($param: $bound),*>)? Serialize for $ty$(<$($param),*>)? {
+            fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+                serialize_state(self, serializer)
+            }
+        }
+
+        impl<'de, $($($param: $bound),*)?> Deserialize<'de> for $ty$(<$($param),*>)? {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_state(deserializer)
+            }
+        }
+    };
+}
+
+impl_serde!(CountState);
+impl_serde!(SumState<S: Number>);
+impl_serde!(MinState<T: StateValue>);
+impl_serde!(MaxState<T: StateValue>);
+impl_serde!(AvgState<S: Number>);
+impl_serde!(UniqExactState<T: Number>);
+impl_serde!(GroupArrayState<T: StateValue>);
+impl_serde!(ArgMaxState<R: StateValue, V: StateValue>);
+
+/// Serializes the encoded state as a tuple of bytes, written as is in `RowBinary`.
+fn serialize_state<T: State, S: Serializer>(state: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    struct Bytes<'a>(&'a [u8]);
+
+    impl Serialize for Bytes<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut tuple = serializer.serialize_tuple(self.0.len())?;
+            for byte in self.0 {
+                tuple.serialize_element(byte)?;
+            }
+            tuple.end()
+        }
+    }
+
+    let mut buf = Vec::new();
+    state.encode(&mut buf);
+    serializer.serialize_newtype_struct(T::MARKER, &Bytes(&buf))
+}
+
+/// Deserializes the state from a tuple of bytes of unknown length.
+fn deserialize_state<'de, T: State, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    struct StateVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: State> Visitor<'de> for StateVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("a state of an aggregate function")
+        }
+
+        fn visit_newtype_struct<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<T, D::Error> {
+            deserializer.deserialize_tuple(usize::MAX, self)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+            T::decode(&mut seq)
+        }
+    }
+
+    deserializer.deserialize_newtype_struct(T::MARKER, StateVisitor(PhantomData))
+}
+
+fn encode_values<T: StateValue>(values: &[T], buf: &mut Vec<u8>) {
+    put_var_uint(buf, values.len() as u64);
+    for value in values {
+        value.encode(buf);
+    }
+}
+
+fn decode_values<'de, T: StateValue, A: SeqAccess<'de>>(seq: &mut A) -> Result<Vec<T>, A::Error> {
+    let len = read_var_uint(seq)?;
+    (0..len).map(|_| T::decode(seq)).collect()
+}
+
+fn put_var_uint(buf: &mut Vec<u8>, value: u64) {
+    clickhouse_types::put_leb128(buf, value);
+}
+
+fn read_u8<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<u8, A::Error> {
+    seq.next_element()?
+        .ok_or_else(|| de::Error::custom("unexpected end of an aggregate function state"))
+}
+
+fn read_var_uint<'de, A: SeqAccess<'de>>(seq: &mut A) -> Result<u64, A::Error> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = read_u8(seq)?;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(de::Error::custom(
+        "too long VarUInt in an aggregate function state",
+    ))
+}
+
+fn read_string<'de, A: SeqAccess<'de>>(seq: &mut A, len: u64) -> Result<String, A::Error> {
+    let bytes = (0..len)
+        .map(|_| read_u8(seq))
+        .collect::<Result<Vec<_>, _>>()?;
+    String::from_utf8(bytes).map_err(de::Error::custom)
+}
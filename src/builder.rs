@@ -0,0 +1,728 @@
+//! Typed builders of `SELECT`, `INSERT ... SELECT` and `ALTER` queries.
+//!
+//! Builders are composed of [`Expr`]s, which are SQL templates with the same
+//! placeholders as [`Client::query`] has. Arguments can be bound either to
+//! expressions by [`Expr::bind`], or later to the built [`Query`] in the order
+//! of the placeholders in the rendered query.
+//!
+//! ```
+//! use clickhouse::{builder::{expr, select, Fill}, sql::Identifier, Row};
+//! use serde::Deserialize;
+//!
+//! #[derive(Row, Deserialize)]
+//! struct Event {
+//!     day: u32,
+//!     count: u64,
+//! }
+//!
+//! # let client = clickhouse::Client::default();
+//! let query = select::<Event>()
+//!     .from(Identifier("events"))
+//!     .final_()
+//!     .where_(expr("kind = ?").bind("click"))
+//!     .where_(expr("day >= ?").bind(20))
+//!     .order_by("day")
+//!     .with_fill(Fill::new().to("30"))
+//!     .settings("max_threads", 4)
+//!     .into_query(&client);
+//!
+//! assert_eq!(
+//!     query.sql_display().to_string(),
+//!     "SELECT `day`,`count` FROM `events` FINAL WHERE (kind = 'click') AND (day >= 20) \
+//!      ORDER BY day WITH FILL TO 30 SETTINGS max_threads = 4",
+//! );
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::{
+    query::Query,
+    row::Row,
+    sql::{Bind, Identifier, SqlBuilder},
+    Client,
+};
+
+// === Expr ===
+
+/// A part of a query, e.g. a condition or a table name.
+///
+/// Created from templates (`&str`), which can contain the same placeholders
+/// as [`Client::query`] has, and from [`Identifier`]s, which are escaped.
+#[derive(Clone)]
+pub struct Expr(SqlBuilder);
+
+impl Expr {
+    /// Creates an expression from a template with optional placeholders.
+    pub fn new(template: &str) -> Self {
+        Self(SqlBuilder::new(template))
+    }
+
+    /// Binds `value` to the next `?` in the expression.
+    ///
+    /// See [`Query::bind`] for details.
+    pub fn bind(mut self, value: impl Bind) -> Self {
+        self.0.bind_arg(value);
+        self
+    }
+}
+
+/// A shortcut for [`Expr::new`].
+pub fn expr(template: &str) -> Expr {
+    Expr::new(template)
+}
+
+impl From<&str> for Expr {
+    fn from(template: &str) -> Self {
+        Self::new(template)
+    }
+}
+
+impl From<Identifier<'_>> for Expr {
+    fn from(identifier: Identifier<'_>) -> Self {
+        Self::new("?").bind(identifier)
+    }
+}
+
+// Renders parts of clauses, accumulating them into one `SqlBuilder`.
+struct Renderer(SqlBuilder);
+
+impl Renderer {
+    fn new() -> Self {
+        Self(SqlBuilder::new(""))
+    }
+
+    fn text(&mut self, text: &str) {
+        self.0.push_text(text);
+    }
+
+    fn expr(&mut self, expr: Expr) {
+        self.0.append(expr.0);
+    }
+
+    // ` WHERE (a) AND (b)`
+    fn conditions(&mut self, keyword: &str, conditions: Vec<Expr>) {
+        let wrap = conditions.len() > 1;
+        for (idx, condition) in conditions.into_iter().enumerate() {
+            self.text(if idx == 0 { keyword } else { " AND " });
+            if wrap {
+                self.text("(");
+                self.expr(condition);
+                self.text(")");
+            } else {
+                self.expr(condition);
+            }
+        }
+    }
+
+    // ` GROUP BY a, b`
+    fn list(&mut self, keyword: &str, items: Vec<Expr>) {
+        for (idx, item) in items.into_iter().enumerate() {
+            self.text(if idx == 0 { keyword } else { ", " });
+            self.expr(item);
+        }
+    }
+
+    // ` SETTINGS a = 1, b = 'c'`
+    fn settings(&mut self, settings: Vec<(String, Expr)>) {
+        for (idx, (name, value)) in settings.into_iter().enumerate() {
+            self.text(if idx == 0 { " SETTINGS " } else { ", " });
+            self.text(&name);
+            self.text(" = ");
+            self.expr(value);
+        }
+    }
+
+    fn finish(mut self, error: Option<String>) -> SqlBuilder {
+        if let Some(error) = error {
+            self.0.error(error);
+        }
+        self.0
+    }
+}
+
+// Keeps the first error, which is reported by the built query.
+fn fail(error: &mut Option<String>, message: &str) {
+    error.get_or_insert_with(|| message.to_string());
+}
+
+fn setting(name: &str, value: impl Bind, error: &mut Option<String>) -> (String, Expr) {
+    let is_valid = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    if name.is_empty() || !name.chars().all(is_valid) {
+        fail(error, &format!("invalid setting name: {name}"));
+    }
+
+    (name.to_string(), Expr::new("?").bind(value))
+}
+
+// === Fill ===
+
+/// Modifiers of `ORDER BY .. WITH FILL`, see [`Select::with_fill`].
+#[derive(Clone, Default)]
+pub struct Fill {
+    from: Option<Expr>,
+    to: Option<Expr>,
+    step: Option<Expr>,
+}
+
+impl Fill {
+    /// Creates `WITH FILL` without modifiers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `FROM expr`.
+    pub fn from(mut self, expr: impl Into<Expr>) -> Self {
+        self.from = Some(expr.into());
+        self
+    }
+
+    /// Sets `TO expr`.
+    pub fn to(mut self, expr: impl Into<Expr>) -> Self {
+        self.to = Some(expr.into());
+        self
+    }
+
+    /// Sets `STEP expr`.
+    pub fn step(mut self, expr: impl Into<Expr>) -> Self {
+        self.step = Some(expr.into());
+        self
+    }
+
+    fn render(self, r: &mut Renderer) {
+        r.text(" WITH FILL");
+        for (keyword, expr) in [
+            (" FROM ", self.from),
+            (" TO ", self.to),
+            (" STEP ", self.step),
+        ] {
+            if let Some(expr) = expr {
+                r.text(keyword);
+                r.expr(expr);
+            }
+        }
+    }
+}
+
+// === Select ===
+
+/// Starts a `SELECT` of the columns of `T`.
+pub fn select<T: Row>() -> Select<T> {
+    Select {
+        distinct: false,
+        from: None,
+        final_: false,
+        sample: None,
+        array_joins: Vec::new(),
+        prewhere: Vec::new(),
+        where_: Vec::new(),
+        group_by: Vec::new(),
+        having: Vec::new(),
+        order_by: Vec::new(),
+        limit_by: None,
+        limit: None,
+        offset: None,
+        settings: Vec::new(),
+        error: None,
+        _marker: PhantomData,
+    }
+}
+
+/// A builder of `SELECT` queries, created by [`select`].
+///
+/// Clauses are rendered in the order required by ClickHouse regardless of
+/// the order of calls. Repeated `where_()`, `prewhere()` and `having()`
+/// are combined with `AND`, other repeated clauses are joined with commas.
+#[must_use]
+pub struct Select<T> {
+    distinct: bool,
+    from: Option<Expr>,
+    final_: bool,
+    sample: Option<Expr>,
+    array_joins: Vec<(&'static str, Expr)>,
+    prewhere: Vec<Expr>,
+    where_: Vec<Expr>,
+    group_by: Vec<Expr>,
+    having: Vec<Expr>,
+    order_by: Vec<(Expr, Option<Fill>)>,
+    limit_by: Option<(u64, Vec<Expr>)>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    settings: Vec<(String, Expr)>,
+    error: Option<String>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Row> Select<T> {
+    /// Adds `DISTINCT`.
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Sets `FROM table`, where `table` is an [`Identifier`], a table function
+    /// or a subquery in parentheses.
+    pub fn from(mut self, table: impl Into<Expr>) -> Self {
+        self.from = Some(table.into());
+        self
+    }
+
+    /// Adds `FINAL` after the table.
+    pub fn final_(mut self) -> Self {
+        self.final_ = true;
+        self
+    }
+
+    /// Sets `SAMPLE k`, e.g. `0.1` or `1/10`.
+    pub fn sample(mut self, k: impl Into<Expr>) -> Self {
+        self.sample = Some(k.into());
+        self
+    }
+
+    /// Adds `ARRAY JOIN expr`.
+    pub fn array_join(mut self, expr: impl Into<Expr>) -> Self {
+        self.array_joins.push((" ARRAY JOIN ", expr.into()));
+        self
+    }
+
+    /// Adds `LEFT ARRAY JOIN expr`.
+    pub fn left_array_join(mut self, expr: impl Into<Expr>) -> Self {
+        self.array_joins.push((" LEFT ARRAY JOIN ", expr.into()));
+        self
+    }
+
+    /// Adds a `PREWHERE` condition.
+    pub fn prewhere(mut self, condition: impl Into<Expr>) -> Self {
+        self.prewhere.push(condition.into());
+        self
+    }
+
+    /// Adds a `WHERE` condition.
+    pub fn where_(mut self, condition: impl Into<Expr>) -> Self {
+        self.where_.push(condition.into());
+        self
+    }
+
+    /// Adds a `GROUP BY` expression.
+    pub fn group_by(mut self, expr: impl Into<Expr>) -> Self {
+        self.group_by.push(expr.into());
+        self
+    }
+
+    /// Adds a `HAVING` condition.
+    pub fn having(mut self, condition: impl Into<Expr>) -> Self {
+        self.having.push(condition.into());
+        self
+    }
+
+    /// Adds an `ORDER BY` expression, e.g. `"ts DESC"`.
+    pub fn order_by(mut self, expr: impl Into<Expr>) -> Self {
+        self.order_by.push((expr.into(), None));
+        self
+    }
+
+    /// Adds `WITH FILL` to the last `ORDER BY` expression.
+    pub fn with_fill(mut self, fill: Fill) -> Self {
+        match self.order_by.last_mut() {
+            Some((_, last)) => *last = Some(fill),
+            None => fail(&mut self.error, "with_fill() must follow order_by()"),
+        }
+        self
+    }
+
+    /// Sets `LIMIT n BY expr`. Repeated calls add expressions to the same clause.
+    pub fn limit_by(mut self, n: u64, expr: impl Into<Expr>) -> Self {
+        let (limit, exprs) = self.limit_by.get_or_insert_with(|| (n, Vec::new()));
+        *limit = n;
+        exprs.push(expr.into());
+        self
+    }
+
+    /// Sets `LIMIT n`.
+    pub fn limit(mut self, n: u64) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Sets `OFFSET n`.
+    pub fn offset(mut self, n: u64) -> Self {
+        self.offset = Some(n);
+        self
+    }
+
+    /// Adds a setting to the `SETTINGS` clause, `value` is bound as a literal.
+    pub fn settings(mut self, name: &str, value: impl Bind) -> Self {
+        let setting = setting(name, value, &mut self.error);
+        self.settings.push(setting);
+        self
+    }
+
+    /// Builds the query, which can be executed or fetched as `T`.
+    ///
+    /// Errors are reported as [`Error::InvalidParams`] during query execution.
+    ///
+    /// [`Error::InvalidParams`]: crate::error::Error::InvalidParams
+    pub fn into_query(self, client: &Client) -> Query {
+        Query::with_sql(client, self.render())
+    }
+
+    fn render(mut self) -> SqlBuilder {
+        let mut r = Renderer(SqlBuilder::new(if self.distinct {
+            "SELECT DISTINCT ?fields"
+        } else {
+            "SELECT ?fields"
+        }));
+        r.0.bind_fields::<T>();
+
+        match self.from {
+            Some(from) => {
+                r.text(" FROM ");
+                r.expr(from);
+            }
+            None => fail(&mut self.error, "from() is required"),
+        }
+        if self.final_ {
+            r.text(" FINAL");
+        }
+        if let Some(sample) = self.sample {
+            r.text(" SAMPLE ");
+            r.expr(sample);
+        }
+        for (keyword, expr) in self.array_joins {
+            r.text(keyword);
+            r.expr(expr);
+        }
+        r.conditions(" PREWHERE ", self.prewhere);
+        r.conditions(" WHERE ", self.where_);
+        r.list(" GROUP BY ", self.group_by);
+        r.conditions(" HAVING ", self.having);
+        for (idx, (expr, fill)) in self.order_by.into_iter().enumerate() {
+            r.text(if idx == 0 { " ORDER BY " } else { ", " });
+            r.expr(expr);
+            if let Some(fill) = fill {
+                fill.render(&mut r);
+            }
+        }
+        if let Some((n, exprs)) = self.limit_by {
+            r.list(&format!(" LIMIT {n} BY "), exprs);
+        }
+        if let Some(limit) = self.limit {
+            r.text(&format!(" LIMIT {limit}"));
+        }
+        if let Some(offset) = self.offset {
+            r.text(&format!(" OFFSET {offset}"));
+        }
+        r.settings(self.settings);
+        r.finish(self.error)
+    }
+}
+
+// === InsertSelect ===
+
+/// Starts an `INSERT INTO table (..) SELECT ..` into the columns of `T`.
+///
+/// Use [`Client::insert`] to insert rows from the client.
+pub fn insert_into<T: Row>(table: impl Into<Expr>) -> InsertSelect<T> {
+    InsertSelect {
+        table: table.into(),
+        select: None,
+        settings: Vec::new(),
+        error: None,
+    }
+}
+
+/// A builder of `INSERT INTO .. SELECT` queries, created by [`insert_into`].
+#[must_use]
+pub struct InsertSelect<T> {
+    table: Expr,
+    select: Option<Select<T>>,
+    settings: Vec<(String, Expr)>,
+    error: Option<String>,
+}
+
+impl<T: Row> InsertSelect<T> {
+    /// Sets the `SELECT` query, which provides rows to insert.
+    pub fn select(mut self, select: Select<T>) -> Self {
+        self.select = Some(select);
+        self
+    }
+
+    /// Adds a setting of the `INSERT`, `value` is bound as a literal.
+    /// Settings of the `SELECT` are set by [`Select::settings`].
+    pub fn settings(mut self, name: &str, value: impl Bind) -> Self {
+        let setting = setting(name, value, &mut self.error);
+        self.settings.push(setting);
+        self
+    }
+
+    /// Builds the query, which should be executed by [`Query::execute`].
+    ///
+    /// Errors are reported as [`Error::InvalidParams`] during query execution.
+    ///
+    /// [`Error::InvalidParams`]: crate::error::Error::InvalidParams
+    pub fn into_query(self, client: &Client) -> Query {
+        Query::with_sql(client, self.render())
+    }
+
+    fn render(mut self) -> SqlBuilder {
+        let mut r = Renderer::new();
+        r.text("INSERT INTO ");
+        r.expr(self.table);
+
+        let mut columns = SqlBuilder::new(" (?fields)");
+        columns.bind_fields::<T>();
+        r.0.append(columns);
+
+        r.settings(self.settings);
+        match self.select {
+            Some(select) => {
+                r.text(" ");
+                r.0.append(select.render());
+            }
+            None => {
+                self.error
+                    .get_or_insert_with(|| "select() is required".into());
+            }
+        }
+        r.finish(self.error)
+    }
+}
+
+// === Alter ===
+
+/// Starts an `ALTER TABLE table UPDATE|DELETE` mutation.
+pub fn alter(table: impl Into<Expr>) -> Alter {
+    Alter {
+        table: table.into(),
+        updates: Vec::new(),
+        delete: false,
+        where_: Vec::new(),
+        settings: Vec::new(),
+        error: None,
+    }
+}
+
+/// A builder of `ALTER TABLE .. UPDATE|DELETE` mutations, created by [`alter`].
+#[must_use]
+pub struct Alter {
+    table: Expr,
+    updates: Vec<Expr>,
+    delete: bool,
+    where_: Vec<Expr>,
+    settings: Vec<(String, Expr)>,
+    error: Option<String>,
+}
+
+impl Alter {
+    /// Adds an assignment to `UPDATE`, e.g. `expr("count = ?").bind(42)`.
+    pub fn update(mut self, assignment: impl Into<Expr>) -> Self {
+        self.updates.push(assignment.into());
+        self
+    }
+
+    /// Makes the mutation `DELETE`.
+    pub fn delete(mut self) -> Self {
+        self.delete = true;
+        self
+    }
+
+    /// Adds a `WHERE` condition, which is required by ClickHouse.
+    pub fn where_(mut self, condition: impl Into<Expr>) -> Self {
+        self.where_.push(condition.into());
+        self
+    }
+
+    /// Adds a setting to the `SETTINGS` clause, `value` is bound as a literal.
+    pub fn settings(mut self, name: &str, value: impl Bind) -> Self {
+        let setting = setting(name, value, &mut self.error);
+        self.settings.push(setting);
+        self
+    }
+
+    /// Builds the query, which should be executed by [`Query::execute`].
+    ///
+    /// Errors are reported as [`Error::InvalidParams`] during query execution.
+    ///
+    /// [`Error::InvalidParams`]: crate::error::Error::InvalidParams
+    pub fn into_query(self, client: &Client) -> Query {
+        Query::with_sql(client, self.render())
+    }
+
+    fn render(mut self) -> SqlBuilder {
+        let mut r = Renderer::new();
+        r.text("ALTER TABLE ");
+        r.expr(self.table);
+
+        match (self.updates.is_empty(), self.delete) {
+            (false, false) => r.list(" UPDATE ", self.updates),
+            (true, true) => r.text(" DELETE"),
+            (true, false) => fail(&mut self.error, "either update() or delete() is required"),
+            (false, true) => fail(&mut self.error, "update() and delete() cannot be combined"),
+        }
+
+        if self.where_.is_empty() {
+            fail(&mut self.error, "where_() is required");
+        }
+        r.conditions(" WHERE ", self.where_);
+        r.settings(self.settings);
+        r.finish(self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // XXX: need for `derive(Row)`. Provide `row(crate = ..)` instead.
+    use crate as clickhouse;
+    use clickhouse_derive::Row;
+
+    #[allow(unused)]
+    #[derive(Row)]
+    struct Event {
+        day: u32,
+        count: u64,
+    }
+
+    fn sql(builder: SqlBuilder) -> String {
+        builder.finish().unwrap_or_else(|err| err.to_string())
+    }
+
+    #[test]
+    fn select_all_clauses() {
+        let query = select::<Event>()
+            .distinct()
+            .settings("max_threads", 4)
+            .from(Identifier("events"))
+            .final_()
+            .sample("1/10")
+            .array_join("tags AS tag")
+            .left_array_join("ids")
+            .prewhere("kind = 'click'")
+            .where_(expr("day >= ?").bind(20))
+            .where_(expr("tag IN ?").bind(&["a'b", "c?"][..]))
+            .group_by("day")
+            .group_by("tag")
+            .having("count() > 1")
+            .order_by("day")
+            .with_fill(Fill::new().from("1").to("30").step("1"))
+            .order_by("tag DESC")
+            .limit_by(2, "day")
+            .limit_by(3, "tag")
+            .limit(10)
+            .offset(20)
+            .settings("log_comment", "it's");
+
+        assert_eq!(
+            sql(query.render()),
+            "SELECT DISTINCT `day`,`count` FROM `events` FINAL SAMPLE 1/10 \
+             ARRAY JOIN tags AS tag LEFT ARRAY JOIN ids PREWHERE kind = 'click' \
+             WHERE (day >= 20) AND (tag IN ['a\\'b','c?']) GROUP BY day, tag \
+             HAVING count() > 1 ORDER BY day WITH FILL FROM 1 TO 30 STEP 1, tag DESC \
+             LIMIT 3 BY day, tag LIMIT 10 OFFSET 20 \
+             SETTINGS max_threads = 4, log_comment = 'it\\'s'"
+        );
+    }
+
+    #[test]
+    fn select_unbound_args() {
+        let query = select::<Event>()
+            .from("numbers(?)")
+            .where_("day = ?param")
+            .where_("count > ?")
+            .where_("?param");
+
+        let mut sql = query.render();
+        sql.bind_arg(10);
+        assert_eq!(
+            sql.bind_param(&crate::types::DataTypeNode::UInt32)
+                .as_deref(),
+            Some("p0")
+        );
+        sql.bind_arg(5);
+        assert_eq!(
+            sql.bind_param(&crate::types::DataTypeNode::Bool).as_deref(),
+            Some("p1")
+        );
+        assert_eq!(
+            sql.finish().unwrap(),
+            "SELECT `day`,`count` FROM numbers(10) WHERE (day = {p0: UInt32}) \
+             AND (count > 5) AND ({p1: Bool})"
+        );
+    }
+
+    #[test]
+    fn select_errors() {
+        let err = sql(select::<Event>().where_("1").render());
+        assert!(err.contains("from() is required"), "{err}");
+
+        let err = sql(select::<Event>().from("t").with_fill(Fill::new()).render());
+        assert!(err.contains("with_fill() must follow order_by()"), "{err}");
+
+        let err = sql(select::<Event>().from("t").settings("a; DROP", 1).render());
+        assert!(err.contains("invalid setting name"), "{err}");
+
+        let err = sql(select::<Event>().from("t").where_(expr("a = ?")).render());
+        assert!(err.contains("unbound query argument"), "{err}");
+
+        let err = sql(select::<u32>().from("t").render());
+        assert!(err.contains("non-struct row types"), "{err}");
+    }
+
+    #[test]
+    fn insert_select() {
+        let query = insert_into::<Event>(Identifier("daily"))
+            .settings("async_insert", 1)
+            .select(
+                select::<Event>()
+                    .from(Identifier("events"))
+                    .where_(expr("day = ?").bind(1))
+                    .settings("max_threads", 2),
+            );
+
+        assert_eq!(
+            sql(query.render()),
+            "INSERT INTO `daily` (`day`,`count`) SETTINGS async_insert = 1 \
+             SELECT `day`,`count` FROM `events` WHERE day = 1 SETTINGS max_threads = 2"
+        );
+
+        let err = sql(insert_into::<Event>("t").render());
+        assert!(err.contains("select() is required"), "{err}");
+    }
+
+    #[test]
+    fn alter() {
+        let query = super::alter(Identifier("events"))
+            .update(expr("count = ?").bind(0))
+            .update("day = day + 1")
+            .where_(expr("day = ?").bind(1))
+            .settings("mutations_sync", 2);
+
+        assert_eq!(
+            sql(query.render()),
+            "ALTER TABLE `events` UPDATE count = 0, day = day + 1 WHERE day = 1 \
+             SETTINGS mutations_sync = 2"
+        );
+
+        let query = super::alter("events").delete().where_("a").where_("b");
+        assert_eq!(
+            sql(query.render()),
+            "ALTER TABLE events DELETE WHERE (a) AND (b)"
+        );
+
+        let err = sql(super::alter("t").delete().render());
+        assert!(err.contains("where_() is required"), "{err}");
+
+        let err = sql(super::alter("t").where_("1").render());
+        assert!(
+            err.contains("either update() or delete() is required"),
+            "{err}"
+        );
+
+        let err = sql(super::alter("t")
+            .update("a = 1")
+            .delete()
+            .where_("1")
+            .render());
+        assert!(err.contains("cannot be combined"), "{err}");
+    }
+}
@@ -0,0 +1,750 @@
+//! Generation of [`Row`](crate::Row) structs from table schemas.
+//!
+//! Columns are taken either from a live table by [`Client::describe_table`]
+//! or from a `CREATE TABLE` statement by [`parse_create_table`].
+//! Types are mapped as described in the "Data Types" section of the README,
+//! including `clickhouse::serde` helpers for dates, UUIDs, decimals etc.
+//!
+//! ```
+//! use clickhouse::codegen::{parse_create_table, RowCodegen};
+//!
+//! let columns = parse_create_table(
+//!     "CREATE TABLE events (
+//!         id UInt64,
+//!         userName LowCardinality(String),
+//!         ts DateTime64(3, 'UTC'),
+//!         score Nullable(Decimal(18, 4))
+//!     ) ENGINE = MergeTree ORDER BY id",
+//! )
+//! .unwrap();
+//!
+//! let code = RowCodegen::new("Event", columns).borrowed(true).generate();
+//! assert!(code.contains("pub struct Event<'a> {"));
+//! assert!(code.contains("#[serde(rename = \"userName\")]\n    pub user_name: &'a str,"));
+//! ```
+//!
+//! [`Client::describe_table`]: crate::Client::describe_table
+
+use std::{collections::HashSet, fmt::Write};
+
+use crate::{
+    error::{Error, Result},
+    sql::lexer::{segments, Segment},
+    types::{Column, DataTypeNode, DateTimePrecision, DecimalType, EnumType},
+};
+
+// === RowCodegen ===
+
+/// The crate used for `Date*` and `Time*` columns by [`RowCodegen`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeCrate {
+    /// The `time` crate (the `time` feature).
+    #[default]
+    Time,
+    /// The `chrono` crate (the `chrono` feature).
+    Chrono,
+    /// The `jiff` crate (the `jiff` feature).
+    Jiff,
+}
+
+/// The crate used for `Decimal*` columns by [`RowCodegen`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecimalCrate {
+    /// `rust_decimal::Decimal` (the `rust_decimal` feature).
+    #[default]
+    RustDecimal,
+    /// `bigdecimal::BigDecimal` (the `bigdecimal` feature).
+    BigDecimal,
+}
+
+/// Generates a [`Row`](crate::Row) struct with a field per column.
+///
+/// Field names are converted to `snake_case` and renamed back by
+/// `#[serde(rename)]` if needed. Every field is documented with the type
+/// of its column. Columns of unsupported types, e.g. `Variant`, `AggregateFunction`
+/// or `FixedString(N)` longer than 32 bytes, are left as `FIXME` comments.
+///
+/// Note that `MATERIALIZED` and `ALIAS` columns can be selected, but not inserted,
+/// so they should be excluded from structs used for inserting.
+#[derive(Debug, Clone)]
+pub struct RowCodegen {
+    name: String,
+    columns: Vec<Column>,
+    borrowed: bool,
+    time_crate: TimeCrate,
+    decimal_crate: DecimalCrate,
+}
+
+// serde implements `Serialize` and `Deserialize` only for arrays up to 32 items,
+// so longer `FixedString(N)` and `QBit(T, N)` are left as `FIXME`s.
+const MAX_ARRAY_LEN: usize = 32;
+
+// A Rust type of a field with an optional `#[serde(with)]` helper.
+struct FieldType {
+    ty: String,
+    with: Option<String>,
+    // Whether `::option` should be added to the helper for `Nullable(_)`.
+    option_helper: bool,
+}
+
+impl FieldType {
+    fn plain(ty: impl Into<String>) -> Option<Self> {
+        Some(Self {
+            ty: ty.into(),
+            with: None,
+            option_helper: false,
+        })
+    }
+}
+
+impl RowCodegen {
+    /// Creates a generator of a struct named `name`.
+    ///
+    /// Accepts [`Column`]s as well as [`ColumnInfo`]s.
+    ///
+    /// [`ColumnInfo`]: crate::schema::ColumnInfo
+    pub fn new(
+        name: impl Into<String>,
+        columns: impl IntoIterator<Item = impl Into<Column>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            columns: columns.into_iter().map(Into::into).collect(),
+            borrowed: false,
+            time_crate: TimeCrate::default(),
+            decimal_crate: DecimalCrate::default(),
+        }
+    }
+
+    /// Generates a borrowed struct with `&'a str` fields instead of `String`.
+    /// It's more efficient for selecting, see the README for details.
+    pub fn borrowed(mut self, enabled: bool) -> Self {
+        self.borrowed = enabled;
+        self
+    }
+
+    /// Sets the crate for dates and times, `time` by default.
+    pub fn with_time_crate(mut self, time_crate: TimeCrate) -> Self {
+        self.time_crate = time_crate;
+        self
+    }
+
+    /// Sets the crate for decimals, `rust_decimal` by default.
+    pub fn with_decimal_crate(mut self, decimal_crate: DecimalCrate) -> Self {
+        self.decimal_crate = decimal_crate;
+        self
+    }
+
+    /// Generates the code of the struct.
+    pub fn generate(&self) -> String {
+        let mut fields = String::new();
+        let mut names = HashSet::new();
+        let mut has_lifetime = false;
+
+        for column in &self.columns {
+            let name = unique_field_name(&column.name, &mut names);
+
+            let Some(field) = self.field_type(&column.data_type, false) else {
+                let _ = writeln!(
+                    fields,
+                    "    // FIXME: column `{}` of type `{}` is not supported",
+                    column.name, column.data_type
+                );
+                continue;
+            };
+
+            has_lifetime |= field.ty.contains("'a");
+
+            let _ = writeln!(fields, "    /// `{}`", column.data_type);
+            if name.strip_prefix("r#").unwrap_or(&name) != column.name {
+                let _ = writeln!(fields, "    #[serde(rename = {:?})]", column.name);
+            }
+            if let Some(with) = &field.with {
+                let _ = writeln!(fields, "    #[serde(with = \"{with}\")]");
+            }
+            let _ = writeln!(fields, "    pub {name}: {},", field.ty);
+        }
+
+        let lifetime = if has_lifetime { "<'a>" } else { "" };
+        format!(
+            "#[derive(Debug, clickhouse::Row, serde::Serialize, serde::Deserialize)]\n\
+             pub struct {}{lifetime} {{\n{fields}}}\n",
+            self.name
+        )
+    }
+
+    // Helpers can be applied only to top-level fields, so nested values
+    // are represented by raw types, e.g. `Array(DateTime)` by `Vec<u32>`.
+    fn field_type(&self, data_type: &DataTypeNode, nested: bool) -> Option<FieldType> {
+        use DataTypeNode as T;
+
+        let with = |ty: &str, helper: String| {
+            Some(FieldType {
+                ty: ty.into(),
+                with: Some(format!("clickhouse::serde::{helper}")),
+                option_helper: true,
+            })
+        };
+
+        match data_type {
+            T::Bool => FieldType::plain("bool"),
+            T::UInt8 => FieldType::plain("u8"),
+            T::UInt16 => FieldType::plain("u16"),
+            T::UInt32 => FieldType::plain("u32"),
+            T::UInt64 => FieldType::plain("u64"),
+            T::UInt128 => FieldType::plain("u128"),
+            T::Int8 => FieldType::plain("i8"),
+            T::Int16 => FieldType::plain("i16"),
+            T::Int32 => FieldType::plain("i32"),
+            T::Int64 => FieldType::plain("i64"),
+            T::Int128 => FieldType::plain("i128"),
+            T::UInt256 | T::Int256 if nested => FieldType::plain("[u8; 32]"),
+            T::UInt256 => with("ethnum::U256", "uint256".into()),
+            T::Int256 => with("ethnum::I256", "int256".into()),
+            T::Float32 => FieldType::plain("f32"),
+            T::Float64 => FieldType::plain("f64"),
+            T::Decimal(_, scale, kind) => {
+                let (raw, helper) = match kind {
+                    DecimalType::Decimal32 => ("i32", "d32"),
+                    DecimalType::Decimal64 => ("i64", "d64"),
+                    DecimalType::Decimal128 => ("i128", "d128"),
+                    DecimalType::Decimal256 => ("[u8; 32]", "d256"),
+                };
+                if nested {
+                    return FieldType::plain(raw);
+                }
+                let ty = match self.decimal_crate {
+                    DecimalCrate::RustDecimal => "rust_decimal::Decimal",
+                    DecimalCrate::BigDecimal => "bigdecimal::BigDecimal",
+                };
+                // The same helpers are used for `Nullable(Decimal(P, S))`.
+                Some(FieldType {
+                    ty: ty.into(),
+                    with: Some(format!("clickhouse::serde::decimal::{helper}::<{scale}>")),
+                    option_helper: false,
+                })
+            }
+            T::String if self.borrowed => FieldType::plain("&'a str"),
+            T::String => FieldType::plain("String"),
+            T::FixedString(len) if *len <= MAX_ARRAY_LEN => {
+                FieldType::plain(format!("[u8; {len}]"))
+            }
+            T::UUID if nested => None,
+            T::UUID => with("uuid::Uuid", "uuid".into()),
+            T::IPv4 if nested => FieldType::plain("u32"),
+            T::IPv4 => with("std::net::Ipv4Addr", "ipv4".into()),
+            T::IPv6 => FieldType::plain("std::net::Ipv6Addr"),
+            T::Date if nested => FieldType::plain("u16"),
+            T::Date32 if nested => FieldType::plain("i32"),
+            T::DateTime(_) if nested => FieldType::plain("u32"),
+            T::DateTime64(..) if nested => FieldType::plain("i64"),
+            T::Time if nested => FieldType::plain("i32"),
+            T::Time64(_) if nested => FieldType::plain("i64"),
+            T::Date => with(self.date_type(), format!("{}::date", self.time_module())),
+            T::Date32 => with(self.date_type(), format!("{}::date32", self.time_module())),
+            T::DateTime(_) => with(
+                self.datetime_type(),
+                format!("{}::datetime", self.time_module()),
+            ),
+            T::DateTime64(precision, _) => match precision_module(precision) {
+                Some(unit) => with(
+                    self.datetime_type(),
+                    format!("{}::datetime64::{unit}", self.time_module()),
+                ),
+                None => FieldType::plain("i64"),
+            },
+            T::Time => with(
+                self.duration_type(),
+                format!("{}::time", self.time_module()),
+            ),
+            T::Time64(precision) => match precision_module(precision) {
+                Some(unit) => with(
+                    self.duration_type(),
+                    format!("{}::time64::{unit}", self.time_module()),
+                ),
+                None => FieldType::plain("i64"),
+            },
+            T::Nullable(inner) => {
+                let inner = self.field_type(inner, nested)?;
+                Some(FieldType {
+                    ty: format!("Option<{}>", inner.ty),
+                    with: match inner.with {
+                        Some(with) if inner.option_helper => Some(format!("{with}::option")),
+                        with => with,
+                    },
+                    option_helper: false,
+                })
+            }
+            T::LowCardinality(inner) | T::SimpleAggregateFunction(_, inner) => {
+                self.field_type(inner, nested)
+            }
+            T::Array(inner) => {
+                let inner = self.field_type(inner, true)?;
+                FieldType::plain(format!("Vec<{}>", inner.ty))
+            }
+            T::Tuple(items) | T::NamedTuple(_, items) => self.tuple_type(items),
+            T::Nested(_, items) => {
+                let item = self.tuple_type(items)?;
+                FieldType::plain(format!("Vec<{}>", item.ty))
+            }
+            // The RowBinary serializer supports maps only as sequences of pairs.
+            T::Map([key, value]) => {
+                let item = self.tuple_type(&[(**key).clone(), (**value).clone()])?;
+                FieldType::plain(format!("Vec<{}>", item.ty))
+            }
+            T::Enum(EnumType::Enum8, _) => FieldType::plain("i8"),
+            T::Enum(EnumType::Enum16, _) => FieldType::plain("i16"),
+            T::Interval(_) => FieldType::plain("i64"),
+            T::Dynamic => FieldType::plain("clickhouse::value::Value"),
+            T::JSON(_) if nested => None,
+            T::JSON(_) => Some(FieldType {
+                ty: "clickhouse::value::Value".into(),
+                with: Some("clickhouse::serde::json".into()),
+                option_helper: false,
+            }),
+            T::QBit(elem, dim) if *dim <= MAX_ARRAY_LEN => match **elem {
+                T::Float32 => FieldType::plain(format!("[f32; {dim}]")),
+                T::Float64 => FieldType::plain(format!("[f64; {dim}]")),
+                _ => None,
+            },
+            T::Point => FieldType::plain("(f64, f64)"),
+            T::Ring | T::LineString => FieldType::plain("Vec<(f64, f64)>"),
+            T::Polygon | T::MultiLineString => FieldType::plain("Vec<Vec<(f64, f64)>>"),
+            T::MultiPolygon => FieldType::plain("Vec<Vec<Vec<(f64, f64)>>>"),
+            _ => None,
+        }
+    }
+
+    fn tuple_type(&self, items: &[DataTypeNode]) -> Option<FieldType> {
+        let items = items
+            .iter()
+            .map(|item| self.field_type(item, true).map(|f| f.ty))
+            .collect::<Option<Vec<_>>>()?;
+
+        let trailing_comma = if items.len() == 1 { "," } else { "" };
+        FieldType::plain(format!("({}{trailing_comma})", items.join(", ")))
+    }
+
+    fn time_module(&self) -> &'static str {
+        match self.time_crate {
+            TimeCrate::Time => "time",
+            TimeCrate::Chrono => "chrono",
+            TimeCrate::Jiff => "jiff",
+        }
+    }
+
+    fn date_type(&self) -> &'static str {
+        match self.time_crate {
+            TimeCrate::Time => "time::Date",
+            TimeCrate::Chrono => "chrono::NaiveDate",
+            TimeCrate::Jiff => "jiff::civil::Date",
+        }
+    }
+
+    fn datetime_type(&self) -> &'static str {
+        match self.time_crate {
+            TimeCrate::Time => "time::OffsetDateTime",
+            TimeCrate::Chrono => "chrono::DateTime<chrono::Utc>",
+            TimeCrate::Jiff => "jiff::Timestamp",
+        }
+    }
+
+    fn duration_type(&self) -> &'static str {
+        match self.time_crate {
+            TimeCrate::Time => "time::Duration",
+            TimeCrate::Chrono => "chrono::Duration",
+            TimeCrate::Jiff => "jiff::SignedDuration",
+        }
+    }
+}
+
+// Helpers exist only for precisions of whole units.
+fn precision_module(precision: &DateTimePrecision) -> Option<&'static str> {
+    match precision {
+        DateTimePrecision::Precision0 => Some("secs"),
+        DateTimePrecision::Precision3 => Some("millis"),
+        DateTimePrecision::Precision6 => Some("micros"),
+        DateTimePrecision::Precision9 => Some("nanos"),
+        _ => None,
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+// Converts a column name to a `snake_case` identifier, e.g. `userId` to `user_id`
+// and `items.name` to `items_name`. Raw identifiers are used for keywords.
+fn field_name(column: &str) -> String {
+    let mut name = String::with_capacity(column.len());
+    let mut prev_lower = false;
+
+    for c in column.chars() {
+        if c.is_ascii_uppercase() {
+            if prev_lower {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+            prev_lower = false;
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            name.push(c);
+            prev_lower = c != '_';
+        } else {
+            if !name.ends_with('_') {
+                name.push('_');
+            }
+            prev_lower = false;
+        }
+    }
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    if matches!(&*name, "_" | "crate" | "self" | "super") {
+        name.push('_');
+    } else if KEYWORDS.contains(&&*name) {
+        name.insert_str(0, "r#");
+    }
+
+    name
+}
+
+fn unique_field_name(column: &str, names: &mut HashSet<String>) -> String {
+    let name = field_name(column);
+    let mut unique = name.clone();
+    let mut suffix = 2;
+    while !names.insert(unique.clone()) {
+        unique = format!("{name}_{suffix}");
+        suffix += 1;
+    }
+    unique
+}
+
+// === parse_create_table ===
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    Quoted(&'a str),
+    Punct(char),
+}
+
+// Splits SQL into words, quoted literals and punctuation, skipping comments.
+// Returns tokens with their offsets.
+fn tokenize(sql: &str) -> Vec<(usize, Token<'_>)> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+
+    for segment in segments(sql) {
+        let code = match segment {
+            Segment::Quoted(quoted) => {
+                if !(quoted.starts_with("--")
+                    || quoted.starts_with('#')
+                    || quoted.starts_with("/*"))
+                {
+                    tokens.push((offset, Token::Quoted(quoted)));
+                }
+                offset += quoted.len();
+                continue;
+            }
+            Segment::Code(code) => code,
+        };
+
+        let mut iter = code.char_indices().peekable();
+        while let Some((idx, c)) = iter.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            if c.is_alphanumeric() || c == '_' {
+                let mut end = idx + c.len_utf8();
+                while let Some((idx, c)) =
+                    iter.next_if(|&(_, c)| c.is_alphanumeric() || c == '_' || c == '$')
+                {
+                    end = idx + c.len_utf8();
+                }
+                tokens.push((offset + idx, Token::Word(&code[idx..end])));
+            } else {
+                tokens.push((offset + idx, Token::Punct(c)));
+            }
+        }
+
+        offset += code.len();
+    }
+
+    tokens
+}
+
+/// Parses column definitions of a `CREATE TABLE` statement.
+///
+/// Indices, projections and constraints are skipped. Columns without
+/// an explicit type, e.g. `x DEFAULT 1`, are reported as errors.
+/// `NULL` after the type makes the column `Nullable`.
+pub fn parse_create_table(sql: &str) -> Result<Vec<Column>> {
+    let invalid = |reason: String| Error::InvalidParams(reason.into());
+
+    let tokens = tokenize(sql);
+    let Some(start) = tokens.iter().position(|(_, t)| *t == Token::Punct('(')) else {
+        return Err(invalid("no column definitions in CREATE TABLE".into()));
+    };
+
+    let mut columns = Vec::new();
+    let mut depth = 0;
+    let mut element_start = start + 1;
+
+    for (idx, (_, token)) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::Punct('(') => depth += 1,
+            Token::Punct(')') | Token::Punct(',') if depth == 1 => {
+                if let Some(column) = parse_column(sql, &tokens[element_start..idx])? {
+                    columns.push(column);
+                }
+                element_start = idx + 1;
+                if *token == Token::Punct(')') {
+                    return Ok(columns);
+                }
+            }
+            Token::Punct(')') => depth -= 1,
+            _ => {}
+        }
+    }
+
+    Err(invalid(
+        "unterminated column definitions in CREATE TABLE".into(),
+    ))
+}
+
+fn parse_column(sql: &str, tokens: &[(usize, Token<'_>)]) -> Result<Option<Column>> {
+    let invalid = |reason: String| Error::InvalidParams(reason.into());
+
+    let name = match tokens.first() {
+        None => return Ok(None),
+        Some((_, Token::Word(word))) => word.to_string(),
+        Some((_, Token::Quoted(quoted))) if !quoted.starts_with('\'') => unquote(quoted),
+        Some((offset, _)) => {
+            return Err(invalid(format!(
+                "unexpected token at {offset} in CREATE TABLE"
+            )))
+        }
+    };
+
+    let word = |idx: usize| match tokens.get(idx) {
+        Some((_, Token::Word(word))) => Some(word.to_ascii_uppercase()),
+        _ => None,
+    };
+
+    // `PRIMARY KEY (..)`, `INDEX name expr TYPE ..`, `PROJECTION name (..)`,
+    // `CONSTRAINT name CHECK ..`, but columns can be named so as well.
+    let may_be_other = matches!(tokens.first(), Some((_, Token::Word(_))))
+        && match &*name.to_ascii_uppercase() {
+            "PRIMARY" => word(1).as_deref() == Some("KEY"),
+            "INDEX" | "PROJECTION" | "CONSTRAINT" | "STATISTICS" => true,
+            _ => false,
+        };
+
+    let type_token = tokens.get(1).filter(|_| {
+        !matches!(
+            word(1).as_deref(),
+            Some("DEFAULT" | "MATERIALIZED" | "ALIAS" | "EPHEMERAL" | "CODEC" | "TTL" | "COMMENT")
+        )
+    });
+
+    let Some((type_start, Token::Word(type_name))) = type_token else {
+        if may_be_other {
+            return Ok(None);
+        }
+        return Err(invalid(format!("column {name} has no explicit type")));
+    };
+
+    let mut type_end = type_start + type_name.len();
+    let mut next = 2;
+
+    if let Some((_, Token::Punct('('))) = tokens.get(2) {
+        let mut depth = 0;
+        for (idx, (offset, token)) in tokens.iter().enumerate().skip(2) {
+            match token {
+                Token::Punct('(') => depth += 1,
+                Token::Punct(')') => depth -= 1,
+                _ => continue,
+            }
+            if depth == 0 {
+                type_end = offset + 1;
+                next = idx + 1;
+                break;
+            }
+        }
+    }
+
+    let data_type = match DataTypeNode::new(&sql[*type_start..type_end]) {
+        Ok(data_type) => data_type,
+        Err(_) if may_be_other => return Ok(None),
+        Err(err) => return Err(invalid(format!("invalid type of column {name}: {err}"))),
+    };
+
+    let data_type = if word(next).as_deref() == Some("NULL") {
+        DataTypeNode::Nullable(Box::new(data_type))
+    } else {
+        data_type
+    };
+
+    Ok(Some(Column::new(name, data_type)))
+}
+
+// "name" or `name` with backslash escapes.
+fn unquote(quoted: &str) -> String {
+    let inner = &quoted[1..quoted.len().saturating_sub(1).max(1)];
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            result.extend(chars.next());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_names() {
+        assert_eq!(field_name("id"), "id");
+        assert_eq!(field_name("userId"), "user_id");
+        assert_eq!(field_name("HTTPCode"), "httpcode");
+        assert_eq!(field_name("items.name"), "items_name");
+        assert_eq!(field_name("_ts"), "_ts");
+        assert_eq!(field_name("1st"), "_1st");
+        assert_eq!(field_name("type"), "r#type");
+        assert_eq!(field_name("self"), "self_");
+        assert_eq!(field_name("名前"), "__");
+
+        let mut names = HashSet::new();
+        assert_eq!(unique_field_name("a.b", &mut names), "a_b");
+        assert_eq!(unique_field_name("a_b", &mut names), "a_b_2");
+        assert_eq!(unique_field_name("aB", &mut names), "a_b_3");
+    }
+
+    #[test]
+    fn create_table() {
+        let columns = parse_create_table(
+            "CREATE TABLE IF NOT EXISTS db.t ON CLUSTER c
+            (
+                `id` UInt64 CODEC(Delta, ZSTD(1)), -- the id (ignored)
+                \"index\" Nullable(String) DEFAULT ')',
+                ts DateTime64(3, 'UTC') TTL ts + INTERVAL 1 DAY,
+                tags Array(LowCardinality(String)) COMMENT 'a, b',
+                opt String NULL,
+                req String NOT NULL,
+                INDEX idx_ts ts TYPE minmax GRANULARITY 1,
+                INDEX idx (id, ts) TYPE minmax,
+                PROJECTION p (SELECT id ORDER BY ts),
+                CONSTRAINT c CHECK id > 0,
+                PRIMARY KEY (id)
+            ) ENGINE = MergeTree ORDER BY id",
+        )
+        .unwrap();
+
+        let t = |s: &str| DataTypeNode::new(s).unwrap();
+        assert_eq!(
+            columns,
+            [
+                Column::new("id".into(), t("UInt64")),
+                Column::new("index".into(), t("Nullable(String)")),
+                Column::new("ts".into(), t("DateTime64(3, 'UTC')")),
+                Column::new("tags".into(), t("Array(LowCardinality(String))")),
+                Column::new("opt".into(), t("Nullable(String)")),
+                Column::new("req".into(), t("String")),
+            ]
+        );
+
+        let err = |sql: &str| parse_create_table(sql).unwrap_err().to_string();
+        assert!(err("CREATE TABLE t AS other").contains("no column definitions"));
+        assert!(err("CREATE TABLE t (a UInt8").contains("unterminated"));
+        assert!(err("CREATE TABLE t (a DEFAULT 1)").contains("column a has no explicit type"));
+        assert!(err("CREATE TABLE t (a Foo)").contains("invalid type of column a"));
+    }
+
+    #[test]
+    fn generate() {
+        let t = |s: &str| DataTypeNode::new(s).unwrap();
+        let columns = [
+            Column::new("id".into(), t("UInt64")),
+            Column::new("userName".into(), t("LowCardinality(Nullable(String))")),
+            Column::new("type".into(), t("Enum8('a' = 1)")),
+            Column::new("ts".into(), t("DateTime64(3, 'UTC')")),
+            Column::new("day".into(), t("Nullable(Date)")),
+            Column::new("price".into(), t("Nullable(Decimal(18, 4))")),
+            Column::new("uid".into(), t("UUID")),
+            Column::new("times".into(), t("Array(DateTime)")),
+            Column::new("attrs".into(), t("Map(String, Tuple(UInt8))")),
+            Column::new("hash".into(), t("FixedString(32)")),
+            Column::new("sha512".into(), t("FixedString(64)")),
+            Column::new("state".into(), t("AggregateFunction(uniq, UInt64)")),
+        ];
+
+        assert_eq!(
+            RowCodegen::new("Event", columns.clone())
+                .borrowed(true)
+                .generate(),
+            r#"#[derive(Debug, clickhouse::Row, serde::Serialize, serde::Deserialize)]
+pub struct Event<'a> {
+    /// `UInt64`
+    pub id: u64,
+    /// `LowCardinality(Nullable(String))`
+    #[serde(rename = "userName")]
+    pub user_name: Option<&'a str>,
+    /// `Enum8('a' = 1)`
+    pub r#type: i8,
+    /// `DateTime64(3, 'UTC')`
+    #[serde(with = "clickhouse::serde::time::datetime64::millis")]
+    pub ts: time::OffsetDateTime,
+    /// `Nullable(Date)`
+    #[serde(with = "clickhouse::serde::time::date::option")]
+    pub day: Option<time::Date>,
+    /// `Nullable(Decimal(18, 4))`
+    #[serde(with = "clickhouse::serde::decimal::d64::<4>")]
+    pub price: Option<rust_decimal::Decimal>,
+    /// `UUID`
+    #[serde(with = "clickhouse::serde::uuid")]
+    pub uid: uuid::Uuid,
+    /// `Array(DateTime)`
+    pub times: Vec<u32>,
+    /// `Map(String, Tuple(UInt8))`
+    pub attrs: Vec<(&'a str, (u8,))>,
+    /// `FixedString(32)`
+    pub hash: [u8; 32],
+    // FIXME: column `sha512` of type `FixedString(64)` is not supported
+    // FIXME: column `state` of type `AggregateFunction(uniq, UInt64)` is not supported
+}
+"#
+        );
+
+        let code = RowCodegen::new("Event", columns)
+            .with_time_crate(TimeCrate::Chrono)
+            .with_decimal_crate(DecimalCrate::BigDecimal)
+            .generate();
+        assert!(code.contains("pub struct Event {"));
+        assert!(code.contains("pub user_name: Option<String>,"));
+        assert!(code.contains(
+            "chrono::datetime64::millis\")]\n    pub ts: chrono::DateTime<chrono::Utc>,"
+        ));
+        assert!(code.contains("pub price: Option<bigdecimal::BigDecimal>,"));
+
+        let code = RowCodegen::new(
+            "Embedding",
+            [Column::new("v".into(), t("QBit(Float32, 128)"))],
+        )
+        .generate();
+        assert!(code.contains("// FIXME: column `v` of type `QBit(Float32, 128)` is not supported"));
+    }
+}
@@ -0,0 +1,93 @@
+use std::{cell::RefCell, collections::HashSet, sync::Arc};
+
+/// The maximum number of cached strings, so that high-cardinality columns
+/// don't make the cache grow without bound.
+const MAX_STRINGS: usize = 1 << 16;
+
+/// A cache of strings decoded by [`crate::serde::interned`], owned by a cursor.
+#[derive(Default)]
+pub(crate) struct StringInterner {
+    strings: HashSet<Arc<str>>,
+}
+
+impl StringInterner {
+    fn intern(&mut self, value: &str) -> Arc<str> {
+        if let Some(interned) = self.strings.get(value) {
+            return interned.clone();
+        }
+
+        let interned = Arc::<str>::from(value);
+        if self.strings.len() < MAX_STRINGS {
+            self.strings.insert(interned.clone());
+        }
+        interned
+    }
+}
+
+thread_local! {
+    /// The interner of the cursor that is currently deserializing a row on this thread.
+    static CURRENT: RefCell<Option<StringInterner>> = const { RefCell::new(None) };
+}
+
+/// Makes `interner` available to [`intern`] while `f` is running.
+pub(crate) fn with_interner<T>(interner: &mut Option<StringInterner>, f: impl FnOnce() -> T) -> T {
+    struct Guard<'a>(&'a mut Option<StringInterner>);
+
+    impl Drop for Guard<'_> {
+        fn drop(&mut self) {
+            // Return the interner even if `f` panics, so it isn't used by another cursor.
+            *self.0 = CURRENT.with(|current| current.borrow_mut().take());
+        }
+    }
+
+    if interner.is_none() {
+        return f();
+    }
+
+    CURRENT.with(|current| *current.borrow_mut() = interner.take());
+    let _guard = Guard(interner);
+    f()
+}
+
+/// Returns a shared string equal to `value`, cached by the current cursor if it has an interner.
+pub(crate) fn intern(value: &str) -> Arc<str> {
+    CURRENT.with(|current| match &mut *current.borrow_mut() {
+        Some(interner) => interner.intern(value),
+        None => Arc::from(value),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_shares_strings_while_enabled() {
+        let mut interner = Some(StringInterner::default());
+
+        let (a, b) = with_interner(&mut interner, || (intern("foo"), intern("foo")));
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(interner.is_some());
+
+        // The cache is kept between rows.
+        let c = with_interner(&mut interner, || intern("foo"));
+        assert!(Arc::ptr_eq(&a, &c));
+
+        // Strings are not shared without the interner.
+        let (d, e) = (intern("foo"), intern("foo"));
+        assert_eq!(d, e);
+        assert!(!Arc::ptr_eq(&d, &e));
+    }
+
+    #[test]
+    fn it_returns_interner_on_panic() {
+        let mut interner = Some(StringInterner::default());
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            with_interner(&mut interner, || panic!("oops"))
+        }));
+        assert!(result.is_err());
+        assert!(interner.is_some());
+        assert!(CURRENT.with(|current| current.borrow().is_none()));
+    }
+}
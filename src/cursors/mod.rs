@@ -1,6 +1,7 @@
-pub(crate) use self::raw::RawCursor;
 pub use self::{bytes::BytesCursor, row::RowCursor};
+pub(crate) use self::{interner::intern, raw::RawCursor};
 
 mod bytes;
+mod interner;
 mod raw;
 mod row;
@@ -1,7 +1,10 @@
 use crate::row_metadata::RowMetadata;
 use crate::{
     bytes_ext::BytesExt,
-    cursors::RawCursor,
+    cursors::{
+        interner::{self, StringInterner},
+        RawCursor,
+    },
     error::{Error, Result},
     response::Response,
     rowbinary, RowRead,
@@ -19,6 +22,8 @@ pub struct RowCursor<T> {
     /// [`None`] until the first call to [`RowCursor::next()`],
     /// as [`RowCursor::new`] is not `async`, so it loads lazily.
     row_metadata: Option<RowMetadata>,
+    /// Caches strings decoded by [`crate::serde::interned`], if enabled.
+    interner: Option<StringInterner>,
     _marker: PhantomData<T>,
 }
 
@@ -29,10 +34,22 @@ impl<T> RowCursor<T> {
             raw: RawCursor::new(response),
             bytes: BytesExt::default(),
             row_metadata: None,
+            interner: None,
             validation,
         }
     }
 
+    /// Enables caching of strings decoded by [`crate::serde::interned`].
+    ///
+    /// Equal values of such fields, e.g. of `LowCardinality(String)` columns,
+    /// are shared between rows as the same `Arc<str>`, instead of being allocated
+    /// for every row. The cache lives as long as the cursor and is limited in size,
+    /// so values of high-cardinality columns are still decoded, but not cached.
+    pub fn with_string_interner(mut self) -> Self {
+        self.interner = Some(StringInterner::default());
+        self
+    }
+
     #[cold]
     #[inline(never)]
     async fn read_columns(&mut self) -> Result<()>
@@ -95,10 +112,12 @@ impl<T> RowCursor<T> {
         loop {
             if self.bytes.remaining() > 0 {
                 let mut slice = self.bytes.slice();
-                let result = rowbinary::deserialize_row::<T::Value<'_>>(
-                    &mut slice,
-                    self.row_metadata.as_ref(),
-                );
+                let result = interner::with_interner(&mut self.interner, || {
+                    rowbinary::deserialize_row::<T::Value<'_>>(
+                        &mut slice,
+                        self.row_metadata.as_ref(),
+                    )
+                });
 
                 match result {
                     Ok(value) => {
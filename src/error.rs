@@ -45,6 +45,16 @@ pub enum Error {
     InvalidColumnsHeader(#[source] BoxedError),
     #[error("unsupported: {0}")]
     Unsupported(String),
+    #[error("statement #{index} of the script failed: {source}")]
+    Script {
+        /// The zero-based index of the failed statement in the script.
+        index: usize,
+        #[source]
+        source: Box<Error>,
+    },
+    #[cfg(feature = "migrations")]
+    #[error("migration error: {0}")]
+    Migration(String),
     #[error("{0}")]
     Other(BoxedError),
 }
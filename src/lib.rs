@@ -10,18 +10,28 @@ pub use self::{
     row::{Row, RowOwned, RowRead, RowWrite},
 };
 use self::{error::Result, http_client::HttpClient};
-pub use clickhouse_derive::Row;
+pub use clickhouse_derive::{variant, Row};
 use std::{collections::HashMap, fmt::Display, sync::Arc};
 
+pub mod aggregate;
+#[cfg(feature = "builder")]
+pub mod builder;
+#[cfg(feature = "codegen")]
+pub mod codegen;
 pub mod error;
 pub mod insert;
 #[cfg(feature = "inserter")]
 pub mod inserter;
+#[cfg(feature = "migrations")]
+pub mod migrations;
 pub mod query;
+pub mod schema;
 pub mod serde;
 pub mod sql;
 #[cfg(feature = "test-util")]
 pub mod test;
+pub mod types;
+pub mod value;
 
 mod bytes_ext;
 mod compression;
@@ -36,6 +46,8 @@ mod rowbinary;
 #[cfg(feature = "inserter")]
 mod ticks;
 
+const DEFAULT_INLINE_SET_LIMIT: usize = 1000;
+
 /// A client containing HTTP pool.
 #[derive(Clone, Debug)]
 pub struct Client {
@@ -49,6 +61,7 @@ pub struct Client {
     headers: HashMap<String, String>,
     products_info: Vec<ProductInfo>,
     validation: bool,
+    inline_set_limit: usize,
 
     #[cfg(feature = "test-util")]
     mocked: bool,
@@ -107,6 +120,7 @@ impl Client {
             headers: HashMap::new(),
             products_info: Vec::default(),
             validation: true,
+            inline_set_limit: DEFAULT_INLINE_SET_LIMIT,
             #[cfg(feature = "test-util")]
             mocked: false,
         }
@@ -322,6 +336,95 @@ impl Client {
         query::Query::new(self, query)
     }
 
+    /// Executes a script of statements separated by `;` one by one, in order.
+    ///
+    /// The script is split by the same tokenizer as query templates, so `;`
+    /// inside string literals, quoted identifiers, comments and heredocs
+    /// doesn't end a statement. Empty statements and ones containing only
+    /// comments are skipped. Unlike [`Client::query`], `?` isn't a placeholder
+    /// in scripts, statements are sent as is.
+    ///
+    /// Execution stops at the first failed statement, which is reported
+    /// as [`error::Error::Script`] with its index and the server error.
+    /// Statements executed before it are not rolled back.
+    ///
+    /// Every statement is a separate request, so `SET` and temporary tables
+    /// don't outlive it, unless the client is bound to a session
+    /// by the `session_id` option.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn example() -> clickhouse::error::Result<()> {
+    /// let client = clickhouse::Client::default()
+    ///     .with_option("session_id", "my-session");
+    ///
+    /// client
+    ///     .execute_script(
+    ///         "CREATE TEMPORARY TABLE tmp (s String) ENGINE = Memory;
+    ///          INSERT INTO tmp VALUES ('a;b'); -- ;
+    ///          INSERT INTO some SELECT * FROM tmp;",
+    ///     )
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn execute_script(&self, script: &str) -> Result<()> {
+        for (index, statement) in sql::statements(script).into_iter().enumerate() {
+            let query = query::Query::with_sql(self, sql::SqlBuilder::raw(statement));
+            query.execute().await.map_err(|err| error::Error::Script {
+                index,
+                source: Box::new(err),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Describes columns of the table, see [`schema::ColumnInfo`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # async fn example() -> clickhouse::error::Result<()> {
+    /// let client = clickhouse::Client::default();
+    /// for info in client.describe_table("default", "events").await? {
+    ///     println!("{}: {:?}", info.column, info.default_expression);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn describe_table(
+        &self,
+        database: &str,
+        table: &str,
+    ) -> Result<Vec<schema::ColumnInfo>> {
+        schema::describe_table(self, database, table).await
+    }
+
+    /// Returns names of all databases, ordered by name.
+    pub async fn list_databases(&self) -> Result<Vec<String>> {
+        schema::list_databases(self).await
+    }
+
+    /// Returns names of tables, views and dictionaries in the database, ordered by name.
+    pub async fn list_tables(&self, database: &str) -> Result<Vec<String>> {
+        schema::list_tables(self, database).await
+    }
+
+    /// Returns the engine of the table.
+    ///
+    /// Returns [`error::Error::RowNotFound`] if there is no such table.
+    pub async fn table_engine(&self, database: &str, table: &str) -> Result<schema::TableEngine> {
+        schema::table_engine(self, database, table).await
+    }
+
+    /// Returns partitions of the table with active parts, ordered by partition ID.
+    pub async fn partitions(
+        &self,
+        database: &str,
+        table: &str,
+    ) -> Result<Vec<schema::PartitionInfo>> {
+        schema::partitions(self, database, table).await
+    }
+
     /// Enables or disables [`Row`] data types validation against the database schema
     /// at the cost of performance. Validation is enabled by default, and in this mode,
     /// the client will use `RowBinaryWithNamesAndTypes` format.
@@ -345,6 +448,21 @@ impl Client {
         self
     }
 
+    /// Sets the maximum number of values of sets bound by [`query::Query::bind_set`],
+    /// which are inlined into the query. Larger sets are sent as external tables
+    /// in the request body, so they don't count towards `max_query_size`.
+    ///
+    /// The default is 1000 values.
+    pub fn with_inline_set_limit(mut self, limit: usize) -> Self {
+        self.inline_set_limit = limit;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn get_inline_set_limit(&self) -> usize {
+        self.inline_set_limit
+    }
+
     /// Used internally to check if the validation mode is enabled,
     /// as it takes into account the `test-util` feature flag.
     #[inline]
@@ -0,0 +1,685 @@
+//! Versioned schema migrations.
+//!
+//! Migrations are SQL scripts named `{version}_{name}.sql` or, if they can be
+//! reverted, `{version}_{name}.up.sql` together with `{version}_{name}.down.sql`,
+//! where `version` is a number, e.g. `0001_create_events.up.sql`.
+//! A script can contain several statements, see [`Client::execute_script`].
+//!
+//! Migrations are loaded from a directory at runtime by [`Migrator::from_dir`]
+//! or embedded into the binary by [`embed_migrations!`]. [`Migrator::run`]
+//! applies pending ones in the order of versions and records their versions
+//! and checksums in the `_migrations` table, so scripts changed or removed
+//! after they have been applied are detected as [`Drift`].
+//!
+//! ```
+//! # async fn example() -> clickhouse::error::Result<()> {
+//! use clickhouse::migrations::Migrator;
+//!
+//! let client = clickhouse::Client::default();
+//! let migrator = Migrator::from_dir("migrations")?
+//!     .with_database("analytics")
+//!     .with_cluster("default");
+//!
+//! let applied_versions = migrator.run(&client).await?;
+//! # Ok(()) }
+//! ```
+//!
+//! Note that migrations aren't transactional: if a script fails, statements
+//! executed before the failed one stay applied. Also, there is no locking,
+//! so migrations should be run by a single process at a time.
+
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+// Required by `#[derive(Row)]`.
+use crate as clickhouse;
+use crate::{
+    error::{Error, Result},
+    query::Query,
+    sql::{self, Identifier},
+    Client, Row,
+};
+
+/// Embeds migrations from a directory into the binary.
+///
+/// The path is relative to the directory containing `Cargo.toml`.
+/// Expands to `Migrator::from_files([..])`, which returns `Result<Migrator>`.
+///
+/// Changes of embedded files trigger recompilation, but new files don't;
+/// add `println!("cargo:rerun-if-changed=migrations")` to `build.rs` for that.
+///
+/// ```ignore
+/// let migrator = clickhouse::migrations::embed_migrations!("migrations")?;
+/// ```
+pub use clickhouse_derive::embed_migrations;
+
+const DEFAULT_TABLE: &str = "_migrations";
+
+// === Migration ===
+
+/// A versioned migration script with an optional script to revert it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    version: u64,
+    name: String,
+    up: String,
+    down: Option<String>,
+}
+
+impl Migration {
+    /// Creates a migration, which can't be reverted.
+    pub fn new(version: u64, name: impl Into<String>, up: impl Into<String>) -> Self {
+        Self {
+            version,
+            name: name.into(),
+            up: up.into(),
+            down: None,
+        }
+    }
+
+    /// Sets a script reverting the migration.
+    pub fn with_down(mut self, down: impl Into<String>) -> Self {
+        self.down = Some(down.into());
+        self
+    }
+
+    /// Returns the version of the migration.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns the name of the migration.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the script applying the migration.
+    pub fn up(&self) -> &str {
+        &self.up
+    }
+
+    /// Returns the script reverting the migration, if any.
+    pub fn down(&self) -> Option<&str> {
+        self.down.as_deref()
+    }
+
+    /// Returns the checksum of the up script, which is recorded when
+    /// the migration is applied. `\r` is ignored, so checkouts with
+    /// different line endings have the same checksum.
+    pub fn checksum(&self) -> String {
+        // FNV-1a, which is enough to detect changes.
+        let mut hash = 0xcbf2_9ce4_8422_2325u64;
+        for byte in self.up.bytes().filter(|&b| b != b'\r') {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        format!("{hash:016x}")
+    }
+}
+
+impl fmt::Display for Migration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.version, self.name)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum ScriptKind {
+    Up,
+    Down,
+}
+
+// Parses `{version}_{name}.sql`, `{version}_{name}.up.sql` and `{version}_{name}.down.sql`.
+fn parse_file_name(file_name: &str) -> Option<(u64, &str, ScriptKind)> {
+    let stem = file_name.strip_suffix(".sql")?;
+    let (stem, kind) = if let Some(stem) = stem.strip_suffix(".up") {
+        (stem, ScriptKind::Up)
+    } else if let Some(stem) = stem.strip_suffix(".down") {
+        (stem, ScriptKind::Down)
+    } else {
+        (stem, ScriptKind::Up)
+    };
+
+    let (version, name) = stem.split_once('_')?;
+    if name.is_empty() || !version.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((version.parse().ok()?, name, kind))
+}
+
+// === AppliedMigration ===
+
+/// A migration recorded as applied in the migrations table.
+#[derive(Debug, Clone, PartialEq, Eq, Row, Serialize, Deserialize)]
+pub struct AppliedMigration {
+    /// The version of the migration.
+    pub version: u64,
+    /// The name of the migration.
+    pub name: String,
+    /// The checksum of the up script, see [`Migration::checksum`].
+    pub checksum: String,
+}
+
+// === Drift ===
+
+/// A mismatch between applied migrations and local ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Drift {
+    /// An applied migration is missing locally.
+    Missing {
+        /// The version of the migration.
+        version: u64,
+        /// The name of the migration.
+        name: String,
+    },
+    /// The up script of an applied migration has been changed.
+    ChecksumMismatch {
+        /// The version of the migration.
+        version: u64,
+        /// The name of the migration.
+        name: String,
+    },
+    /// A pending migration is older than the latest applied one.
+    OutOfOrder {
+        /// The version of the migration.
+        version: u64,
+        /// The name of the migration.
+        name: String,
+    },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { version, name } => {
+                write!(f, "migration {version}_{name} is applied, but missing")
+            }
+            Self::ChecksumMismatch { version, name } => {
+                write!(f, "migration {version}_{name} is changed after applying")
+            }
+            Self::OutOfOrder { version, name } => {
+                write!(
+                    f,
+                    "migration {version}_{name} is older than the latest applied one"
+                )
+            }
+        }
+    }
+}
+
+// === Migrator ===
+
+/// Applies and reverts [`Migration`]s, see the [module-level docs](self).
+#[derive(Debug, Clone)]
+pub struct Migrator {
+    migrations: Vec<Migration>,
+    database: Option<String>,
+    table: String,
+    cluster: Option<String>,
+    dry_run: bool,
+}
+
+impl Migrator {
+    /// Creates a migrator from migrations with unique versions.
+    pub fn new(migrations: impl IntoIterator<Item = Migration>) -> Result<Self> {
+        let mut migrations = migrations.into_iter().collect::<Vec<_>>();
+        migrations.sort_by_key(|m| m.version);
+
+        if let Some(pair) = migrations.windows(2).find(|p| p[0].version == p[1].version) {
+            return Err(Error::Migration(format!(
+                "migrations {} and {} have the same version",
+                pair[0], pair[1]
+            )));
+        }
+
+        Ok(Self {
+            migrations,
+            database: None,
+            table: DEFAULT_TABLE.into(),
+            cluster: None,
+            dry_run: false,
+        })
+    }
+
+    /// Creates a migrator from pairs of file names and their contents.
+    ///
+    /// See the [module-level docs](self) for the naming of files.
+    pub fn from_files<'a>(files: impl IntoIterator<Item = (&'a str, &'a str)>) -> Result<Self> {
+        let mut migrations = BTreeMap::new();
+        let mut downs = Vec::new();
+
+        for (file_name, script) in files {
+            let Some((version, name, kind)) = parse_file_name(file_name) else {
+                return Err(Error::Migration(format!(
+                    "invalid migration file name {file_name}, \
+                     expected {{version}}_{{name}}[.up|.down].sql"
+                )));
+            };
+
+            if kind == ScriptKind::Down {
+                downs.push((file_name, version, name, script));
+                continue;
+            }
+
+            let migration = Migration::new(version, name, script);
+            if let Some(prev) = migrations.insert(version, migration) {
+                return Err(Error::Migration(format!(
+                    "migrations {prev} and {version}_{name} have the same version"
+                )));
+            }
+        }
+
+        for (file_name, version, name, script) in downs {
+            match migrations.get_mut(&version) {
+                Some(migration) if migration.name == name && migration.down.is_none() => {
+                    migration.down = Some(script.into());
+                }
+                _ => {
+                    return Err(Error::Migration(format!(
+                        "{file_name} doesn't match any up script"
+                    )));
+                }
+            }
+        }
+
+        Self::new(migrations.into_values())
+    }
+
+    /// Loads migrations from `*.sql` files in the directory, other files are
+    /// ignored. Files are read synchronously, so it's supposed to be called
+    /// on startup.
+    pub fn from_dir(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let read_error =
+            |path: &Path, err| Error::Migration(format!("cannot read {}: {err}", path.display()));
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(path).map_err(|err| read_error(path, err))? {
+            let path = entry.map_err(|err| read_error(path, err))?.path();
+            if !path.is_file() || path.extension().map_or(true, |ext| ext != "sql") {
+                continue;
+            }
+
+            let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                let reason = format!("non UTF-8 file name {}", path.display());
+                return Err(Error::Migration(reason));
+            };
+
+            let script = fs::read_to_string(&path).map_err(|err| read_error(&path, err))?;
+            files.push((file_name.to_string(), script));
+        }
+
+        Self::from_files(files.iter().map(|(name, script)| (&**name, &**script)))
+    }
+
+    /// Runs migrations and keeps the migrations table in `database`,
+    /// which is created if it doesn't exist.
+    /// By default, the database of the client is used.
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.database = Some(database.into());
+        self
+    }
+
+    /// Sets the name of the table recording applied migrations.
+    /// The default is `_migrations`.
+    pub fn with_table(mut self, table: impl Into<String>) -> Self {
+        self.table = table.into();
+        self
+    }
+
+    /// Creates the database and the migrations table `ON CLUSTER`, the latter
+    /// with the `ReplicatedReplacingMergeTree` engine, so all replicas share it.
+    ///
+    /// Scripts are executed as is, so DDL statements in them should contain
+    /// `ON CLUSTER` too. Such statements wait until they are executed on all hosts,
+    /// but at most `distributed_ddl_task_timeout` (180s by default), and fail otherwise,
+    /// so the migration isn't recorded as applied.
+    pub fn with_cluster(mut self, cluster: impl Into<String>) -> Self {
+        self.cluster = Some(cluster.into());
+        self
+    }
+
+    /// In the dry-run mode, statements are printed to stdout instead of
+    /// being executed. The migrations table is still read to find
+    /// pending migrations.
+    pub fn with_dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Returns migrations ordered by version.
+    pub fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
+
+    /// Returns migrations recorded as applied, ordered by version.
+    pub async fn applied(&self, client: &Client) -> Result<Vec<AppliedMigration>> {
+        let exists = match &self.database {
+            Some(database) => client.query("EXISTS TABLE ?.?").bind(Identifier(database)),
+            None => client.query("EXISTS TABLE ?"),
+        };
+        let exists = exists.bind(Identifier(&self.table));
+        if exists.fetch_one::<u8>().await? == 0 {
+            return Ok(Vec::new());
+        }
+
+        self.client(client)
+            .query("SELECT ?fields FROM ? FINAL WHERE applied ORDER BY version")
+            .bind(Identifier(&self.table))
+            .fetch_all()
+            .await
+    }
+
+    /// Returns mismatches between applied migrations and local ones.
+    pub async fn drift(&self, client: &Client) -> Result<Vec<Drift>> {
+        Ok(self.compare(&self.applied(client).await?))
+    }
+
+    /// Applies pending migrations in the order of versions, returning
+    /// their versions. Nothing is applied if any [`Drift`] is detected.
+    pub async fn run(&self, client: &Client) -> Result<Vec<u64>> {
+        let applied = self.applied(client).await?;
+        check_drift(self.compare(&applied))?;
+        self.create_table(client).await?;
+
+        let client = self.client(client);
+        let mut versions = Vec::new();
+
+        let pending = self
+            .migrations
+            .iter()
+            .filter(|m| !applied.iter().any(|a| a.version == m.version));
+
+        for migration in pending {
+            self.execute_script(&client, migration, migration.up())
+                .await?;
+            self.record(&client, migration, true).await?;
+            versions.push(migration.version);
+        }
+
+        Ok(versions)
+    }
+
+    /// Reverts applied migrations with versions greater than `target`
+    /// by their down scripts in the reverse order, returning their versions.
+    /// Nothing is reverted if any [`Drift`] is detected or any of these
+    /// migrations has no down script.
+    pub async fn revert(&self, client: &Client, target: u64) -> Result<Vec<u64>> {
+        let applied = self.applied(client).await?;
+        check_drift(self.compare(&applied))?;
+
+        let to_revert = applied
+            .iter()
+            .rev()
+            .filter(|a| a.version > target)
+            .filter_map(|a| self.find(a.version))
+            .collect::<Vec<_>>();
+
+        if let Some(migration) = to_revert.iter().find(|m| m.down.is_none()) {
+            let reason = format!("migration {migration} has no down script");
+            return Err(Error::Migration(reason));
+        }
+
+        let client = self.client(client);
+        let mut versions = Vec::new();
+
+        for migration in to_revert {
+            let down = migration.down().unwrap_or_default();
+            self.execute_script(&client, migration, down).await?;
+            self.record(&client, migration, false).await?;
+            versions.push(migration.version);
+        }
+
+        Ok(versions)
+    }
+
+    fn find(&self, version: u64) -> Option<&Migration> {
+        let idx = self
+            .migrations
+            .binary_search_by_key(&version, |m| m.version)
+            .ok()?;
+        Some(&self.migrations[idx])
+    }
+
+    fn compare(&self, applied: &[AppliedMigration]) -> Vec<Drift> {
+        let mut drift = Vec::new();
+
+        for a in applied {
+            let (version, name) = (a.version, a.name.clone());
+            match self.find(a.version) {
+                None => drift.push(Drift::Missing { version, name }),
+                Some(m) if m.checksum() != a.checksum => {
+                    drift.push(Drift::ChecksumMismatch { version, name });
+                }
+                Some(_) => {}
+            }
+        }
+
+        if let Some(latest) = applied.last().map(|a| a.version) {
+            drift.extend(
+                self.migrations
+                    .iter()
+                    .filter(|m| m.version < latest)
+                    .filter(|m| !applied.iter().any(|a| a.version == m.version))
+                    .map(|m| Drift::OutOfOrder {
+                        version: m.version,
+                        name: m.name.clone(),
+                    }),
+            );
+        }
+
+        drift
+    }
+
+    fn client(&self, client: &Client) -> Client {
+        let mut client = client.clone();
+
+        // `ON CLUSTER` DDLs wait for all hosts up to `distributed_ddl_task_timeout`
+        // and fail if any of them fails or times out. It's the default mode,
+        // but it can be overridden in settings profiles.
+        if self.cluster.is_some() {
+            client = client.with_option("distributed_ddl_output_mode", "throw");
+        }
+
+        match &self.database {
+            Some(database) => client.with_database(database),
+            None => client,
+        }
+    }
+
+    fn on_cluster(&self, query: Query) -> Query {
+        match &self.cluster {
+            Some(cluster) => query.bind(Identifier(cluster)),
+            None => query,
+        }
+    }
+
+    async fn create_table(&self, client: &Client) -> Result<()> {
+        let on_cluster = if self.cluster.is_some() {
+            " ON CLUSTER ?"
+        } else {
+            ""
+        };
+
+        if let Some(database) = &self.database {
+            let sql = format!("CREATE DATABASE IF NOT EXISTS ?{on_cluster}");
+            let query = client.query(&sql).bind(Identifier(database));
+            self.execute(self.on_cluster(query)).await?;
+        }
+
+        let engine = if self.cluster.is_some() {
+            "ReplicatedReplacingMergeTree('/clickhouse/tables/{uuid}/{shard}', '{replica}', updated_at)"
+        } else {
+            "ReplacingMergeTree(updated_at)"
+        };
+
+        let sql = format!(
+            "CREATE TABLE IF NOT EXISTS ?{on_cluster} (\
+                version UInt64, \
+                name String, \
+                checksum String, \
+                applied Bool, \
+                updated_at DateTime64(6) DEFAULT now64(6)\
+            ) ENGINE = {engine} ORDER BY version"
+        );
+        let client = self.client(client);
+        let query = client.query(&sql).bind(Identifier(&self.table));
+        self.execute(self.on_cluster(query)).await
+    }
+
+    async fn execute_script(
+        &self,
+        client: &Client,
+        migration: &Migration,
+        script: &str,
+    ) -> Result<()> {
+        if self.dry_run {
+            println!("-- {migration}");
+            for statement in sql::statements(script) {
+                println!("{statement};");
+            }
+            return Ok(());
+        }
+
+        client
+            .execute_script(script)
+            .await
+            .map_err(|err| Error::Migration(format!("migration {migration} failed: {err}")))
+    }
+
+    async fn record(&self, client: &Client, migration: &Migration, applied: bool) -> Result<()> {
+        let query = client
+            .query("INSERT INTO ? (version, name, checksum, applied) VALUES (?, ?, ?, ?)")
+            .bind(Identifier(&self.table))
+            .bind(migration.version)
+            .bind(&migration.name)
+            .bind(migration.checksum())
+            .bind(applied);
+        self.execute(query).await
+    }
+
+    async fn execute(&self, query: Query) -> Result<()> {
+        if self.dry_run {
+            println!("{};", query.sql_display());
+            Ok(())
+        } else {
+            query.execute().await
+        }
+    }
+}
+
+fn check_drift(drift: Vec<Drift>) -> Result<()> {
+    if drift.is_empty() {
+        return Ok(());
+    }
+
+    let list = drift
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ");
+    Err(Error::Migration(format!("drift detected: {list}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_names() {
+        use ScriptKind::*;
+
+        assert_eq!(parse_file_name("1_init.sql"), Some((1, "init", Up)));
+        assert_eq!(parse_file_name("0002_a_b.up.sql"), Some((2, "a_b", Up)));
+        assert_eq!(parse_file_name("0002_a_b.down.sql"), Some((2, "a_b", Down)));
+        assert_eq!(parse_file_name("init.sql"), None);
+        assert_eq!(parse_file_name("_init.sql"), None);
+        assert_eq!(parse_file_name("1_.sql"), None);
+        assert_eq!(parse_file_name("v1_init.sql"), None);
+        assert_eq!(parse_file_name("1_init.txt"), None);
+    }
+
+    #[test]
+    fn from_files() {
+        let migrator = Migrator::from_files([
+            ("2_b.down.sql", "DROP TABLE b"),
+            ("2_b.up.sql", "CREATE TABLE b"),
+            ("1_a.sql", "CREATE TABLE a"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            migrator.migrations(),
+            [
+                Migration::new(1, "a", "CREATE TABLE a"),
+                Migration::new(2, "b", "CREATE TABLE b").with_down("DROP TABLE b"),
+            ]
+        );
+
+        let err = |files: &[(&str, &str)]| {
+            Migrator::from_files(files.iter().copied())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(err(&[("a.sql", "")]).contains("invalid migration file name a.sql"));
+        assert!(err(&[("1_a.sql", ""), ("01_b.sql", "")]).contains("1_a and 1_b"));
+        assert!(err(&[("1_a.sql", ""), ("1_b.down.sql", "")]).contains("1_b.down.sql"));
+        assert!(err(&[("1_a.down.sql", "")]).contains("1_a.down.sql"));
+    }
+
+    #[test]
+    fn checksum() {
+        let lf = Migration::new(1, "a", "CREATE TABLE a\n(n UInt8)");
+        let crlf = Migration::new(1, "a", "CREATE TABLE a\r\n(n UInt8)");
+        let other = Migration::new(1, "a", "CREATE TABLE a\n(n UInt16)");
+
+        assert_eq!(lf.checksum(), crlf.checksum());
+        assert_ne!(lf.checksum(), other.checksum());
+        assert_eq!(lf.checksum().len(), 16);
+    }
+
+    #[test]
+    fn drift() {
+        let migrator = Migrator::new([
+            Migration::new(1, "a", "A"),
+            Migration::new(2, "b", "B"),
+            Migration::new(3, "c", "C"),
+            Migration::new(5, "e", "E"),
+        ])
+        .unwrap();
+
+        let applied = |version: u64, name: &str, up: &str| AppliedMigration {
+            version,
+            name: name.into(),
+            checksum: Migration::new(version, name, up).checksum(),
+        };
+
+        assert_eq!(migrator.compare(&[]), []);
+        assert_eq!(migrator.compare(&[applied(1, "a", "A")]), []);
+        assert_eq!(
+            migrator.compare(&[
+                applied(1, "a", "A"),
+                applied(2, "b", "changed"),
+                applied(4, "d", "D"),
+            ]),
+            [
+                Drift::ChecksumMismatch {
+                    version: 2,
+                    name: "b".into()
+                },
+                Drift::Missing {
+                    version: 4,
+                    name: "d".into()
+                },
+                Drift::OutOfOrder {
+                    version: 3,
+                    name: "c".into()
+                },
+            ]
+        );
+    }
+}
@@ -1,6 +1,9 @@
-use hyper::{header::CONTENT_LENGTH, Method, Request};
+use hyper::{
+    header::{CONTENT_LENGTH, CONTENT_TYPE},
+    Method, Request,
+};
 use serde::Serialize;
-use std::fmt::Display;
+use std::fmt::{Display, Write};
 use url::Url;
 
 use crate::{
@@ -9,7 +12,8 @@ use crate::{
     request_body::RequestBody,
     response::Response,
     row::{Row, RowOwned, RowRead},
-    sql::{ser, Bind, SqlBuilder},
+    sql::{ser, Bind, ClickHouseType, SqlBuilder},
+    types::DataTypeNode,
     Client,
 };
 
@@ -23,13 +27,29 @@ use crate::headers::with_authentication;
 pub struct Query {
     client: Client,
     sql: SqlBuilder,
+    external_tables: Vec<ExternalTable>,
+}
+
+// A temporary table sent along with the query, see `Query::bind_set`.
+#[derive(Clone)]
+struct ExternalTable {
+    name: String,
+    // Columns, e.g. `value UInt32`.
+    structure: String,
+    // Rows in the `Values` format, e.g. `(1),(2)`.
+    data: String,
 }
 
 impl Query {
     pub(crate) fn new(client: &Client, template: &str) -> Self {
+        Self::with_sql(client, SqlBuilder::new(template))
+    }
+
+    pub(crate) fn with_sql(client: &Client, sql: SqlBuilder) -> Self {
         Self {
             client: client.clone(),
-            sql: SqlBuilder::new(template),
+            sql,
+            external_tables: Vec::new(),
         }
     }
 
@@ -46,8 +66,8 @@ impl Query {
     /// All possible errors will be returned as [`Error::InvalidParams`]
     /// during query execution (`execute()`, `fetch()`, etc.).
     ///
-    /// WARNING: This means that the query must not have any extra `?`, even if
-    /// they are in a string literal! Use `??` to have plain `?` in query.
+    /// `?` inside string literals, quoted identifiers, comments and heredocs
+    /// isn't a placeholder. Elsewhere, use `??` to have plain `?` in query.
     ///
     /// [`Serialize`]: serde::Serialize
     /// [`Identifier`]: crate::sql::Identifier
@@ -57,6 +77,99 @@ impl Query {
         self
     }
 
+    /// Binds a set of `values` to the next `?` in the query, e.g. in `x IN ?`.
+    ///
+    /// Small sets are inlined into the query as [`Query::bind`] does.
+    /// Sets larger than [`Client::with_inline_set_limit`] are sent in the request
+    /// body as an [external table] `_setN` instead, so they are limited neither
+    /// by `max_query_size` nor by the length of the URL. Such a table has
+    /// the `value` column or, for sets of tuples, `c0`, `c1`, etc. columns,
+    /// so the placeholder must be the right side of `IN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let client = clickhouse::Client::default();
+    /// let ids: Vec<u32> = (0..100_000).collect();
+    /// let query = client
+    ///     .query("SELECT ?fields FROM some WHERE id IN ?")
+    ///     .bind_set(&ids); // id IN _setN
+    /// ```
+    ///
+    /// [external table]: https://clickhouse.com/docs/engines/table-engines/special/external-data
+    #[track_caller]
+    pub fn bind_set<T: Serialize + ClickHouseType>(mut self, values: &[T]) -> Self {
+        if values.len() <= self.client.get_inline_set_limit() {
+            return self.bind(values);
+        }
+
+        let Some(name) = self.sql.bind_arg_table("_set") else {
+            return self;
+        };
+
+        let (structure, is_tuple) = match T::data_type() {
+            DataTypeNode::Tuple(items) => {
+                let columns = items.iter().enumerate();
+                let columns = columns.map(|(idx, item)| format!("c{idx} {item}"));
+                (columns.collect::<Vec<_>>().join(", "), true)
+            }
+            data_type => (format!("value {data_type}"), false),
+        };
+
+        let mut data = String::new();
+        let result = values.iter().enumerate().try_for_each(|(idx, value)| {
+            if idx > 0 {
+                data.write_char(',')?;
+            }
+            // Tuples are already written as rows, e.g. `(1,'a')`.
+            if is_tuple {
+                value.write_param(true, &mut data)
+            } else {
+                data.write_char('(')?;
+                value.write_param(true, &mut data)?;
+                data.write_char(')')
+            }
+        });
+
+        if let Err(err) = result {
+            self.sql = SqlBuilder::Failed(format!("invalid set: {err}"));
+            return self;
+        }
+
+        self.external_tables.push(ExternalTable {
+            name,
+            structure,
+            data,
+        });
+        self
+    }
+
+    /// Binds `value` to all `?{name}` placeholders with the given `name`.
+    ///
+    /// Named placeholders can be mixed with positional `?` ones, which are
+    /// still bound by [`Query::bind`] in order, so adding a named condition
+    /// doesn't shift other arguments. A name can be used several times
+    /// in the query, all its occurrences get the same value.
+    /// A name consists of ASCII letters, digits and underscores.
+    ///
+    /// Unbound names, as well as names absent in the query, are reported as
+    /// [`Error::InvalidParams`] during query execution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let client = clickhouse::Client::default();
+    /// let query = client
+    ///     .query("SELECT ?fields FROM some WHERE a > ?{min} AND (b > ?{min} OR c = ?)")
+    ///     .bind_named("min", 10)
+    ///     .bind("foo");
+    /// ```
+    #[track_caller]
+    pub fn bind_named(mut self, name: &str, value: impl Bind) -> Self {
+        self.sql.bind_named(name, value);
+        self
+    }
+
     /// Executes the query.
     pub async fn execute(self) -> Result<()> {
         self.do_execute(false)?.finish().await
@@ -162,8 +275,21 @@ impl Query {
         }
 
         let use_post = !read_only || query.len() > MAX_QUERY_LEN_TO_USE_GET;
+        let mut content_type = None;
 
-        let (method, body, content_length) = if use_post {
+        let (method, body, content_length) = if !self.external_tables.is_empty() {
+            if read_only {
+                pairs.append_pair("readonly", "1");
+            }
+            for table in &self.external_tables {
+                pairs.append_pair(&format!("{}_structure", table.name), &table.structure);
+                pairs.append_pair(&format!("{}_format", table.name), "Values");
+            }
+            let (boundary, form) = encode_form(&query, &self.external_tables);
+            content_type = Some(format!("multipart/form-data; boundary={boundary}"));
+            let len = form.len();
+            (Method::POST, RequestBody::full(form), len)
+        } else if use_post {
             if read_only {
                 pairs.append_pair("readonly", "1");
             }
@@ -193,6 +319,10 @@ impl Query {
             builder = builder.header(CONTENT_LENGTH, content_length.to_string());
         }
 
+        if let Some(content_type) = content_type {
+            builder = builder.header(CONTENT_TYPE, content_type);
+        }
+
         let request = builder
             .body(body)
             .map_err(|err| Error::InvalidParams(Box::new(err)))?;
@@ -219,4 +349,93 @@ impl Query {
             self.with_option(format!("param_{name}"), param)
         }
     }
+
+    /// Specify server side parameter for query, whose ClickHouse type is
+    /// derived from the Rust type of `value` by [`ClickHouseType`].
+    ///
+    /// All `?{name}` placeholders are replaced with `{name: Type}`,
+    /// so the type in the query is always in sync with the value.
+    /// Params written as `{name: Type}` by hand are also supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let client = clickhouse::Client::default();
+    /// let query = client
+    ///     .query("SELECT ?fields FROM some WHERE name IN ?{names} AND no > ?{no}")
+    ///     .param_typed("names", vec!["foo", "bar"]) // {names: Array(String)}
+    ///     .param_typed("no", 42u32); // {no: UInt32}
+    /// ```
+    pub fn param_typed<T: ClickHouseType>(mut self, name: &str, value: T) -> Self {
+        self.sql.bind_named_param(name, &T::data_type());
+        self.set_typed_param(name, &value)
+    }
+
+    /// Binds `value` to the next `?param` in the query as a server side
+    /// parameter, which is the injection-safe alternative to [`Query::bind`].
+    ///
+    /// The placeholder is replaced with `{pN: Type}`, where `N` is the index
+    /// of the placeholder among all `?param` ones, and the type is derived
+    /// from the Rust type of `value` by [`ClickHouseType`].
+    /// Thus, names `p0`, `p1`, etc. shouldn't be used for other params.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let client = clickhouse::Client::default();
+    /// let query = client
+    ///     .query("SELECT ?fields FROM some WHERE name = ?param AND no > ?param")
+    ///     .bind_param("foo") // {p0: String}
+    ///     .bind_param(42u32); // {p1: UInt32}
+    /// ```
+    #[track_caller]
+    pub fn bind_param<T: ClickHouseType>(mut self, value: T) -> Self {
+        match self.sql.bind_param(&T::data_type()) {
+            Some(name) => self.set_typed_param(&name, &value),
+            None => self,
+        }
+    }
+
+    fn set_typed_param(mut self, name: &str, value: &impl ClickHouseType) -> Self {
+        let mut param = String::new();
+        if let Err(err) = value.write_param(false, &mut param) {
+            self.sql = SqlBuilder::Failed(format!("invalid param: {err}"));
+            self
+        } else {
+            self.with_option(format!("param_{name}"), param)
+        }
+    }
+}
+
+// Encodes the query and external tables as `multipart/form-data`, returning
+// the boundary and the body. The server reads the query from the `query` field
+// and the tables from files described by `{name}_structure` and `{name}_format`.
+fn encode_form(query: &str, tables: &[ExternalTable]) -> (String, String) {
+    let is_used = |boundary: &str| {
+        query.contains(boundary) || tables.iter().any(|table| table.data.contains(boundary))
+    };
+    let boundary = (0u64..)
+        .map(|idx| format!("clickhouse-rs-boundary-{idx}"))
+        .find(|boundary| !is_used(boundary))
+        .expect("unused boundary");
+
+    let mut form = String::new();
+    let mut add_part = |disposition: &str, content: &str| {
+        let _ = write!(
+            form,
+            "--{boundary}\r\nContent-Disposition: form-data; {disposition}\r\n\r\n{content}\r\n"
+        );
+    };
+
+    add_part("name=\"query\"", query);
+    for table in tables {
+        let name = &table.name;
+        add_part(
+            &format!("name=\"{name}\"; filename=\"{name}\""),
+            &table.data,
+        );
+    }
+
+    let _ = write!(form, "--{boundary}--\r\n");
+    (boundary, form)
 }
@@ -235,6 +235,8 @@ macro_rules! impl_row_for_tuple {
 // TODO: revise this?
 impl Primitive for () {}
 
+impl Primitive for crate::value::Value {}
+
 impl<P: Primitive> Row for P {
     const NAME: &'static str = stringify!(P);
     const COLUMN_NAMES: &'static [&'static str] = &[];
@@ -1,11 +1,18 @@
+use crate::aggregate;
 use crate::error::{Error, Result};
 use crate::row_metadata::RowMetadata;
+use crate::rowbinary::dynamic::{self, JsonBytes};
+use crate::rowbinary::timezone::{self, TimestampAccess};
 use crate::rowbinary::utils::{ensure_size, get_unsigned_leb128};
 use crate::rowbinary::validation::{DataTypeValidator, SchemaValidator, SerdeType};
 use crate::Row;
 use bytes::Buf;
+use clickhouse_types::data_types::EnumType;
 use core::mem::size_of;
-use serde::de::MapAccess;
+use serde::de::{
+    value::{StrDeserializer, U64Deserializer},
+    MapAccess,
+};
 use serde::{
     de::{DeserializeSeed, Deserializer, EnumAccess, SeqAccess, VariantAccess, Visitor},
     Deserialize,
@@ -13,6 +20,9 @@ use serde::{
 use std::marker::PhantomData;
 use std::{convert::TryFrom, str};
 
+/// The serde name of enums marked by `#[clickhouse::variant]`, set by the macro.
+const VARIANT_ENUM_NAME: &str = "$clickhouse::Variant";
+
 /// Deserializes a row from `input` with a row encoded in `RowBinary`.
 ///
 /// If the optional metadata ([`RowMetadata`]) parsed from `RowBinaryWithNamesAndTypes` header
@@ -87,6 +97,25 @@ where
         }
     }
 
+    fn visit_struct<T: Visitor<'data>>(
+        &mut self,
+        fields: &'static [&'static str],
+        visitor: T,
+    ) -> Result<T::Value> {
+        if !self.validator.is_field_order_wrong() {
+            visitor.visit_seq(RowBinarySeqAccess {
+                deserializer: self,
+                len: fields.len(),
+            })
+        } else {
+            visitor.visit_map(RowBinaryStructAsMapAccess {
+                deserializer: self,
+                current_field_idx: 0,
+                fields,
+            })
+        }
+    }
+
     fn read_vec(&mut self, size: usize) -> Result<Vec<u8>> {
         Ok(self.read_slice(size)?.to_vec())
     }
@@ -228,12 +257,36 @@ where
     #[inline(always)]
     fn deserialize_enum<V: Visitor<'data>>(
         self,
-        _name: &'static str,
-        _variants: &'static [&'static str],
+        name: &'static str,
+        variants: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
         let deserializer = &mut self.inner(SerdeType::Enum);
-        visitor.visit_enum(RowBinaryEnumAccess { deserializer })
+        if let Some((enum_type, values_map)) = deserializer.validator.enum_values() {
+            let value = match enum_type {
+                EnumType::Enum8 => {
+                    ensure_size(&mut deserializer.input, size_of::<i8>())?;
+                    i16::from(deserializer.input.get_i8())
+                }
+                EnumType::Enum16 => {
+                    ensure_size(&mut deserializer.input, size_of::<i16>())?;
+                    deserializer.input.get_i16_le()
+                }
+            };
+            // Unknown names are handled by the visitor, e.g. using `#[serde(other)]`
+            let name = values_map.get(&value).ok_or_else(|| {
+                Error::Custom(format!(
+                    "{enum_type:?} value {value} is not present in the schema"
+                ))
+            })?;
+            return visitor.visit_enum(StrDeserializer::<Error>::new(name));
+        }
+        // Only variants of enums marked by `#[clickhouse::variant]` are named after their types.
+        let variants = (name == VARIANT_ENUM_NAME).then_some(variants);
+        visitor.visit_enum(RowBinaryEnumAccess {
+            deserializer,
+            variants,
+        })
     }
 
     #[inline(always)]
@@ -278,27 +331,52 @@ where
         fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
-        if !self.validator.is_field_order_wrong() {
-            visitor.visit_seq(RowBinarySeqAccess {
-                deserializer: self,
-                len: fields.len(),
-            })
-        } else {
-            visitor.visit_map(RowBinaryStructAsMapAccess {
-                deserializer: self,
-                current_field_idx: 0,
-                fields,
-            })
+        // A struct of a column, e.g. a named tuple, is validated as a whole
+        if let Some(validator) = self.validator.validate_struct(fields) {
+            let mut deserializer = RowBinaryDeserializer::new(self.input, validator);
+            return deserializer.visit_struct(fields, visitor);
         }
+        self.visit_struct(fields, visitor)
     }
 
     #[inline(always)]
     fn deserialize_newtype_struct<V: Visitor<'data>>(
         self,
-        _name: &str,
+        name: &'static str,
         visitor: V,
     ) -> Result<V::Value> {
-        visitor.visit_newtype_struct(self)
+        match name {
+            dynamic::DYNAMIC => {
+                self.validator.validate(SerdeType::Dynamic);
+                visitor.visit_borrowed_bytes(dynamic::read_dynamic(self.input)?)
+            }
+            dynamic::JSON => {
+                let validator = self.validator.validate(SerdeType::Json);
+                // Without validation, typed paths cannot be decoded.
+                match dynamic::read_json(self.input, validator.json_type())? {
+                    JsonBytes::Borrowed(data) => visitor.visit_borrowed_bytes(data),
+                    JsonBytes::Owned(data) => visitor.visit_byte_buf(data),
+                }
+            }
+            timezone::DATETIME => {
+                let validator = self.validator.validate(SerdeType::DateTime);
+                ensure_size(&mut self.input, size_of::<u32>())?;
+                let timestamp = i64::from(self.input.get_u32_le());
+                visitor.visit_seq(TimestampAccess::new(timestamp, validator.timezone()))
+            }
+            timezone::DATETIME64 => {
+                let validator = self.validator.validate(SerdeType::DateTime64);
+                ensure_size(&mut self.input, size_of::<i64>())?;
+                let timestamp = self.input.get_i64_le();
+                visitor.visit_seq(TimestampAccess::new(timestamp, validator.timezone()))
+            }
+            _ if name.starts_with(aggregate::MARKER) => {
+                let function = &name[aggregate::MARKER.len()..];
+                let deserializer = &mut self.inner(SerdeType::AggregateFunction(function));
+                visitor.visit_newtype_struct(deserializer)
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
     }
 
     #[inline(always)]
@@ -505,6 +583,9 @@ where
     Validator: SchemaValidator<R>,
 {
     deserializer: &'de mut RowBinaryDeserializer<'cursor, 'data, R, Validator>,
+    /// Names of the variants of the Rust enum, matched with the types of `Variant`,
+    /// if the enum is marked by `#[clickhouse::variant]`.
+    variants: Option<&'static [&'static str]>,
 }
 
 struct VariantDeserializer<'de, 'cursor, 'data, R: Row, Validator>
@@ -559,7 +640,21 @@ where
     where
         T: DeserializeSeed<'data>,
     {
-        let value = seed.deserialize(&mut *self.deserializer)?;
+        let deserializer = &mut *self.deserializer;
+        ensure_size(&mut deserializer.input, size_of::<u8>())?;
+        let discriminator = deserializer.input.get_u8();
+        deserializer
+            .validator
+            .validate_identifier::<u8>(discriminator);
+        let index = self
+            .variants
+            .and_then(|variants| {
+                deserializer
+                    .validator
+                    .variant_index(discriminator, variants)
+            })
+            .map_or(u64::from(discriminator), |index| index as u64);
+        let value = seed.deserialize(U64Deserializer::<Error>::new(index))?;
         let deserializer = VariantDeserializer {
             deserializer: self.deserializer,
         };
@@ -0,0 +1,585 @@
+//! Codec for the `Dynamic` and `JSON` data types.
+//!
+//! In `RowBinary`, a `Dynamic` value is prefixed with its data type in the binary encoding
+//! (see [`clickhouse_types::binary`]), and `NULL` is encoded as the `Nothing` type.
+//! A `JSON` value is a list of paths, where typed paths are encoded according to
+//! their types from the column definition, and the rest of them as `Dynamic` values.
+//!
+//! See <https://clickhouse.com/docs/en/interfaces/formats/RowBinary>
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use bytes::{Buf, BufMut};
+use clickhouse_types::{
+    binary::{put_data_type, read_data_type},
+    data_types::{DataTypeNode, DateTimePrecision, DecimalType, EnumType, JsonType},
+    error::TypesError,
+    put_leb128,
+};
+
+use crate::error::{Error, Result};
+use crate::rowbinary::utils::{ensure_size, get_unsigned_leb128};
+use crate::value::Value;
+
+/// Used as a newtype name to request a `Dynamic` value from the deserializer.
+pub(crate) const DYNAMIC: &str = "$clickhouse::Dynamic";
+/// Used as a newtype name to request a `JSON` value from the deserializer.
+pub(crate) const JSON: &str = "$clickhouse::JSON";
+
+/// Reads a `Dynamic` value and returns its raw encoding.
+pub(crate) fn read_dynamic<'data>(input: &mut &'data [u8]) -> Result<&'data [u8]> {
+    consumed(input, |input| decode_dynamic(input).map(drop))
+}
+
+/// Reads a `JSON` value and returns it encoded as if all paths were dynamic,
+/// so it can be decoded without the column definition by [`decode_json`].
+///
+/// The value is borrowed if there are no typed paths in the definition.
+pub(crate) fn read_json<'data>(
+    input: &mut &'data [u8],
+    json: Option<&JsonType>,
+) -> Result<JsonBytes<'data>> {
+    let Some(json) = json.filter(|json| !json.typed_paths.is_empty()) else {
+        return consumed(input, |input| decode_json(input, None).map(drop))
+            .map(JsonBytes::Borrowed);
+    };
+
+    let mut output = Vec::new();
+    let count = read_size(input)?;
+    put_leb128(&mut output, count as u64);
+    for _ in 0..count {
+        let path = read_str(input)?;
+        put_str(&mut output, path);
+        match json.typed_path(path) {
+            Some(data_type) => {
+                let data = consumed(input, |input| decode_value(input, data_type).map(drop))?;
+                put_data_type(&mut output, data_type).map_err(from_types_error)?;
+                output.put_slice(data);
+            }
+            None => output.put_slice(read_dynamic(input)?),
+        }
+    }
+    Ok(JsonBytes::Owned(output))
+}
+
+pub(crate) enum JsonBytes<'data> {
+    Borrowed(&'data [u8]),
+    Owned(Vec<u8>),
+}
+
+pub(crate) fn decode_dynamic(input: &mut &[u8]) -> Result<Value> {
+    let data_type = read_data_type(&mut *input).map_err(from_types_error)?;
+    decode_value(input, &data_type)
+}
+
+/// Decodes paths of a `JSON` value. Without the column definition,
+/// all paths are expected to be encoded as `Dynamic` values.
+pub(crate) fn decode_json(
+    input: &mut &[u8],
+    json: Option<&JsonType>,
+) -> Result<Vec<(String, Value)>> {
+    let count = read_size(input)?;
+    let mut paths = Vec::with_capacity(count.min(input.len()));
+    for _ in 0..count {
+        let path = read_str(input)?.to_string();
+        let value = match json.and_then(|json| json.typed_path(&path)) {
+            Some(data_type) => decode_value(input, data_type)?,
+            None => decode_dynamic(input)?,
+        };
+        paths.push((path, value));
+    }
+    Ok(paths)
+}
+
+fn decode_value(input: &mut &[u8], data_type: &DataTypeNode) -> Result<Value> {
+    macro_rules! get {
+        ($getter:ident, $size:literal) => {{
+            ensure_size(&mut *input, $size)?;
+            input.$getter()
+        }};
+    }
+
+    Ok(match data_type {
+        DataTypeNode::Nothing => Value::Null,
+        DataTypeNode::Bool => Value::Bool(get!(get_u8, 1) != 0),
+        DataTypeNode::UInt8 => Value::UInt8(get!(get_u8, 1)),
+        DataTypeNode::UInt16 => Value::UInt16(get!(get_u16_le, 2)),
+        DataTypeNode::UInt32 => Value::UInt32(get!(get_u32_le, 4)),
+        DataTypeNode::UInt64 => Value::UInt64(get!(get_u64_le, 8)),
+        DataTypeNode::UInt128 => Value::UInt128(get!(get_u128_le, 16)),
+        DataTypeNode::Int8 => Value::Int8(get!(get_i8, 1)),
+        DataTypeNode::Int16 => Value::Int16(get!(get_i16_le, 2)),
+        DataTypeNode::Int32 => Value::Int32(get!(get_i32_le, 4)),
+        DataTypeNode::Int64 => Value::Int64(get!(get_i64_le, 8)),
+        DataTypeNode::Int128 => Value::Int128(get!(get_i128_le, 16)),
+        DataTypeNode::Float32 => Value::Float32(get!(get_f32_le, 4)),
+        DataTypeNode::Float64 => Value::Float64(get!(get_f64_le, 8)),
+        DataTypeNode::String => Value::String(read_str(input)?.to_string()),
+        DataTypeNode::UUID => {
+            let high = get!(get_u64_le, 8);
+            let low = get!(get_u64_le, 8);
+            Value::UUID(u128::from(high) << 64 | u128::from(low))
+        }
+        DataTypeNode::IPv4 => Value::IPv4(Ipv4Addr::from(get!(get_u32_le, 4))),
+        DataTypeNode::IPv6 => Value::IPv6(Ipv6Addr::from(get!(get_u128, 16))),
+        DataTypeNode::Date => Value::Date(get!(get_u16_le, 2)),
+        DataTypeNode::Date32 => Value::Date32(get!(get_i32_le, 4)),
+        DataTypeNode::DateTime(_) => Value::DateTime(get!(get_u32_le, 4)),
+        DataTypeNode::DateTime64(precision, _) => {
+            Value::DateTime64(get!(get_i64_le, 8), precision_digits(precision))
+        }
+        DataTypeNode::Enum(enum_type, values) => {
+            let index = match enum_type {
+                EnumType::Enum8 => i16::from(get!(get_i8, 1)),
+                EnumType::Enum16 => get!(get_i16_le, 2),
+            };
+            match values.get(&index) {
+                Some(name) => Value::String(name.clone()),
+                None => return Err(Error::InvalidTagEncoding(index as usize)),
+            }
+        }
+        DataTypeNode::Nullable(inner) => match get!(get_u8, 1) {
+            0 => decode_value(input, inner)?,
+            1 => Value::Null,
+            tag => return Err(Error::InvalidTagEncoding(tag as usize)),
+        },
+        DataTypeNode::LowCardinality(inner) => decode_value(input, inner)?,
+        DataTypeNode::Array(inner) => Value::Array(decode_array(input, inner)?),
+        DataTypeNode::Tuple(elements) | DataTypeNode::NamedTuple(_, elements) => Value::Tuple(
+            elements
+                .iter()
+                .map(|element| decode_value(input, element))
+                .collect::<Result<_>>()?,
+        ),
+        DataTypeNode::Map([key, value]) => {
+            let count = read_size(input)?;
+            let mut entries = Vec::with_capacity(count.min(input.len()));
+            for _ in 0..count {
+                let key = decode_value(input, key)?;
+                entries.push((key, decode_value(input, value)?));
+            }
+            Value::Map(entries)
+        }
+        DataTypeNode::Variant(variants) => match get!(get_u8, 1) {
+            255 => Value::Null,
+            index => match variants.get(index as usize) {
+                Some(variant) => decode_value(input, variant)?,
+                None => return Err(Error::VariantDiscriminatorIsOutOfBound(index as usize)),
+            },
+        },
+        DataTypeNode::Dynamic => decode_dynamic(input)?,
+        DataTypeNode::JSON(json) => Value::Json(decode_json(input, Some(json))?),
+        DataTypeNode::Point => Value::Tuple(vec![
+            Value::Float64(get!(get_f64_le, 8)),
+            Value::Float64(get!(get_f64_le, 8)),
+        ]),
+        DataTypeNode::Ring | DataTypeNode::LineString => {
+            Value::Array(decode_array(input, &DataTypeNode::Point)?)
+        }
+        DataTypeNode::MultiLineString => {
+            Value::Array(decode_array(input, &DataTypeNode::LineString)?)
+        }
+        DataTypeNode::Polygon => Value::Array(decode_array(input, &DataTypeNode::Ring)?),
+        DataTypeNode::MultiPolygon => Value::Array(decode_array(input, &DataTypeNode::Polygon)?),
+        DataTypeNode::UInt256 | DataTypeNode::Int256 => decode_other(input, data_type, 32)?,
+        DataTypeNode::BFloat16 => decode_other(input, data_type, 2)?,
+        DataTypeNode::Time => decode_other(input, data_type, 4)?,
+        DataTypeNode::Time64(_) => decode_other(input, data_type, 8)?,
+        DataTypeNode::Interval(_) => decode_other(input, data_type, 8)?,
+        DataTypeNode::FixedString(size) => decode_other(input, data_type, *size)?,
+        DataTypeNode::Decimal(_, _, decimal_type) => {
+            let size = match decimal_type {
+                DecimalType::Decimal32 => 4,
+                DecimalType::Decimal64 => 8,
+                DecimalType::Decimal128 => 16,
+                DecimalType::Decimal256 => 32,
+            };
+            decode_other(input, data_type, size)?
+        }
+        _ => {
+            return Err(Error::Unsupported(format!(
+                "decoding {data_type} values is not supported"
+            )))
+        }
+    })
+}
+
+fn decode_array(input: &mut &[u8], data_type: &DataTypeNode) -> Result<Vec<Value>> {
+    let count = read_size(input)?;
+    let mut values = Vec::with_capacity(count.min(input.len()));
+    for _ in 0..count {
+        values.push(decode_value(input, data_type)?);
+    }
+    Ok(values)
+}
+
+fn precision_digits(precision: &DateTimePrecision) -> u8 {
+    match precision {
+        DateTimePrecision::Precision0 => 0,
+        DateTimePrecision::Precision1 => 1,
+        DateTimePrecision::Precision2 => 2,
+        DateTimePrecision::Precision3 => 3,
+        DateTimePrecision::Precision4 => 4,
+        DateTimePrecision::Precision5 => 5,
+        DateTimePrecision::Precision6 => 6,
+        DateTimePrecision::Precision7 => 7,
+        DateTimePrecision::Precision8 => 8,
+        DateTimePrecision::Precision9 => 9,
+    }
+}
+
+fn decode_other(input: &mut &[u8], data_type: &DataTypeNode, size: usize) -> Result<Value> {
+    ensure_size(&mut *input, size)?;
+    let data = input[..size].to_vec();
+    input.advance(size);
+    Ok(Value::Other(data_type.to_string(), data))
+}
+
+pub(crate) fn encode_dynamic(value: &Value) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+    put_dynamic(&mut output, value)?;
+    Ok(output)
+}
+
+pub(crate) fn encode_json(paths: &[(String, Value)]) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+    put_json(&mut output, paths)?;
+    Ok(output)
+}
+
+fn put_dynamic(output: &mut Vec<u8>, value: &Value) -> Result<()> {
+    let data_type = infer_type(value)?;
+    put_data_type(&mut *output, &data_type).map_err(from_types_error)?;
+    put_value(output, value, &data_type)
+}
+
+fn put_json(output: &mut Vec<u8>, paths: &[(String, Value)]) -> Result<()> {
+    put_leb128(&mut *output, paths.len() as u64);
+    for (path, value) in paths {
+        put_str(output, path);
+        put_dynamic(output, value)?;
+    }
+    Ok(())
+}
+
+fn put_value(output: &mut Vec<u8>, value: &Value, data_type: &DataTypeNode) -> Result<()> {
+    match (value, data_type) {
+        (_, DataTypeNode::Dynamic) => put_dynamic(output, value)?,
+        (Value::Null, DataTypeNode::Nullable(_)) => output.put_u8(1),
+        (_, DataTypeNode::Nullable(inner)) => {
+            output.put_u8(0);
+            put_value(output, value, inner)?;
+        }
+        (Value::Null, _) => {} // `Nothing` has no data
+        (Value::Bool(v), _) => output.put_u8(u8::from(*v)),
+        (Value::UInt8(v), _) => output.put_u8(*v),
+        (Value::UInt16(v), _) => output.put_u16_le(*v),
+        (Value::UInt32(v), _) => output.put_u32_le(*v),
+        (Value::UInt64(v), _) => output.put_u64_le(*v),
+        (Value::UInt128(v), _) => output.put_u128_le(*v),
+        (Value::Int8(v), _) => output.put_i8(*v),
+        (Value::Int16(v), _) => output.put_i16_le(*v),
+        (Value::Int32(v), _) => output.put_i32_le(*v),
+        (Value::Int64(v), _) => output.put_i64_le(*v),
+        (Value::Int128(v), _) => output.put_i128_le(*v),
+        (Value::Float32(v), _) => output.put_f32_le(*v),
+        (Value::Float64(v), _) => output.put_f64_le(*v),
+        (Value::String(v), _) => put_str(output, v),
+        (Value::UUID(v), _) => {
+            output.put_u64_le((*v >> 64) as u64);
+            output.put_u64_le(*v as u64);
+        }
+        (Value::IPv4(v), _) => output.put_u32_le(u32::from(*v)),
+        (Value::IPv6(v), _) => output.put_slice(&v.octets()),
+        (Value::Date(v), _) => output.put_u16_le(*v),
+        (Value::Date32(v), _) => output.put_i32_le(*v),
+        (Value::DateTime(v), _) => output.put_u32_le(*v),
+        (Value::DateTime64(v, _), _) => output.put_i64_le(*v),
+        (Value::Array(values), DataTypeNode::Array(inner)) => {
+            put_leb128(&mut *output, values.len() as u64);
+            for value in values {
+                put_value(output, value, inner)?;
+            }
+        }
+        (Value::Tuple(values), DataTypeNode::Tuple(elements)) => {
+            for (value, element) in values.iter().zip(elements) {
+                put_value(output, value, element)?;
+            }
+        }
+        (Value::Map(entries), DataTypeNode::Map([key_type, value_type])) => {
+            put_leb128(&mut *output, entries.len() as u64);
+            for (key, value) in entries {
+                put_value(output, key, key_type)?;
+                put_value(output, value, value_type)?;
+            }
+        }
+        (Value::Json(paths), DataTypeNode::JSON(_)) => put_json(output, paths)?,
+        (Value::Other(_, data), _) => output.put_slice(data),
+        (value, data_type) => {
+            // unreachable as long as `data_type` is inferred from `value`
+            return Err(Error::Custom(format!(
+                "cannot encode {value:?} as {data_type}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Infers the data type to be sent along with the value in the `Dynamic` encoding.
+fn infer_type(value: &Value) -> Result<DataTypeNode> {
+    Ok(match value {
+        Value::Null => DataTypeNode::Nothing,
+        Value::Bool(_) => DataTypeNode::Bool,
+        Value::UInt8(_) => DataTypeNode::UInt8,
+        Value::UInt16(_) => DataTypeNode::UInt16,
+        Value::UInt32(_) => DataTypeNode::UInt32,
+        Value::UInt64(_) => DataTypeNode::UInt64,
+        Value::UInt128(_) => DataTypeNode::UInt128,
+        Value::Int8(_) => DataTypeNode::Int8,
+        Value::Int16(_) => DataTypeNode::Int16,
+        Value::Int32(_) => DataTypeNode::Int32,
+        Value::Int64(_) => DataTypeNode::Int64,
+        Value::Int128(_) => DataTypeNode::Int128,
+        Value::Float32(_) => DataTypeNode::Float32,
+        Value::Float64(_) => DataTypeNode::Float64,
+        Value::String(_) => DataTypeNode::String,
+        Value::UUID(_) => DataTypeNode::UUID,
+        Value::IPv4(_) => DataTypeNode::IPv4,
+        Value::IPv6(_) => DataTypeNode::IPv6,
+        Value::Date(_) => DataTypeNode::Date,
+        Value::Date32(_) => DataTypeNode::Date32,
+        Value::DateTime(_) => DataTypeNode::DateTime(None),
+        Value::DateTime64(_, precision) => {
+            DataTypeNode::new(&format!("DateTime64({precision})")).map_err(from_types_error)?
+        }
+        Value::Array(values) => DataTypeNode::Array(Box::new(infer_common_type(values)?)),
+        Value::Tuple(values) => DataTypeNode::Tuple(
+            values
+                .iter()
+                .map(|value| infer_common_type([value]))
+                .collect::<Result<_>>()?,
+        ),
+        Value::Map(entries) => DataTypeNode::Map([
+            Box::new(infer_common_type(entries.iter().map(|(key, _)| key))?),
+            Box::new(infer_common_type(entries.iter().map(|(_, value)| value))?),
+        ]),
+        Value::Json(_) => DataTypeNode::JSON(JsonType::default()),
+        Value::Other(type_name, _) => DataTypeNode::new(type_name).map_err(from_types_error)?,
+    })
+}
+
+/// Infers the type of elements: `Nullable(T)` if some of them are `NULL`,
+/// or `Dynamic` if they have different types.
+fn infer_common_type<'a>(values: impl IntoIterator<Item = &'a Value>) -> Result<DataTypeNode> {
+    let mut common = None;
+    let mut has_nulls = false;
+    for value in values {
+        if *value == Value::Null {
+            has_nulls = true;
+            continue;
+        }
+        let data_type = infer_type(value)?;
+        match &common {
+            None => common = Some(data_type),
+            Some(common) if *common == data_type => {}
+            Some(_) => return Ok(DataTypeNode::Dynamic),
+        }
+    }
+
+    Ok(match common {
+        None if has_nulls => DataTypeNode::Nullable(Box::new(DataTypeNode::Nothing)),
+        None => DataTypeNode::Nothing,
+        Some(data_type) if has_nulls => {
+            if can_be_inside_nullable(&data_type) {
+                DataTypeNode::Nullable(Box::new(data_type))
+            } else {
+                DataTypeNode::Dynamic
+            }
+        }
+        Some(data_type) => data_type,
+    })
+}
+
+fn can_be_inside_nullable(data_type: &DataTypeNode) -> bool {
+    !matches!(
+        data_type,
+        DataTypeNode::Array(_)
+            | DataTypeNode::Tuple(_)
+            | DataTypeNode::Map(_)
+            | DataTypeNode::JSON(_)
+            | DataTypeNode::Dynamic
+            | DataTypeNode::Nullable(_)
+            | DataTypeNode::LowCardinality(_)
+            | DataTypeNode::Variant(_)
+            | DataTypeNode::Point
+            | DataTypeNode::Ring
+            | DataTypeNode::LineString
+            | DataTypeNode::MultiLineString
+            | DataTypeNode::Polygon
+            | DataTypeNode::MultiPolygon
+    )
+}
+
+/// Calls `f` and returns the part of `input` consumed by it.
+fn consumed<'data>(
+    input: &mut &'data [u8],
+    f: impl FnOnce(&mut &'data [u8]) -> Result<()>,
+) -> Result<&'data [u8]> {
+    let start = *input;
+    f(input)?;
+    Ok(&start[..start.len() - input.len()])
+}
+
+fn read_size(input: &mut &[u8]) -> Result<usize> {
+    let size = get_unsigned_leb128(&mut *input)?;
+    usize::try_from(size).map_err(|_| Error::NotEnoughData)
+}
+
+fn read_str<'data>(input: &mut &'data [u8]) -> Result<&'data str> {
+    let size = read_size(input)?;
+    ensure_size(&mut *input, size)?;
+    let (data, rest) = input.split_at(size);
+    *input = rest;
+    Ok(std::str::from_utf8(data)?)
+}
+
+fn put_str(output: &mut Vec<u8>, value: &str) {
+    put_leb128(&mut *output, value.len() as u64);
+    output.put_slice(value.as_bytes());
+}
+
+fn from_types_error(err: TypesError) -> Error {
+    match err {
+        TypesError::NotEnoughData(_) => Error::NotEnoughData,
+        err => Error::Other(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: Value, expected: &[u8]) {
+        let encoded = encode_dynamic(&value).unwrap();
+        assert_eq!(encoded, expected, "encoding {value:?}");
+        let mut input = encoded.as_slice();
+        assert_eq!(decode_dynamic(&mut input).unwrap(), value);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn it_encodes_scalars() {
+        round_trip(Value::Null, &[0x00]);
+        round_trip(Value::Bool(true), &[0x2D, 1]);
+        round_trip(
+            Value::Int64(-2),
+            &[0x0A, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        );
+        round_trip(Value::String("foo".into()), &[0x15, 3, b'f', b'o', b'o']);
+        round_trip(Value::IPv4(Ipv4Addr::new(1, 2, 3, 4)), &[0x28, 4, 3, 2, 1]);
+        round_trip(
+            Value::DateTime64(42, 3),
+            &[0x13, 3, 42, 0, 0, 0, 0, 0, 0, 0],
+        );
+        round_trip(
+            Value::UUID(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10),
+            &[0x1D, 8, 7, 6, 5, 4, 3, 2, 1, 16, 15, 14, 13, 12, 11, 10, 9],
+        );
+        round_trip(
+            Value::Other("Decimal(9, 2)".into(), vec![1, 0, 0, 0]),
+            &[0x19, 9, 2, 1, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn it_encodes_containers() {
+        round_trip(Value::Array(vec![]), &[0x1E, 0x00, 0]);
+        round_trip(
+            Value::Array(vec![Value::UInt8(1), Value::Null]),
+            &[0x1E, 0x23, 0x01, 2, 0, 1, 1],
+        );
+        round_trip(
+            Value::Array(vec![Value::UInt8(1), Value::Bool(true)]),
+            &[0x1E, 0x2B, 32, 2, 0x01, 1, 0x2D, 1],
+        );
+        round_trip(
+            Value::Tuple(vec![Value::UInt8(1), Value::Null]),
+            &[0x1F, 2, 0x01, 0x23, 0x00, 1, 1],
+        );
+        round_trip(
+            Value::Map(vec![(Value::String("a".into()), Value::UInt8(1))]),
+            &[0x27, 0x15, 0x01, 1, 1, b'a', 1],
+        );
+        round_trip(
+            Value::Json(vec![("a.b".into(), Value::UInt8(1))]),
+            &[
+                0x30, 0, 0x80, 0x08, 16, 0, 0, 0, 1, 3, b'a', b'.', b'b', 0x01, 1,
+            ],
+        );
+    }
+
+    #[test]
+    fn it_decodes_typed_values() {
+        let data_type = DataTypeNode::new("Variant(String, UInt8)").unwrap();
+        let mut input = &[1, 42][..];
+        assert_eq!(
+            decode_value(&mut input, &data_type).unwrap(),
+            Value::UInt8(42)
+        );
+        let mut input = &[255][..];
+        assert_eq!(decode_value(&mut input, &data_type).unwrap(), Value::Null);
+
+        let data_type = DataTypeNode::new("Enum8('a' = 1, 'b' = 2)").unwrap();
+        let mut input = &[2][..];
+        assert_eq!(
+            decode_value(&mut input, &data_type).unwrap(),
+            Value::String("b".into())
+        );
+
+        let data_type = DataTypeNode::new("LowCardinality(Nullable(String))").unwrap();
+        let mut input = &[0, 1, b'x'][..];
+        assert_eq!(
+            decode_value(&mut input, &data_type).unwrap(),
+            Value::String("x".into())
+        );
+    }
+
+    #[test]
+    fn it_reads_json_with_typed_paths() {
+        let data_type = DataTypeNode::new("JSON(a UInt8)").unwrap();
+        let DataTypeNode::JSON(json) = &data_type else {
+            unreachable!()
+        };
+
+        // {"a": 42, "b": "x"}, where `a` is typed and `b` is dynamic
+        let data = [2, 1, b'a', 42, 1, b'b', 0x15, 1, b'x', 0xFF];
+        let mut input = &data[..];
+        let JsonBytes::Owned(canonical) = read_json(&mut input, Some(json)).unwrap() else {
+            panic!("expected owned bytes");
+        };
+        assert_eq!(input, &[0xFF]);
+
+        let expected = vec![
+            ("a".to_string(), Value::UInt8(42)),
+            ("b".to_string(), Value::String("x".into())),
+        ];
+        assert_eq!(
+            decode_json(&mut canonical.as_slice(), None).unwrap(),
+            expected
+        );
+        assert_eq!(decode_json(&mut &data[..], Some(json)).unwrap(), expected);
+        assert_eq!(encode_json(&expected).unwrap(), &canonical[..]);
+    }
+
+    #[test]
+    fn it_fails_on_truncated_data() {
+        let encoded = encode_dynamic(&Value::Array(vec![Value::String("foo".into())])).unwrap();
+        for len in 0..encoded.len() {
+            let mut input = &encoded[..len];
+            assert!(
+                matches!(read_dynamic(&mut input), Err(Error::NotEnoughData)),
+                "len = {len}"
+            );
+        }
+    }
+}
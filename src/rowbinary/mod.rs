@@ -1,6 +1,8 @@
 pub(crate) use de::deserialize_row;
 pub(crate) use ser::serialize_into;
 
+pub(crate) mod dynamic;
+pub(crate) mod timezone;
 pub(crate) mod validation;
 
 mod de;
@@ -109,7 +109,10 @@ impl<B: BufMut> Serializer for &'_ mut RowBinarySerializer<B> {
         _variant_index: u32,
         variant: &'static str,
     ) -> Result<()> {
-        panic!("unit variant types are unsupported: `{name}::{variant}`");
+        panic!(
+            "unit variant types are unsupported: `{name}::{variant}`; \
+             use `serde_repr` to write enums as `Enum8` or `Enum16`"
+        );
     }
 
     #[inline]
@@ -1,5 +1,48 @@
+use crate::row_metadata::RowMetadata;
 use crate::Row;
-use serde::{Deserialize, Serialize};
+use clickhouse_types::data_types::{Column, DataTypeNode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Implements `Row` for a struct with the given columns, like `clickhouse_derive` would.
+macro_rules! impl_row {
+    ($name:ident $(<$param:ident>)?, [$($column:literal),* $(,)?]) => {
+        impl$(<$param>)? Row for $name$(<$param>)? {
+            const NAME: &'static str = stringify!($name);
+            const COLUMN_NAMES: &'static [&'static str] = &[$($column),*];
+            const COLUMN_COUNT: usize = Self::COLUMN_NAMES.len();
+            const KIND: crate::row::RowKind = crate::row::RowKind::Struct;
+
+            type Value<'a> = $name$(<$param>)?;
+        }
+    };
+}
+
+/// Builds metadata of `T` from `(name, type)` pairs of columns.
+fn metadata<T: Row>(columns: &[(&str, &str)]) -> RowMetadata {
+    let columns = columns
+        .iter()
+        .map(|(name, data_type)| {
+            Column::new(name.to_string(), DataTypeNode::new(data_type).unwrap())
+        })
+        .collect();
+    RowMetadata::new::<T>(columns)
+}
+
+/// Serializes `value` and checks that it's deserialized back, with validation against
+/// `columns` if they're provided. Returns the serialized value.
+fn roundtrip<T>(value: &T, columns: Option<&[(&str, &str)]>) -> Vec<u8>
+where
+    T: Row + Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let mut actual = Vec::new();
+    super::serialize_into(&mut actual, value).unwrap();
+
+    let metadata = columns.map(metadata::<T>);
+    let decoded: T = super::deserialize_row(&mut actual.as_slice(), metadata.as_ref()).unwrap();
+    assert_eq!(&decoded, value);
+    actual
+}
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 struct Timestamp32(u32);
@@ -317,3 +360,615 @@ fn it_time_serializes_time64_nanos_overflow_fails() {
         "Unexpected error message: {err}"
     );
 }
+
+#[cfg(feature = "ethnum")]
+#[test]
+fn it_serializes_int256() {
+    use crate::serde::{int256, uint256};
+    use ethnum::{I256, U256};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        #[serde(with = "int256")]
+        int: I256,
+        #[serde(with = "uint256::option")]
+        uint: Option<U256>,
+    }
+
+    impl_row!(Sample, ["int", "uint"]);
+
+    let value = Sample {
+        int: I256::MINUS_ONE,
+        uint: Some(U256::from_words(1, 2)),
+    };
+
+    let mut expected = vec![0xff; 32];
+    expected.push(0x00); // not null
+    expected.extend_from_slice(&2u128.to_le_bytes());
+    expected.extend_from_slice(&1u128.to_le_bytes());
+    assert_eq!(roundtrip(&value, None), expected);
+}
+
+#[test]
+fn it_serializes_dynamic_and_json() {
+    use crate::value::Value;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        dynamic: Value,
+        #[serde(with = "crate::serde::json")]
+        json: Value,
+    }
+
+    impl_row!(Sample, ["dynamic", "json"]);
+
+    let value = Sample {
+        dynamic: Value::Array(vec![Value::Int32(1), Value::Null]),
+        json: Value::Json(vec![
+            ("a".into(), Value::UInt8(42)),
+            ("b.c".into(), Value::String("foo".into())),
+        ]),
+    };
+
+    #[rustfmt::skip]
+    let expected = [
+        // Array(Nullable(Int32)): [1, NULL]
+        0x1E, 0x23, 0x09, 2, 0, 1, 0, 0, 0, 1,
+        // {"a": 42 (UInt8), "b.c": "foo" (String)}
+        2, 1, b'a', 0x01, 42, 3, b'b', b'.', b'c', 0x15, 3, b'f', b'o', b'o',
+    ];
+    assert_eq!(roundtrip(&value, None), expected);
+
+    // Typed paths are decoded according to the column definition.
+    #[rustfmt::skip]
+    let typed = [
+        0x1E, 0x23, 0x09, 2, 0, 1, 0, 0, 0, 1,
+        // `a` is encoded without the type prefix
+        2, 1, b'a', 42, 3, b'b', b'.', b'c', 0x15, 3, b'f', b'o', b'o',
+    ];
+    let metadata = metadata::<Sample>(&[("dynamic", "Dynamic"), ("json", "JSON(a UInt8)")]);
+    let decoded: Sample = super::deserialize_row(&mut &typed[..], Some(&metadata)).unwrap();
+    assert_eq!(decoded, value);
+
+    // Truncated data must be reported as such to be retried by cursors.
+    for len in 0..typed.len() {
+        let result: Result<Sample, _> = super::deserialize_row(&mut &typed[..len], Some(&metadata));
+        assert!(matches!(result, Err(crate::error::Error::NotEnoughData)));
+    }
+}
+
+#[test]
+fn it_validates_special_types() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        total: u64,
+        interval: i64,
+        items: Vec<(String, u32)>,
+        vector: [f32; 2],
+    }
+
+    impl_row!(Sample, ["total", "interval", "items", "vector"]);
+
+    let value = Sample {
+        total: 42,
+        interval: -3,
+        items: vec![("foo".into(), 1), ("bar".into(), 5)],
+        vector: [1.5, -2.0],
+    };
+
+    let columns = [
+        ("total", "SimpleAggregateFunction(sum, UInt64)"),
+        ("interval", "IntervalDay"),
+        ("items", "Nested(name String, count UInt32)"),
+        ("vector", "QBit(Float32, 2)"),
+    ];
+    roundtrip(&value, Some(&columns));
+}
+
+mod aggregate_states {
+    use super::*;
+    use crate::aggregate::{
+        ArgMaxState, AvgState, CountState, GroupArrayState, MaxState, MinState, SumState,
+        UniqExactState,
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        count: CountState,
+        sum: SumState<u64>,
+        min: MinState<String>,
+        max: MaxState<i32>,
+        avg: AvgState<f64>,
+        uniq: UniqExactState<u8>,
+        group: GroupArrayState<String>,
+        arg_max: ArgMaxState<String, u16>,
+    }
+
+    impl_row!(
+        Sample,
+        ["count", "sum", "min", "max", "avg", "uniq", "group", "arg_max"]
+    );
+
+    fn sample() -> Sample {
+        Sample {
+            count: CountState(300),
+            sum: SumState(5),
+            min: MinState(Some("ab".into())),
+            max: MaxState(None),
+            avg: AvgState {
+                numerator: 1.5,
+                denominator: 2,
+            },
+            uniq: UniqExactState(vec![3, 1]),
+            group: GroupArrayState(vec!["x".into()]),
+            arg_max: ArgMaxState {
+                result: None,
+                value: Some(7),
+            },
+        }
+    }
+
+    fn columns(count_type: &str) -> [(&str, &str); 8] {
+        [
+            ("count", count_type),
+            ("sum", "AggregateFunction(sum, UInt32)"),
+            ("min", "AggregateFunction(min, String)"),
+            ("max", "AggregateFunction(max, Int32)"),
+            ("avg", "AggregateFunction(avg, Float32)"),
+            ("uniq", "AggregateFunction(uniqExact, UInt8)"),
+            ("group", "AggregateFunction(groupArray, String)"),
+            ("arg_max", "AggregateFunction(argMax, String, UInt16)"),
+        ]
+    }
+
+    #[test]
+    fn it_serializes_states() {
+        let expected: &[&[u8]] = &[
+            // count: VarUInt
+            &[0xac, 0x02],
+            // sum: UInt64
+            &[5, 0, 0, 0, 0, 0, 0, 0],
+            // min: Int32 size including the trailing zero, bytes
+            &[3, 0, 0, 0, b'a', b'b', 0],
+            // max: no value
+            &[0],
+            // avg: Float64 numerator, VarUInt denominator
+            &1.5f64.to_le_bytes(),
+            &[2],
+            // uniq: VarUInt size, values
+            &[2, 3, 1],
+            // group: VarUInt size, strings
+            &[1, 1, b'x'],
+            // arg_max: no result (-1), the value
+            &[0xff, 0xff, 0xff, 0xff],
+            &[1, 7, 0],
+        ];
+        assert_eq!(roundtrip(&sample(), None), expected.concat());
+    }
+
+    #[test]
+    fn it_deserializes_states() {
+        roundtrip(&sample(), Some(&columns("AggregateFunction(count)")));
+    }
+
+    #[test]
+    #[should_panic(expected = "a state of `count`")]
+    fn it_reports_mismatched_functions() {
+        let mut actual = Vec::new();
+        super::super::serialize_into(&mut actual, &sample()).unwrap();
+
+        let metadata = metadata::<Sample>(&columns("AggregateFunction(uniq, UInt64)"));
+        let _: Result<Sample, _> =
+            super::super::deserialize_row(&mut actual.as_slice(), Some(&metadata));
+    }
+}
+
+mod ip_addrs {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        #[serde(with = "crate::serde::ip")]
+        v4: IpAddr,
+        #[serde(with = "crate::serde::ip")]
+        v6: IpAddr,
+        #[serde(with = "crate::serde::ip::option")]
+        opt: Option<IpAddr>,
+    }
+
+    impl_row!(Sample, ["v4", "v6", "opt"]);
+
+    #[test]
+    fn it_maps_ipv4_into_ipv6() {
+        let sample = Sample {
+            v4: Ipv4Addr::new(10, 0, 0, 1).into(),
+            v6: Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1).into(),
+            opt: Some(Ipv4Addr::LOCALHOST.into()),
+        };
+
+        let columns = [("v4", "IPv6"), ("v6", "IPv6"), ("opt", "Nullable(IPv6)")];
+        let expected: &[&[u8]] = &[
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 10, 0, 0, 1],
+            &[0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 127, 0, 0, 1],
+        ];
+        assert_eq!(roundtrip(&sample, Some(&columns)), expected.concat());
+    }
+
+    #[cfg(feature = "ipnet")]
+    #[test]
+    fn it_splits_ip_nets() {
+        use crate::serde::ipnet::{join, split};
+        use ::ipnet::IpNet;
+
+        let net: IpNet = "10.1.0.0/16".parse().unwrap();
+        let (addr, prefix_len) = split(&net);
+        assert_eq!(addr, Ipv4Addr::new(10, 1, 0, 0).to_ipv6_mapped());
+        assert_eq!(prefix_len, 112);
+        assert_eq!(join(addr, prefix_len), Ok(net));
+
+        let net: IpNet = "2001:db8::/32".parse().unwrap();
+        assert_eq!(split(&net), ("2001:db8::".parse().unwrap(), 32));
+        assert_eq!(join(split(&net).0, 32), Ok(net));
+
+        // Too short prefixes of mapped addresses are kept as IPv6 networks.
+        let net: IpNet = "::ffff:0:0/80".parse().unwrap();
+        assert_eq!(join(split(&net).0, 80), Ok(net));
+        assert!(join(addr, 129).is_err());
+    }
+}
+
+mod enums_by_name {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Season {
+        Spring,
+        Summer,
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Direction {
+        North,
+        South,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Sample {
+        seasons: Vec<Season>,
+        direction: Option<Direction>,
+    }
+
+    impl_row!(Sample, ["seasons", "direction"]);
+
+    fn deserialize(input: &[u8]) -> crate::error::Result<Sample> {
+        let metadata = metadata::<Sample>(&[
+            (
+                "seasons",
+                "Array(Enum8('Winter' = -128, 'Spring' = 0, 'Summer' = 100))",
+            ),
+            (
+                "direction",
+                "Nullable(Enum16('South' = 144, 'North' = -1, 'West' = 1))",
+            ),
+        ]);
+        super::super::deserialize_row(&mut &input[..], Some(&metadata))
+    }
+
+    #[test]
+    fn it_deserializes_enums_by_name() {
+        let input = [3, 100, 0, 0x80, 0, 144, 0];
+        assert_eq!(
+            deserialize(&input).unwrap(),
+            Sample {
+                seasons: vec![Season::Summer, Season::Spring, Season::Unknown],
+                direction: Some(Direction::South),
+            }
+        );
+    }
+
+    #[test]
+    fn it_reports_unknown_names_without_fallback() {
+        // `West` is not a variant of `Direction`, which has no fallback
+        let input = [0, 0, 1, 0];
+        let err = deserialize(&input).unwrap_err();
+        assert!(err.to_string().contains("unknown variant `West`"));
+
+        // An unknown value, which can only be caused by corrupted data
+        let input = [0, 0, 5, 0];
+        let err = deserialize(&input).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Enum16 value 5 is not present in the schema"));
+    }
+}
+
+mod variants_by_type {
+    use super::*;
+
+    // Plain enums are read by discriminators, even if variants are named like types.
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Arrays {
+        Array(Vec<String>),
+        Numbers(Vec<u64>),
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Mixed {
+        Text(String),
+        UInt64(u64),
+    }
+
+    #[crate::variant]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Ordered {
+        UInt64(u64),
+        #[clickhouse(data_type = "Array(UInt16)")]
+        Numbers(Vec<u16>),
+        String(String),
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Sample<V> {
+        values: Vec<V>,
+    }
+
+    impl_row!(Sample<V>, ["values"]);
+
+    fn deserialize<'a, V: Deserialize<'a>>(input: &mut &'a [u8], data_type: &str) -> Vec<V> {
+        let metadata = metadata::<Sample<V>>(&[("values", data_type)]);
+        let sample: Sample<V> = super::super::deserialize_row(input, Some(&metadata)).unwrap();
+        sample.values
+    }
+
+    #[test]
+    fn it_reads_plain_enums_by_discriminators() {
+        let input = [2, 1, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, b'a'];
+        let values: Vec<Arrays> = deserialize(
+            &mut &input[..],
+            "Array(Variant(Array(String), Array(UInt64)))",
+        );
+        assert_eq!(
+            values,
+            [Arrays::Numbers(vec![7]), Arrays::Array(vec!["a".into()])]
+        );
+
+        let input = [2, 1, 42, 0, 0, 0, 0, 0, 0, 0, 0, 3, b'f', b'o', b'o'];
+        let values: Vec<Mixed> = deserialize(&mut &input[..], "Array(Variant(String, UInt64))");
+        assert_eq!(values, [Mixed::UInt64(42), Mixed::Text("foo".into())]);
+    }
+
+    #[test]
+    #[should_panic(expected = "no enum variant matches the Variant type Bool")]
+    fn it_rejects_unmatched_variant_types() {
+        let input = [1, 0, 1];
+        let _: Vec<Ordered> = deserialize(&mut &input[..], "Array(Variant(Bool, String))");
+    }
+
+    #[test]
+    fn it_orders_variants_by_types() {
+        let values = vec![
+            Ordered::UInt64(42),
+            Ordered::Numbers(vec![7]),
+            Ordered::String("foo".into()),
+        ];
+        let mut actual = Vec::new();
+        super::super::serialize_into(&mut actual, &values).unwrap();
+        assert_eq!(
+            actual,
+            [3, 2, 42, 0, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 1, 3, b'f', b'o', b'o']
+        );
+
+        // A new type is added on the server, which shifts the discriminators.
+        let input = [
+            3, 3, 42, 0, 0, 0, 0, 0, 0, 0, 0, 1, 7, 0, 2, 3, b'f', b'o', b'o',
+        ];
+        let decoded: Vec<Ordered> = deserialize(
+            &mut &input[..],
+            "Array(Variant(Array(UInt16), Bool, String, UInt64))",
+        );
+        assert_eq!(decoded, values);
+    }
+}
+
+mod named_tuples {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Location {
+        lon: f64,
+        lat: f64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        location: Location,
+        history: Vec<Location>,
+    }
+
+    impl_row!(Sample, ["location", "history"]);
+
+    fn deserialize(location_type: &str, history_type: &str) -> Sample {
+        #[rustfmt::skip]
+        let input = [
+            // (lat, lon) in the schema order
+            0, 0, 0, 0, 0, 0, 0x4A, 0x40, // 52.0
+            0, 0, 0, 0, 0, 0, 0x12, 0x40, // 4.5
+            1,
+            0, 0, 0, 0, 0, 0, 0xF0, 0x3F, // 1.0
+            0, 0, 0, 0, 0, 0, 0x00, 0x40, // 2.0
+        ];
+        let metadata =
+            metadata::<Sample>(&[("location", location_type), ("history", history_type)]);
+        super::super::deserialize_row(&mut &input[..], Some(&metadata)).unwrap()
+    }
+
+    #[test]
+    fn it_deserializes_named_tuples_into_structs() {
+        let expected = Sample {
+            location: Location {
+                lat: 52.0,
+                lon: 4.5,
+            },
+            history: vec![Location { lat: 1.0, lon: 2.0 }],
+        };
+        assert_eq!(
+            deserialize(
+                "Tuple(lat Float64, lon Float64)",
+                "Array(Tuple(lat Float64, lon Float64))"
+            ),
+            expected
+        );
+        assert_eq!(
+            deserialize(
+                "Tuple(lat Float64, lon Float64)",
+                "Nested(lat Float64, lon Float64)"
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn it_deserializes_unnamed_tuples_into_structs_by_position() {
+        let expected = Sample {
+            location: Location {
+                lon: 52.0,
+                lat: 4.5,
+            },
+            history: vec![Location { lon: 1.0, lat: 2.0 }],
+        };
+        assert_eq!(
+            deserialize("Tuple(Float64, Float64)", "Array(Tuple(Float64, Float64))"),
+            expected
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "elements lat, longitude do not match the struct fields lon, lat")]
+    fn it_reports_mismatched_names() {
+        deserialize(
+            "Tuple(lat Float64, longitude Float64)",
+            "Array(Tuple(lat Float64, lon Float64))",
+        );
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn it_deserializes_zoned_with_column_timezone() {
+    use jiff::{Timestamp, Zoned};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        #[serde(with = "crate::serde::jiff::zoned")]
+        dt: Zoned,
+        #[serde(with = "crate::serde::jiff::zoned64::millis::option")]
+        dt64: Option<Zoned>,
+    }
+
+    impl_row!(Sample, ["dt", "dt64"]);
+
+    let ts = Timestamp::from_millisecond(1_700_000_000_123).unwrap();
+    let ts_secs = Timestamp::from_second(1_700_000_000).unwrap();
+    let value = Sample {
+        dt: ts.in_tz("Europe/Berlin").unwrap(),
+        dt64: Some(ts.in_tz("America/New_York").unwrap()),
+    };
+
+    let mut actual = Vec::new();
+    super::serialize_into(&mut actual, &value).unwrap();
+
+    let mut expected = 1_700_000_000u32.to_le_bytes().to_vec();
+    expected.push(0x00); // not null
+    expected.extend_from_slice(&1_700_000_000_123i64.to_le_bytes());
+    assert_eq!(actual, expected);
+
+    // Without validation, time zones are unknown.
+    let decoded: Sample = super::deserialize_row(&mut actual.as_slice(), None).unwrap();
+    assert_eq!(decoded.dt, ts_secs.to_zoned(jiff::tz::TimeZone::UTC));
+    assert_eq!(decoded.dt64, Some(ts.to_zoned(jiff::tz::TimeZone::UTC)));
+
+    let metadata = metadata::<Sample>(&[
+        ("dt", "DateTime('Asia/Tokyo')"),
+        ("dt64", "Nullable(DateTime64(3))"),
+    ]);
+    let decoded: Sample = super::deserialize_row(&mut actual.as_slice(), Some(&metadata)).unwrap();
+    assert_eq!(decoded.dt.time_zone().iana_name(), Some("Asia/Tokyo"));
+    assert_eq!(decoded.dt.timestamp(), ts_secs);
+    assert_eq!(decoded.dt64, Some(ts.to_zoned(jiff::tz::TimeZone::UTC)));
+}
+
+#[cfg(all(feature = "chrono-tz", feature = "time-tz"))]
+#[test]
+fn it_deserializes_datetime_with_column_timezone() {
+    use chrono::{DateTime, TimeZone};
+    use time::{OffsetDateTime, UtcOffset};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        #[serde(with = "crate::serde::chrono::datetime_tz")]
+        chrono: DateTime<chrono_tz::Tz>,
+        #[serde(with = "crate::serde::chrono::datetime64_tz::millis::option")]
+        chrono64: Option<DateTime<chrono_tz::Tz>>,
+        #[serde(with = "crate::serde::time::datetime_tz")]
+        time: OffsetDateTime,
+        #[serde(with = "crate::serde::time::datetime64_tz::nanos")]
+        time64: OffsetDateTime,
+    }
+
+    impl_row!(Sample, ["chrono", "chrono64", "time", "time64"]);
+
+    // 2023-11-14 22:13:20 UTC
+    let berlin = chrono_tz::Europe::Berlin
+        .timestamp_opt(1_700_000_000, 0)
+        .unwrap();
+    let value = Sample {
+        chrono: berlin,
+        chrono64: Some(berlin),
+        time: OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap(),
+        time64: OffsetDateTime::from_unix_timestamp(1_700_000_000)
+            .unwrap()
+            .to_offset(UtcOffset::from_hms(-5, 0, 0).unwrap()),
+    };
+
+    let mut actual = Vec::new();
+    super::serialize_into(&mut actual, &value).unwrap();
+
+    // All values are written as UTC timestamps.
+    let mut expected = 1_700_000_000u32.to_le_bytes().to_vec();
+    expected.push(0x00); // not null
+    expected.extend_from_slice(&1_700_000_000_000i64.to_le_bytes());
+    expected.extend_from_slice(&1_700_000_000u32.to_le_bytes());
+    expected.extend_from_slice(&1_700_000_000_000_000_000i64.to_le_bytes());
+    assert_eq!(actual, expected);
+
+    // Without validation, time zones are unknown.
+    let decoded: Sample = super::deserialize_row(&mut actual.as_slice(), None).unwrap();
+    assert_eq!(decoded.chrono.timezone(), chrono_tz::UTC);
+    assert_eq!(decoded.chrono, value.chrono);
+    assert_eq!(decoded.time.offset(), UtcOffset::UTC);
+    assert_eq!(decoded.time64, value.time64);
+
+    let metadata = metadata::<Sample>(&[
+        ("chrono", "DateTime('Asia/Tokyo')"),
+        ("chrono64", "Nullable(DateTime64(3, 'America/New_York'))"),
+        ("time", "DateTime('Asia/Kolkata')"),
+        ("time64", "DateTime64(9)"),
+    ]);
+    let decoded: Sample = super::deserialize_row(&mut actual.as_slice(), Some(&metadata)).unwrap();
+    assert_eq!(decoded.chrono.timezone(), chrono_tz::Asia::Tokyo);
+    assert_eq!(decoded.chrono.to_string(), "2023-11-15 07:13:20 JST");
+    let chrono64 = decoded.chrono64.unwrap();
+    assert_eq!(chrono64.timezone(), chrono_tz::America::New_York);
+    assert_eq!(chrono64.to_string(), "2023-11-14 17:13:20 EST");
+    assert_eq!(decoded.time, value.time);
+    assert_eq!(
+        decoded.time.offset(),
+        UtcOffset::from_hms(5, 30, 0).unwrap()
+    );
+    assert_eq!(decoded.time64.offset(), UtcOffset::UTC);
+}
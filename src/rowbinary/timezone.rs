@@ -0,0 +1,108 @@
+//! Timestamps of `DateTime` and `DateTime64(P)` along with time zones of the columns.
+//!
+//! A serde helper requests a timestamp using one of the markers as a newtype name.
+//! `RowBinaryDeserializer` provides it as a sequence of the timestamp and, if validation
+//! is enabled and the column has an explicit time zone, the name of that time zone.
+//! Other deserializers are expected to provide just the timestamp.
+
+#[cfg(any(feature = "jiff", feature = "chrono-tz", feature = "time-tz"))]
+use std::fmt;
+
+#[cfg(any(feature = "jiff", feature = "chrono-tz", feature = "time-tz"))]
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::de::{DeserializeSeed, IntoDeserializer, SeqAccess};
+
+use crate::error::{Error, Result};
+
+/// Used as a newtype name to request a `DateTime` value (seconds) from the deserializer.
+pub(crate) const DATETIME: &str = "$clickhouse::DateTimeTz";
+/// Used as a newtype name to request a `DateTime64(P)` value (ticks) from the deserializer.
+pub(crate) const DATETIME64: &str = "$clickhouse::DateTime64Tz";
+
+/// Deserializes a timestamp requested by the `marker`
+/// and the time zone of the column, if it's known.
+#[cfg(any(feature = "jiff", feature = "chrono-tz", feature = "time-tz"))]
+pub(crate) fn deserialize<'de, D>(
+    deserializer: D,
+    marker: &'static str,
+) -> Result<(i64, Option<String>), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = TimestampVisitor {
+        datetime64: marker == DATETIME64,
+    };
+    deserializer.deserialize_newtype_struct(marker, visitor)
+}
+
+/// Provided by `RowBinaryDeserializer` to visitors of the markers.
+pub(crate) struct TimestampAccess<'a> {
+    timestamp: Option<i64>,
+    timezone: Option<&'a str>,
+}
+
+impl<'a> TimestampAccess<'a> {
+    pub(crate) fn new(timestamp: i64, timezone: Option<&'a str>) -> Self {
+        Self {
+            timestamp: Some(timestamp),
+            timezone,
+        }
+    }
+}
+
+impl<'de> SeqAccess<'de> for TimestampAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if let Some(timestamp) = self.timestamp.take() {
+            return seed.deserialize(timestamp.into_deserializer()).map(Some);
+        }
+
+        self.timezone
+            .take()
+            .map(|timezone| seed.deserialize(timezone.into_deserializer()))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(usize::from(self.timestamp.is_some()) + usize::from(self.timezone.is_some()))
+    }
+}
+
+#[cfg(any(feature = "jiff", feature = "chrono-tz", feature = "time-tz"))]
+struct TimestampVisitor {
+    datetime64: bool,
+}
+
+#[cfg(any(feature = "jiff", feature = "chrono-tz", feature = "time-tz"))]
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = (i64, Option<String>);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.datetime64 {
+            formatter.write_str("a DateTime64 value")
+        } else {
+            formatter.write_str("a DateTime value")
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let timestamp = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let timezone = seq.next_element()?;
+        Ok((timestamp, timezone))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let timestamp = if self.datetime64 {
+            i64::deserialize(deserializer)?
+        } else {
+            i64::from(u32::deserialize(deserializer)?)
+        };
+        Ok((timestamp, None))
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{row::RowKind, row_metadata::RowMetadata, Row};
-use clickhouse_types::data_types::{Column, DataTypeNode, DecimalType, EnumType};
+use clickhouse_types::data_types::{Column, DataTypeNode, DecimalType, EnumType, JsonType};
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::marker::PhantomData;
@@ -28,11 +28,32 @@ pub(crate) trait SchemaValidator<R: Row>: Sized {
     /// It is used only if the crate detects that while the field names and the types are correct,
     /// the field order in the struct does not match the column order in the database schema.
     fn get_schema_index(&self, struct_idx: usize) -> usize;
+    /// Returns the definition of the `JSON` column validated by [`SerdeType::Json`].
+    /// It is required to decode typed paths of the column.
+    fn json_type(&self) -> Option<&JsonType>;
+    /// Returns the time zone of the `DateTime` or `DateTime64(P)` column validated by
+    /// [`SerdeType::DateTime`] or [`SerdeType::DateTime64`], if it is specified.
+    fn timezone(&self) -> Option<&str>;
+    /// Returns the type and the values of the `Enum8` or `Enum16` column validated
+    /// by [`SerdeType::Enum`], so that the enum is deserialized by the names of its variants.
+    fn enum_values(&self) -> Option<(&EnumType, &HashMap<i16, String>)>;
+    /// Returns the index of the variant of a Rust enum matching the type of the `Variant` column
+    /// with the given discriminator by name, e.g. `UInt64` or `Array(String)`.
+    /// Returns `None` if the column is not validated, so the discriminator is used as the index.
+    /// Panics if no variant or more than one variant matches the type.
+    fn variant_index(&self, discriminator: u8, variants: &[&str]) -> Option<usize>;
+    /// Validates a struct that is a value of a column or of its element, e.g. a named tuple.
+    /// Returns `None` if the fields of the struct should be validated by this validator instead,
+    /// i.e. if the struct is the row itself, or it is an element of a tuple row.
+    fn validate_struct(&mut self, fields: &'static [&'static str]) -> Option<Self::Inner<'_>>;
 }
 
 pub(crate) struct DataTypeValidator<'cursor, R: Row> {
     metadata: &'cursor RowMetadata,
     current_column_idx: usize,
+    /// Whether the struct of the row has been visited,
+    /// so that structs of the columns can be told apart from it.
+    is_row_struct_visited: bool,
     _marker: PhantomData<R>,
 }
 
@@ -41,6 +62,7 @@ impl<'cursor, R: Row> DataTypeValidator<'cursor, R> {
         Self {
             metadata,
             current_column_idx: 0,
+            is_row_struct_visited: false,
             _marker: PhantomData::<R>,
         }
     }
@@ -188,6 +210,35 @@ impl<'cursor, R: Row> SchemaValidator<R> for DataTypeValidator<'cursor, R> {
     fn validate_identifier<T: EnumOrVariantIdentifier>(&mut self, _value: T) {
         unreachable!()
     }
+
+    #[inline]
+    fn json_type(&self) -> Option<&JsonType> {
+        None
+    }
+
+    #[inline]
+    fn timezone(&self) -> Option<&str> {
+        None
+    }
+
+    #[inline]
+    fn enum_values(&self) -> Option<(&EnumType, &HashMap<i16, String>)> {
+        None
+    }
+
+    #[inline]
+    fn variant_index(&self, _discriminator: u8, _variants: &[&str]) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn validate_struct(&mut self, fields: &'static [&'static str]) -> Option<Self::Inner<'_>> {
+        if matches!(R::KIND, RowKind::Struct) && !self.is_row_struct_visited {
+            self.is_row_struct_visited = true;
+            return None;
+        }
+        Some(self.validate(SerdeType::Struct(fields)))
+    }
 }
 
 /// Having a ClickHouse `Map<K, V>` defined as a `HashMap<K, V>` in Rust, Serde will call:
@@ -225,12 +276,25 @@ pub(crate) enum InnerDataTypeValidatorKind<'cursor> {
     /// Allows supporting ClickHouse `Map<K, V>` defined as `Vec<(K, V)>` in Rust
     MapAsSequence(&'cursor [Box<DataTypeNode>; 2], MapAsSequenceValidatorState),
     Tuple(&'cursor [DataTypeNode]),
+    /// `Nested(..)` is encoded as `Array(Tuple(..))`; contains the names and types of the tuple elements
+    Nested(&'cursor [String], &'cursor [DataTypeNode]),
+    /// A named tuple deserialized as a struct with a different order of fields.
+    /// Contains the names of the elements, the types of the remaining elements and the struct fields
+    NamedTuple(
+        &'cursor [String],
+        &'cursor [DataTypeNode],
+        &'static [&'static str],
+    ),
     /// This is a hack to support deserializing tuples/arrays (and not structs) from fetch calls
     RootTuple(&'cursor [Column], usize),
     RootArray(&'cursor DataTypeNode),
     Enum(&'cursor HashMap<i16, String>),
+    /// A Rust enum deserialized by the names of the variants of `Enum8` or `Enum16`
+    EnumNames(&'cursor EnumType, &'cursor HashMap<i16, String>),
     Variant(&'cursor [DataTypeNode], VariantValidationState),
     Nullable(&'cursor DataTypeNode),
+    Json(&'cursor JsonType),
+    Timezone(Option<&'cursor str>),
 }
 
 #[derive(Debug)]
@@ -303,6 +367,28 @@ impl<'cursor, R: Row> SchemaValidator<R> for Option<InnerDataTypeValidator<'_, '
                     }
                 }
             }
+            InnerDataTypeValidatorKind::NamedTuple(_, elements_types, _) => {
+                // The number of elements is checked in advance
+                let (first, rest) = elements_types.split_first()?;
+                *elements_types = rest;
+                validate_impl(inner.root, first, &serde_type, true)
+            }
+            InnerDataTypeValidatorKind::Nested(names, elements_types) => match serde_type {
+                SerdeType::Tuple(_) => Some(InnerDataTypeValidator {
+                    root: inner.root,
+                    kind: InnerDataTypeValidatorKind::Tuple(elements_types),
+                }),
+                SerdeType::Struct(fields) => {
+                    validate_struct_fields(inner.root, names, elements_types, fields)
+                }
+                _ => {
+                    let (full_name, full_data_type) = inner.root.get_current_column_name_and_type();
+                    panic!(
+                        "While processing column {full_name} defined as {full_data_type}: \
+                            attempting to deserialize Nested elements as {serde_type}, expected tuples"
+                    )
+                }
+            },
             InnerDataTypeValidatorKind::FixedString(_len) => {
                 None // actually unreachable
             }
@@ -345,6 +431,12 @@ impl<'cursor, R: Row> SchemaValidator<R> for Option<InnerDataTypeValidator<'_, '
             InnerDataTypeValidatorKind::Enum(_values_map) => {
                 unreachable!()
             }
+            // `JSON` values, timestamps with time zones and enum names are decoded as a whole
+            InnerDataTypeValidatorKind::Json(_)
+            | InnerDataTypeValidatorKind::Timezone(_)
+            | InnerDataTypeValidatorKind::EnumNames(..) => {
+                unreachable!()
+            }
         }
     }
 
@@ -386,13 +478,154 @@ impl<'cursor, R: Row> SchemaValidator<R> for Option<InnerDataTypeValidator<'_, '
 
     #[inline(always)]
     fn is_field_order_wrong(&self) -> bool {
-        false
+        matches!(
+            self,
+            Some(InnerDataTypeValidator {
+                kind: InnerDataTypeValidatorKind::NamedTuple(..),
+                ..
+            })
+        )
     }
 
-    #[cold]
-    fn get_schema_index(&self, _struct_idx: usize) -> usize {
-        unreachable!()
+    #[inline]
+    fn get_schema_index(&self, struct_idx: usize) -> usize {
+        match self {
+            Some(InnerDataTypeValidator {
+                kind: InnerDataTypeValidatorKind::NamedTuple(names, _, fields),
+                ..
+            }) => fields
+                .iter()
+                .position(|field| *field == names[struct_idx])
+                .expect("named tuple elements are checked in advance"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn json_type(&self) -> Option<&JsonType> {
+        match self {
+            Some(InnerDataTypeValidator {
+                kind: InnerDataTypeValidatorKind::Json(json),
+                ..
+            }) => Some(json),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn timezone(&self) -> Option<&str> {
+        match self {
+            Some(InnerDataTypeValidator {
+                kind: InnerDataTypeValidatorKind::Timezone(tz),
+                ..
+            }) => *tz,
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn enum_values(&self) -> Option<(&EnumType, &HashMap<i16, String>)> {
+        match self {
+            Some(InnerDataTypeValidator {
+                kind: InnerDataTypeValidatorKind::EnumNames(enum_type, values_map),
+                ..
+            }) => Some((*enum_type, *values_map)),
+            _ => None,
+        }
+    }
+
+    fn variant_index(&self, discriminator: u8, variants: &[&str]) -> Option<usize> {
+        let Some(InnerDataTypeValidator {
+            root,
+            kind: InnerDataTypeValidatorKind::Variant(possible_types, _),
+        }) = self
+        else {
+            return None;
+        };
+
+        let data_type = possible_types.get(usize::from(discriminator))?;
+        let mut matching = variants
+            .iter()
+            .enumerate()
+            .filter(|(_, variant)| type_name_matches(data_type, variant))
+            .map(|(index, _)| index);
+        match (matching.next(), matching.next()) {
+            (Some(index), None) => Some(index),
+            (first, _) => {
+                let (full_name, full_data_type) = root.get_current_column_name_and_type();
+                let reason = if first.is_some() {
+                    "multiple enum variants match"
+                } else {
+                    "no enum variant matches"
+                };
+                panic!(
+                    "While processing column {full_name} defined as {full_data_type}: \
+                    {reason} the Variant type {data_type}, expected one of {}",
+                    variants.join(", "),
+                )
+            }
+        }
+    }
+
+    #[inline]
+    fn validate_struct(&mut self, fields: &'static [&'static str]) -> Option<Self> {
+        match self {
+            // Fields of a struct in a tuple row are validated against the columns
+            Some(InnerDataTypeValidator {
+                kind: InnerDataTypeValidatorKind::RootTuple(..),
+                ..
+            })
+            | None => None,
+            Some(_) => Some(self.validate(SerdeType::Struct(fields))),
+        }
+    }
+}
+
+/// Checks that the name of `data_type` is `name`, e.g. `Array(String)`.
+/// The name is compared while being formatted to avoid allocations.
+fn type_name_matches(data_type: &DataTypeNode, name: &str) -> bool {
+    struct Matcher<'a> {
+        rest: &'a str,
+    }
+
+    impl std::fmt::Write for Matcher<'_> {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.rest = self.rest.strip_prefix(s).ok_or(std::fmt::Error)?;
+            Ok(())
+        }
     }
+
+    let mut matcher = Matcher { rest: name };
+    std::fmt::write(&mut matcher, format_args!("{data_type}")).is_ok() && matcher.rest.is_empty()
+}
+
+/// Having a named tuple (or `Nested`) deserialized as a struct, checks that its elements
+/// match the struct fields by name. The struct is then deserialized as a map,
+/// unless the order of the fields is the same as in the schema.
+fn validate_struct_fields<'de, 'cursor, R: Row>(
+    root: &'de DataTypeValidator<'cursor, R>,
+    names: &'cursor [String],
+    elements: &'cursor [DataTypeNode],
+    fields: &'static [&'static str],
+) -> Option<InnerDataTypeValidator<'de, 'cursor, R>> {
+    let is_same_set =
+        names.len() == fields.len() && names.iter().all(|name| fields.contains(&name.as_str()));
+    if !is_same_set {
+        let (full_name, full_data_type) = root.get_current_column_name_and_type();
+        panic!(
+            "While processing column {full_name} defined as {full_data_type}: \
+            elements {} do not match the struct fields {}",
+            names.join(", "),
+            fields.join(", "),
+        );
+    }
+
+    let kind = if names.iter().zip(fields).all(|(name, field)| name == field) {
+        InnerDataTypeValidatorKind::Tuple(elements)
+    } else {
+        InnerDataTypeValidatorKind::NamedTuple(names, elements, fields)
+    };
+    Some(InnerDataTypeValidator { root, kind })
 }
 
 impl<R: Row> Drop for InnerDataTypeValidator<'_, '_, R> {
@@ -426,7 +659,11 @@ fn validate_impl<'de, 'cursor, R: Row>(
     serde_type: &SerdeType,
     is_inner: bool,
 ) -> Option<InnerDataTypeValidator<'de, 'cursor, R>> {
-    let data_type = column_data_type.remove_low_cardinality();
+    // `SimpleAggregateFunction(f, T)` values are stored as `T`
+    let data_type = match column_data_type.remove_low_cardinality() {
+        DataTypeNode::SimpleAggregateFunction(_, inner_type) => inner_type.remove_low_cardinality(),
+        data_type => data_type,
+    };
     match serde_type {
         SerdeType::Bool
             if data_type == &DataTypeNode::Bool || data_type == &DataTypeNode::UInt8 =>
@@ -464,6 +701,7 @@ fn validate_impl<'de, 'cursor, R: Row>(
             if data_type == &DataTypeNode::Int64
                 || matches!(data_type, DataTypeNode::DateTime64(_, _))
                 || matches!(data_type, DataTypeNode::Time64(_))
+                || matches!(data_type, DataTypeNode::Interval(_))
                 || matches!(
                     data_type,
                     DataTypeNode::Decimal(_, _, DecimalType::Decimal64)
@@ -498,10 +736,41 @@ fn validate_impl<'de, 'cursor, R: Row>(
         SerdeType::F32 if data_type == &DataTypeNode::Float32 => None,
         SerdeType::F64 if data_type == &DataTypeNode::Float64 => None,
         SerdeType::Str | SerdeType::String
-            if data_type == &DataTypeNode::String || data_type == &DataTypeNode::JSON =>
+            if data_type == &DataTypeNode::String || matches!(data_type, DataTypeNode::JSON(_)) =>
         {
             None
         }
+        SerdeType::Dynamic if data_type == &DataTypeNode::Dynamic => None,
+        // The state is decoded by its type, so the elements are not validated
+        SerdeType::AggregateFunction(function) => match data_type {
+            DataTypeNode::AggregateFunction(name, _)
+                if name.split('(').next() == Some(*function) =>
+            {
+                None
+            }
+            _ => root.panic_on_schema_mismatch(data_type, serde_type, is_inner),
+        },
+        SerdeType::Json => match data_type {
+            DataTypeNode::JSON(json) => Some(InnerDataTypeValidator {
+                root,
+                kind: InnerDataTypeValidatorKind::Json(json),
+            }),
+            _ => root.panic_on_schema_mismatch(data_type, serde_type, is_inner),
+        },
+        SerdeType::DateTime => match data_type {
+            DataTypeNode::DateTime(tz) => Some(InnerDataTypeValidator {
+                root,
+                kind: InnerDataTypeValidatorKind::Timezone(tz.as_deref()),
+            }),
+            _ => root.panic_on_schema_mismatch(data_type, serde_type, is_inner),
+        },
+        SerdeType::DateTime64 => match data_type {
+            DataTypeNode::DateTime64(_, tz) => Some(InnerDataTypeValidator {
+                root,
+                kind: InnerDataTypeValidatorKind::Timezone(tz.as_deref()),
+            }),
+            _ => root.panic_on_schema_mismatch(data_type, serde_type, is_inner),
+        },
         // allows to work with BLOB strings as well
         SerdeType::Bytes(_) | SerdeType::ByteBuf(_) if data_type == &DataTypeNode::String => None,
         SerdeType::Option => {
@@ -519,6 +788,10 @@ fn validate_impl<'de, 'cursor, R: Row>(
                 root,
                 kind: InnerDataTypeValidatorKind::Array(inner_type),
             }),
+            DataTypeNode::Nested(names, elements) => Some(InnerDataTypeValidator {
+                root,
+                kind: InnerDataTypeValidatorKind::Nested(names, elements),
+            }),
             // A map can be defined as `Vec<(K, V)>` in the struct
             DataTypeNode::Map(kv) => Some(InnerDataTypeValidator {
                 root,
@@ -564,10 +837,12 @@ fn validate_impl<'de, 'cursor, R: Row>(
                     )
                 }
             }
-            DataTypeNode::Tuple(elements) => Some(InnerDataTypeValidator {
-                root,
-                kind: InnerDataTypeValidatorKind::Tuple(elements),
-            }),
+            DataTypeNode::Tuple(elements) | DataTypeNode::NamedTuple(_, elements) => {
+                Some(InnerDataTypeValidator {
+                    root,
+                    kind: InnerDataTypeValidatorKind::Tuple(elements),
+                })
+            }
             DataTypeNode::Array(inner_type) => Some(InnerDataTypeValidator {
                 root,
                 kind: InnerDataTypeValidatorKind::Array(inner_type),
@@ -576,6 +851,23 @@ fn validate_impl<'de, 'cursor, R: Row>(
                 root,
                 kind: InnerDataTypeValidatorKind::Array(&DataTypeNode::UInt8),
             }),
+            // `QBit(T, N)` has no length prefix, so it can be defined only as `[T; N]`
+            DataTypeNode::QBit(element_type, dimension) if dimension == len => {
+                Some(InnerDataTypeValidator {
+                    root,
+                    kind: InnerDataTypeValidatorKind::Array(element_type),
+                })
+            }
+            DataTypeNode::Int256
+            | DataTypeNode::UInt256
+            | DataTypeNode::Decimal(_, _, DecimalType::Decimal256)
+                if *len == 32 =>
+            {
+                Some(InnerDataTypeValidator {
+                    root,
+                    kind: InnerDataTypeValidatorKind::Array(&DataTypeNode::UInt8),
+                })
+            }
             DataTypeNode::UUID => Some(InnerDataTypeValidator {
                 root,
                 kind: InnerDataTypeValidatorKind::Tuple(UUID_TUPLE_ELEMENTS),
@@ -586,6 +878,17 @@ fn validate_impl<'de, 'cursor, R: Row>(
             }),
             _ => root.panic_on_schema_mismatch(data_type, serde_type, is_inner),
         },
+        SerdeType::Struct(fields) => match data_type {
+            // Elements of an unnamed tuple are matched with the fields by position
+            DataTypeNode::Tuple(elements) => Some(InnerDataTypeValidator {
+                root,
+                kind: InnerDataTypeValidatorKind::Tuple(elements),
+            }),
+            DataTypeNode::NamedTuple(names, elements) => {
+                validate_struct_fields(root, names, elements, fields)
+            }
+            _ => root.panic_on_schema_mismatch(data_type, serde_type, is_inner),
+        },
         SerdeType::Map(_) => {
             if let DataTypeNode::Map(kv) = data_type {
                 Some(InnerDataTypeValidator {
@@ -596,19 +899,20 @@ fn validate_impl<'de, 'cursor, R: Row>(
                 panic!("Expected Map for {serde_type} call, but got {data_type}",)
             }
         }
-        SerdeType::Enum => {
-            if let DataTypeNode::Variant(possible_types) = data_type {
-                Some(InnerDataTypeValidator {
-                    root,
-                    kind: InnerDataTypeValidatorKind::Variant(
-                        possible_types,
-                        VariantValidationState::Pending,
-                    ),
-                })
-            } else {
-                panic!("Expected Variant for {serde_type} call, but got {data_type}")
-            }
-        }
+        SerdeType::Enum => match data_type {
+            DataTypeNode::Variant(possible_types) => Some(InnerDataTypeValidator {
+                root,
+                kind: InnerDataTypeValidatorKind::Variant(
+                    possible_types,
+                    VariantValidationState::Pending,
+                ),
+            }),
+            DataTypeNode::Enum(enum_type, values_map) => Some(InnerDataTypeValidator {
+                root,
+                kind: InnerDataTypeValidatorKind::EnumNames(enum_type, values_map),
+            }),
+            _ => panic!("Expected Variant or Enum for {serde_type} call, but got {data_type}"),
+        },
 
         _ => root.panic_on_schema_mismatch(
             data_type,
@@ -637,6 +941,31 @@ impl<R: Row> SchemaValidator<R> for () {
     fn get_schema_index(&self, _struct_idx: usize) -> usize {
         unreachable!()
     }
+
+    #[inline(always)]
+    fn json_type(&self) -> Option<&JsonType> {
+        None
+    }
+
+    #[inline(always)]
+    fn timezone(&self) -> Option<&str> {
+        None
+    }
+
+    #[inline(always)]
+    fn enum_values(&self) -> Option<(&EnumType, &HashMap<i16, String>)> {
+        None
+    }
+
+    #[inline(always)]
+    fn variant_index(&self, _discriminator: u8, _variants: &[&str]) -> Option<usize> {
+        None
+    }
+
+    #[inline(always)]
+    fn validate_struct(&mut self, _fields: &'static [&'static str]) -> Option<()> {
+        None
+    }
 }
 
 /// Which Serde data type (De)serializer used for the given type.
@@ -666,10 +995,22 @@ pub(crate) enum SerdeType {
     Tuple(usize),
     Seq(usize),
     Map(usize),
+    /// A struct that is a value of a column, e.g. a named tuple; contains the names of the fields.
+    Struct(&'static [&'static str]),
+    /// A `Dynamic` value requested by [`crate::value::Value`].
+    Dynamic,
+    /// A `JSON` value requested by [`crate::serde::json`].
+    Json,
+    /// A `DateTime` value along with the time zone of the column.
+    DateTime,
+    /// A `DateTime64(P)` value along with the time zone of the column.
+    DateTime64,
+    /// A state of `AggregateFunction(..)` requested by [`crate::aggregate`];
+    /// contains the name of the function.
+    AggregateFunction(&'static str),
     // Identifier,
     // Char,
     // Unit,
-    // Struct,
     // NewtypeStruct,
     // TupleStruct,
     // UnitStruct,
@@ -701,10 +1042,15 @@ impl Display for SerdeType {
             SerdeType::Seq(_len) => write!(f, "Vec<T>"),
             SerdeType::Tuple(len) => write!(f, "a tuple or sequence with length {len}"),
             SerdeType::Map(_len) => write!(f, "Map<K, V>"),
+            SerdeType::Struct(fields) => write!(f, "a struct with fields {}", fields.join(", ")),
+            SerdeType::Dynamic => write!(f, "Value"),
+            SerdeType::AggregateFunction(function) => write!(f, "a state of `{function}`"),
+            SerdeType::Json => write!(f, "JSON value"),
+            SerdeType::DateTime => write!(f, "u32 with a time zone"),
+            SerdeType::DateTime64 => write!(f, "i64 with a time zone"),
             // SerdeType::Identifier => "identifier",
             // SerdeType::Char => "char",
             // SerdeType::Unit => "()",
-            // SerdeType::Struct => "struct",
             // SerdeType::NewtypeStruct => "newtype struct",
             // SerdeType::TupleStruct => "tuple struct",
             // SerdeType::UnitStruct => "unit struct",
@@ -0,0 +1,236 @@
+//! Schema introspection, see [`Client::describe_table`] and neighbouring methods.
+//!
+//! Columns are described by [`ColumnInfo`], which contains the same [`Column`]
+//! as reported by the server in `RowBinaryWithNamesAndTypes` headers,
+//! so it can be compared with the columns of query results.
+
+use serde::Deserialize;
+
+// Required by `#[derive(Row)]`.
+use crate as clickhouse;
+use crate::{
+    error::{Error, Result},
+    sql::Identifier,
+    types::{Column, DataTypeNode},
+    Client, Row,
+};
+
+/// A column of a table, as returned by [`Client::describe_table`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ColumnInfo {
+    /// The name and the parsed type of the column.
+    pub column: Column,
+    /// The kind of the default expression, if any.
+    pub default_kind: Option<DefaultKind>,
+    /// The default expression, e.g. `now()`, if any.
+    pub default_expression: Option<String>,
+    /// The compression codec, e.g. `Delta(8), ZSTD(1)`, if any.
+    pub codec: Option<String>,
+    /// The comment, if any.
+    pub comment: Option<String>,
+    /// The TTL expression, if any.
+    pub ttl: Option<String>,
+}
+
+impl From<ColumnInfo> for Column {
+    fn from(info: ColumnInfo) -> Self {
+        info.column
+    }
+}
+
+/// The kind of the default expression of a column.
+///
+/// See <https://clickhouse.com/docs/sql-reference/statements/create/table#default_values>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DefaultKind {
+    /// `DEFAULT expr`
+    Default,
+    /// `MATERIALIZED expr`
+    Materialized,
+    /// `ALIAS expr`
+    Alias,
+    /// `EPHEMERAL [expr]`
+    Ephemeral,
+}
+
+impl DefaultKind {
+    fn parse(kind: &str) -> Result<Option<Self>> {
+        Ok(Some(match kind {
+            "" => return Ok(None),
+            "DEFAULT" => Self::Default,
+            "MATERIALIZED" => Self::Materialized,
+            "ALIAS" => Self::Alias,
+            "EPHEMERAL" => Self::Ephemeral,
+            _ => return Err(Error::BadResponse(format!("unknown default kind {kind}"))),
+        }))
+    }
+}
+
+/// The engine of a table, as returned by [`Client::table_engine`].
+#[derive(Debug, Clone, PartialEq, Eq, Row, Deserialize)]
+#[non_exhaustive]
+pub struct TableEngine {
+    /// The name of the engine, e.g. `ReplacingMergeTree`.
+    #[serde(rename = "engine")]
+    pub name: String,
+    /// The full definition of the engine with parameters and table settings,
+    /// e.g. `ReplacingMergeTree(version) ORDER BY id SETTINGS index_granularity = 8192`.
+    #[serde(rename = "engine_full")]
+    pub full: String,
+}
+
+/// An aggregated info about active parts of a partition,
+/// as returned by [`Client::partitions`].
+#[derive(Debug, Clone, PartialEq, Eq, Row, Deserialize)]
+#[non_exhaustive]
+pub struct PartitionInfo {
+    /// The partition expression value, e.g. `202401` or `tuple()`.
+    pub partition: String,
+    /// The partition ID, used in `ALTER TABLE ... PARTITION ID`.
+    pub partition_id: String,
+    /// The number of active parts.
+    pub parts: u64,
+    /// The number of rows.
+    pub rows: u64,
+    /// The compressed size on disk in bytes.
+    pub bytes_on_disk: u64,
+}
+
+#[derive(Row, Deserialize)]
+struct DescribeRow {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: String,
+    default_type: String,
+    default_expression: String,
+    comment: String,
+    codec_expression: String,
+    ttl_expression: String,
+}
+
+impl DescribeRow {
+    fn into_info(self) -> Result<ColumnInfo> {
+        let data_type = DataTypeNode::new(&self.data_type).map_err(|err| {
+            Error::BadResponse(format!("invalid type of column {}: {err}", self.name))
+        })?;
+
+        let non_empty = |s: String| (!s.is_empty()).then_some(s);
+
+        Ok(ColumnInfo {
+            default_kind: DefaultKind::parse(&self.default_type)?,
+            column: Column::new(self.name, data_type),
+            default_expression: non_empty(self.default_expression),
+            codec: non_empty(self.codec_expression),
+            comment: non_empty(self.comment),
+            ttl: non_empty(self.ttl_expression),
+        })
+    }
+}
+
+pub(crate) async fn describe_table(
+    client: &Client,
+    database: &str,
+    table: &str,
+) -> Result<Vec<ColumnInfo>> {
+    client
+        .query("DESCRIBE TABLE ?.?")
+        .bind(Identifier(database))
+        .bind(Identifier(table))
+        .fetch_all::<DescribeRow>()
+        .await?
+        .into_iter()
+        .map(DescribeRow::into_info)
+        .collect()
+}
+
+pub(crate) async fn list_databases(client: &Client) -> Result<Vec<String>> {
+    client
+        .query("SELECT name FROM system.databases ORDER BY name")
+        .fetch_all()
+        .await
+}
+
+pub(crate) async fn list_tables(client: &Client, database: &str) -> Result<Vec<String>> {
+    client
+        .query("SELECT name FROM system.tables WHERE database = ? ORDER BY name")
+        .bind(database)
+        .fetch_all()
+        .await
+}
+
+pub(crate) async fn table_engine(
+    client: &Client,
+    database: &str,
+    table: &str,
+) -> Result<TableEngine> {
+    client
+        .query("SELECT ?fields FROM system.tables WHERE database = ? AND name = ?")
+        .bind(database)
+        .bind(table)
+        .fetch_one()
+        .await
+}
+
+pub(crate) async fn partitions(
+    client: &Client,
+    database: &str,
+    table: &str,
+) -> Result<Vec<PartitionInfo>> {
+    client
+        .query(
+            "SELECT partition, partition_id, count() AS parts, sum(rows) AS rows, \
+                    sum(bytes_on_disk) AS bytes_on_disk \
+             FROM system.parts \
+             WHERE database = ? AND table = ? AND active \
+             GROUP BY partition, partition_id \
+             ORDER BY partition_id",
+        )
+        .bind(database)
+        .bind(table)
+        .fetch_all()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_row() {
+        let row = DescribeRow {
+            name: "ts".into(),
+            data_type: "DateTime64(3, 'UTC')".into(),
+            default_type: "MATERIALIZED".into(),
+            default_expression: "now64(3)".into(),
+            comment: "".into(),
+            codec_expression: "Delta(8), ZSTD(1)".into(),
+            ttl_expression: "".into(),
+        };
+
+        let info = row.into_info().unwrap();
+        assert_eq!(info.column.name, "ts");
+        assert_eq!(info.column.data_type.to_string(), "DateTime64(3, 'UTC')");
+        assert_eq!(info.default_kind, Some(DefaultKind::Materialized));
+        assert_eq!(info.default_expression.as_deref(), Some("now64(3)"));
+        assert_eq!(info.codec.as_deref(), Some("Delta(8), ZSTD(1)"));
+        assert_eq!(info.comment, None);
+        assert_eq!(info.ttl, None);
+
+        let row = DescribeRow {
+            name: "x".into(),
+            data_type: "Unknown".into(),
+            default_type: "".into(),
+            default_expression: "".into(),
+            comment: "".into(),
+            codec_expression: "".into(),
+            ttl_expression: "".into(),
+        };
+        let err = row.into_info().unwrap_err();
+        assert!(
+            err.to_string().contains("invalid type of column x"),
+            "{err}"
+        );
+    }
+}
@@ -44,6 +44,9 @@ macro_rules! option {
 }
 
 /// Ser/de [`std::net::Ipv4Addr`] to/from `IPv4`.
+///
+/// Also supports binding values in [`crate::query::Query::bind`]
+/// and [`crate::query::Query::param`] as `toIPv4(..)` via [`ipv4::wrap`].
 pub mod ipv4 {
     use std::net::Ipv4Addr;
 
@@ -68,6 +71,257 @@ pub mod ipv4 {
         let ip: u32 = Deserialize::deserialize(deserializer)?;
         Ok(Ipv4Addr::from(ip))
     }
+
+    /// Wraps the value to be used in [`crate::query::Query::bind`]
+    /// and [`crate::query::Query::param`] as `IPv4`.
+    pub fn wrap(value: Ipv4Addr) -> impl Serialize {
+        struct Wrapped(Ipv4Addr);
+
+        impl Serialize for Wrapped {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    let literal = crate::sql::ser::typed_literal::IPV4;
+                    serializer.serialize_newtype_struct(literal, &self.0.to_string())
+                } else {
+                    serialize(&self.0, serializer)
+                }
+            }
+        }
+
+        Wrapped(value)
+    }
+}
+
+/// Ser/de [`std::net::IpAddr`] to/from `IPv6`.
+///
+/// IPv4 addresses are stored as IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`),
+/// the same way ClickHouse converts `IPv4` to `IPv6`, and are read back as
+/// [`IpAddr::V4`](std::net::IpAddr::V4).
+///
+/// Also supports binding values in [`crate::query::Query::bind`]
+/// and [`crate::query::Query::param`] via [`ip::wrap`], which renders
+/// the address as `toIPv6(..)`. Bound as is, `IpAddr` is a plain string.
+///
+/// ```
+/// use std::net::IpAddr;
+///
+/// #[derive(clickhouse::Row, serde::Serialize, serde::Deserialize)]
+/// struct MyRow {
+///     #[serde(with = "clickhouse::serde::ip")]
+///     client: IpAddr, // IPv6
+///     #[serde(with = "clickhouse::serde::ip::option")]
+///     proxy: Option<IpAddr>, // Nullable(IPv6)
+/// }
+/// ```
+pub mod ip {
+    use std::net::{IpAddr, Ipv6Addr};
+
+    use super::*;
+
+    option!(IpAddr, "Ser/de `Option<IpAddr>` to/from `Nullable(IPv6)`.");
+
+    pub fn serialize<S>(ip: &IpAddr, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let ipv6 = match ip {
+            IpAddr::V4(ipv4) => ipv4.to_ipv6_mapped(),
+            IpAddr::V6(ipv6) => *ipv6,
+        };
+
+        if serializer.is_human_readable() {
+            let literal = crate::sql::ser::typed_literal::IPV6;
+            serializer.serialize_newtype_struct(literal, &ipv6.to_string())
+        } else {
+            ipv6.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<IpAddr, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ipv6: Ipv6Addr = Deserialize::deserialize(deserializer)?;
+        Ok(ipv6.to_canonical())
+    }
+
+    /// Wraps the value to be used in [`crate::query::Query::bind`]
+    /// and [`crate::query::Query::param`] as `IPv6`.
+    pub fn wrap(value: IpAddr) -> impl Serialize {
+        struct Wrapped(IpAddr);
+
+        impl Serialize for Wrapped {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize(&self.0, serializer)
+            }
+        }
+
+        Wrapped(value)
+    }
+}
+
+/// Ser/de [`::ipnet::IpNet`] to/from `Tuple(IPv6, UInt8)`.
+///
+/// The network address is stored the same way as in [`ip`], i.e. IPv4 networks
+/// are mapped into IPv6, and their prefix lengths are increased by 96 accordingly,
+/// so that the stored pair describes the same range of `IPv6` addresses.
+///
+/// Tables often keep a network in two separate columns, the address and the
+/// prefix length. As a field corresponds to exactly one column, use two fields
+/// in this case and convert them with [`ipnet::split`] and [`ipnet::join`]:
+///
+/// ```
+/// use std::net::Ipv6Addr;
+/// use ipnet::IpNet;
+///
+/// #[derive(clickhouse::Row, serde::Serialize, serde::Deserialize)]
+/// struct MyRow {
+///     #[serde(with = "clickhouse::serde::ipnet")]
+///     subnet: IpNet, // Tuple(IPv6, UInt8)
+///     net_addr: Ipv6Addr, // IPv6
+///     net_prefix: u8,     // UInt8
+/// }
+///
+/// let subnet: IpNet = "10.1.0.0/16".parse().unwrap();
+/// let (net_addr, net_prefix) = clickhouse::serde::ipnet::split(&subnet);
+/// let row = MyRow { subnet, net_addr, net_prefix };
+///
+/// assert_eq!(clickhouse::serde::ipnet::join(row.net_addr, row.net_prefix), Ok(subnet));
+/// ```
+///
+/// Note that ClickHouse doesn't support `Nullable(Tuple(..))`,
+/// so there is no `option` submodule here.
+#[cfg(feature = "ipnet")]
+pub mod ipnet {
+    use std::net::Ipv6Addr;
+
+    use ::ipnet::{IpNet, Ipv4Net, Ipv6Net, PrefixLenError};
+    use serde::de::Error as _;
+
+    use super::*;
+
+    // The length of the `::ffff:0:0/96` prefix of IPv4-mapped addresses.
+    const MAPPED_PREFIX_LEN: u8 = 96;
+
+    /// Splits the network into an `IPv6` address and a prefix length.
+    pub fn split(net: &IpNet) -> (Ipv6Addr, u8) {
+        match net {
+            IpNet::V4(net) => (
+                net.addr().to_ipv6_mapped(),
+                net.prefix_len() + MAPPED_PREFIX_LEN,
+            ),
+            IpNet::V6(net) => (net.addr(), net.prefix_len()),
+        }
+    }
+
+    /// Joins an `IPv6` address and a prefix length produced by [`split`].
+    ///
+    /// Returns an error if the prefix length is greater than 128.
+    pub fn join(addr: Ipv6Addr, prefix_len: u8) -> Result<IpNet, PrefixLenError> {
+        match addr.to_ipv4_mapped() {
+            Some(ipv4) if prefix_len >= MAPPED_PREFIX_LEN => {
+                Ipv4Net::new(ipv4, prefix_len - MAPPED_PREFIX_LEN).map(IpNet::V4)
+            }
+            _ => Ipv6Net::new(addr, prefix_len).map(IpNet::V6),
+        }
+    }
+
+    pub fn serialize<S>(net: &IpNet, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (addr, prefix_len) = split(net);
+        (super::ip::wrap(addr.into()), prefix_len).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<IpNet, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (addr, prefix_len): (Ipv6Addr, u8) = Deserialize::deserialize(deserializer)?;
+        join(addr, prefix_len).map_err(D::Error::custom)
+    }
+}
+
+/// Ser/de `Arc<str>` to/from `String` and `LowCardinality(String)`.
+///
+/// If the cursor is created with [`crate::query::RowCursor::with_string_interner`],
+/// equal values are decoded into the same shared `Arc<str>` from a per-cursor cache,
+/// which avoids allocations for columns having few distinct values.
+/// Otherwise, every value is allocated separately.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// #[derive(clickhouse::Row, serde::Deserialize)]
+/// struct MyRow {
+///     #[serde(with = "clickhouse::serde::interned")]
+///     country: Arc<str>, // LowCardinality(String)
+/// }
+/// ```
+pub mod interned {
+    use std::{fmt, sync::Arc};
+
+    use serde::de::Visitor;
+
+    use super::*;
+
+    /// Ser/de `Option<Arc<str>>` to/from `Nullable(String)`
+    /// and `LowCardinality(Nullable(String))`.
+    pub mod option {
+        use super::*;
+
+        struct Interned(Arc<str>);
+
+        impl<'de> Deserialize<'de> for Interned {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                super::deserialize(deserializer).map(Interned)
+            }
+        }
+
+        pub fn serialize<S>(value: &Option<Arc<str>>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            value.as_deref().serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Arc<str>>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let value: Option<Interned> = Deserialize::deserialize(deserializer)?;
+            Ok(value.map(|v| v.0))
+        }
+    }
+
+    pub fn serialize<S>(value: &Arc<str>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Arc<str>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct InternedVisitor;
+
+        impl Visitor<'_> for InternedVisitor {
+            type Value = Arc<str>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Arc<str>, E> {
+                Ok(crate::cursors::intern(value))
+            }
+        }
+
+        deserializer.deserialize_str(InternedVisitor)
+    }
 }
 
 /// Ser/de [`::uuid::Uuid`] to/from `UUID`.
@@ -106,6 +360,81 @@ pub mod uuid {
     }
 }
 
+#[cfg(feature = "ethnum")]
+macro_rules! int256_impl {
+    ($ty:ident, $literal:path) => {
+        use serde::de::Error as _;
+
+        pub fn serialize<S>(value: &$ty, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if serializer.is_human_readable() {
+                serializer.serialize_newtype_struct($literal, &value.to_string())
+            } else {
+                value.to_le_bytes().serialize(serializer)
+            }
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<$ty, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                let text: &str = Deserialize::deserialize(deserializer)?;
+                text.parse().map_err(D::Error::custom)
+            } else {
+                let bytes: [u8; 32] = Deserialize::deserialize(deserializer)?;
+                Ok($ty::from_le_bytes(bytes))
+            }
+        }
+
+        /// Wraps the value to be used in [`crate::query::Query::bind`]
+        /// and [`crate::query::Query::param`].
+        pub fn wrap(value: $ty) -> impl Serialize {
+            struct Wrapped($ty);
+
+            impl Serialize for Wrapped {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize(&self.0, serializer)
+                }
+            }
+
+            Wrapped(value)
+        }
+    };
+}
+
+/// Ser/de [`::ethnum::I256`] to/from `Int256`.
+///
+/// Also supports binding values in [`crate::query::Query::bind`]
+/// and [`crate::query::Query::param`] via [`int256::wrap`].
+#[cfg(feature = "ethnum")]
+pub mod int256 {
+    use ::ethnum::I256;
+
+    use super::*;
+
+    option!(I256, "Ser/de `Option<I256>` to/from `Nullable(Int256)`.");
+
+    int256_impl!(I256, crate::sql::ser::typed_literal::INT256);
+}
+
+/// Ser/de [`::ethnum::U256`] to/from `UInt256`.
+///
+/// Also supports binding values in [`crate::query::Query::bind`]
+/// and [`crate::query::Query::param`] via [`uint256::wrap`].
+#[cfg(feature = "ethnum")]
+pub mod uint256 {
+    use ::ethnum::U256;
+
+    use super::*;
+
+    option!(U256, "Ser/de `Option<U256>` to/from `Nullable(UInt256)`.");
+
+    int256_impl!(U256, crate::sql::ser::typed_literal::UINT256);
+}
+
 #[cfg(feature = "chrono")]
 pub mod chrono {
     use super::*;
@@ -262,6 +591,176 @@ pub mod chrono {
         }
     }
 
+    /// Ser/de `DateTime<chrono_tz::Tz>` to/from `DateTime`.
+    ///
+    /// When deserializing, the time zone of the column (e.g. `DateTime('Asia/Tokyo')`) is used
+    /// if validation is enabled (default). Otherwise, or if the column has no explicit
+    /// time zone, values are in UTC. When serializing, values are converted to UTC.
+    #[cfg(feature = "chrono-tz")]
+    pub mod datetime_tz {
+        use super::*;
+        use crate::rowbinary::timezone;
+        use chrono_tz::Tz;
+
+        type DateTimeTz = DateTime<Tz>;
+
+        option!(
+            DateTimeTz,
+            "Ser/de `Option<DateTime<chrono_tz::Tz>>` to/from `Nullable(DateTime)`."
+        );
+
+        pub fn serialize<S>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            datetime::serialize(&dt.with_timezone(&Utc), serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Tz>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let (ts, tz) = timezone::deserialize(deserializer, timezone::DATETIME)?;
+            from_ticks(ts, 1, tz)
+        }
+
+        pub(super) fn from_ticks<E: serde::de::Error>(
+            ticks: i64,
+            ticks_per_second: i64,
+            tz: Option<String>,
+        ) -> Result<DateTime<Tz>, E> {
+            let tz = match tz {
+                Some(name) => name.parse::<Tz>().map_err(E::custom)?,
+                None => Tz::UTC,
+            };
+
+            let secs = ticks.div_euclid(ticks_per_second);
+            let nanos = ticks.rem_euclid(ticks_per_second) * (1_000_000_000 / ticks_per_second);
+            DateTime::<Utc>::from_timestamp(secs, nanos as u32)
+                .map(|dt| dt.with_timezone(&tz))
+                .ok_or_else(|| E::custom(format!("Can't create DateTime<Tz> from {ticks}")))
+        }
+    }
+
+    /// Contains modules to ser/de `DateTime<chrono_tz::Tz>` to/from `DateTime64(_)`.
+    ///
+    /// Time zones are handled the same way as in [`datetime_tz`].
+    #[cfg(feature = "chrono-tz")]
+    pub mod datetime64_tz {
+        use super::{datetime_tz::from_ticks, *};
+        use crate::rowbinary::timezone;
+        use chrono_tz::Tz;
+
+        type DateTimeTz = DateTime<Tz>;
+
+        /// Ser/de `DateTime<chrono_tz::Tz>` to/from `DateTime64(0)` (seconds).
+        pub mod secs {
+            use super::*;
+
+            option!(
+                DateTimeTz,
+                "Ser/de `Option<DateTime<chrono_tz::Tz>>` to/from `Nullable(DateTime64(0))`."
+            );
+
+            pub fn serialize<S>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                datetime64::secs::serialize(&dt.with_timezone(&Utc), serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Tz>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1)
+            }
+        }
+
+        /// Ser/de `DateTime<chrono_tz::Tz>` to/from `DateTime64(3)` (milliseconds).
+        pub mod millis {
+            use super::*;
+
+            option!(
+                DateTimeTz,
+                "Ser/de `Option<DateTime<chrono_tz::Tz>>` to/from `Nullable(DateTime64(3))`."
+            );
+
+            pub fn serialize<S>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                datetime64::millis::serialize(&dt.with_timezone(&Utc), serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Tz>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000)
+            }
+        }
+
+        /// Ser/de `DateTime<chrono_tz::Tz>` to/from `DateTime64(6)` (microseconds).
+        pub mod micros {
+            use super::*;
+
+            option!(
+                DateTimeTz,
+                "Ser/de `Option<DateTime<chrono_tz::Tz>>` to/from `Nullable(DateTime64(6))`."
+            );
+
+            pub fn serialize<S>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                datetime64::micros::serialize(&dt.with_timezone(&Utc), serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Tz>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000_000)
+            }
+        }
+
+        /// Ser/de `DateTime<chrono_tz::Tz>` to/from `DateTime64(9)` (nanoseconds).
+        pub mod nanos {
+            use super::*;
+
+            option!(
+                DateTimeTz,
+                "Ser/de `Option<DateTime<chrono_tz::Tz>>` to/from `Nullable(DateTime64(9))`."
+            );
+
+            pub fn serialize<S>(dt: &DateTime<Tz>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                datetime64::nanos::serialize(&dt.with_timezone(&Utc), serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Tz>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000_000_000)
+            }
+        }
+
+        fn do_deserialize<'de, D>(
+            deserializer: D,
+            ticks_per_second: i64,
+        ) -> Result<DateTime<Tz>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let (ticks, tz) = timezone::deserialize(deserializer, timezone::DATETIME64)?;
+            from_ticks(ticks, ticks_per_second, tz)
+        }
+    }
+
     /// Ser/de `time::Date` to/from `Date`.
     pub mod date {
         use super::*;
@@ -489,54 +988,243 @@ pub mod chrono {
             }
         }
     }
-}
 
-/// Ser/de [`::time::OffsetDateTime`] and [`::time::Date`].
-#[cfg(feature = "time")]
-pub mod time {
-    use std::convert::TryFrom;
-
-    use ::time::{error::ComponentRange, Date, Duration, OffsetDateTime};
-    use serde::{de::Error as _, ser::Error as _};
-
-    use super::*;
-
-    /// Ser/de `OffsetDateTime` to/from `DateTime`.
-    pub mod datetime {
+    /// Ser/de `chrono::NaiveTime` (a wall-clock time) to/from `Time`,
+    /// and to/from `Time64(_)` using the submodules.
+    ///
+    /// Unlike [`time`] and [`time64`] working with durations,
+    /// only values in the `[00:00:00, 24:00:00)` range can be deserialized.
+    pub mod time_of_day {
         use super::*;
+        use ::chrono::{NaiveTime, Timelike};
 
         option!(
-            OffsetDateTime,
-            "Ser/de `Option<OffsetDateTime>` to/from `Nullable(DateTime)`."
+            NaiveTime,
+            "Ser/de `Option<NaiveTime>` to/from `Nullable(Time)`."
         );
 
-        pub fn serialize<S>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
         where
             S: Serializer,
         {
-            let ts = dt.unix_timestamp();
-
-            u32::try_from(ts)
-                .map_err(|_| S::Error::custom(format!("{dt} cannot be represented as DateTime")))?
-                .serialize(serializer)
+            let seconds = to_ticks(time, 1) as i32; // cannot overflow: less than a day
+            seconds.serialize(serializer)
         }
 
-        pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
         where
             D: Deserializer<'de>,
         {
-            let ts: u32 = Deserialize::deserialize(deserializer)?;
-            OffsetDateTime::from_unix_timestamp(i64::from(ts)).map_err(D::Error::custom)
+            let seconds: i32 = Deserialize::deserialize(deserializer)?;
+            from_ticks(i64::from(seconds), 1)
         }
-    }
 
-    /// Contains modules to ser/de `OffsetDateTime` to/from `DateTime64(_)`.
-    pub mod datetime64 {
-        use super::*;
+        /// Returns the number of ticks since midnight, truncating extra precision.
+        fn to_ticks(time: &NaiveTime, ticks_per_second: i64) -> i64 {
+            let seconds = i64::from(time.num_seconds_from_midnight());
+            // Leap seconds, e.g. `23:59:60.5`, are represented by nanoseconds exceeding
+            // a second and clamped to the previous second, e.g. `23:59:59.5`, because
+            // `24:00:00` is out of range and cannot be read back.
+            let nanos = i64::from(time.nanosecond() % 1_000_000_000);
+            seconds * ticks_per_second + nanos / (1_000_000_000 / ticks_per_second)
+        }
 
-        /// Ser/de `OffsetDateTime` to/from `DateTime64(0)`.
-        pub mod secs {
-            use super::*;
+        fn from_ticks<E: serde::de::Error>(
+            ticks: i64,
+            ticks_per_second: i64,
+        ) -> Result<NaiveTime, E> {
+            let seconds = ticks.div_euclid(ticks_per_second);
+            let nanos = ticks.rem_euclid(ticks_per_second) * (1_000_000_000 / ticks_per_second);
+            u32::try_from(seconds)
+                .ok()
+                .filter(|seconds| *seconds < 86_400)
+                .and_then(|seconds| {
+                    NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanos as u32)
+                })
+                .ok_or_else(|| E::custom(format!("{ticks} is out of range for NaiveTime")))
+        }
+
+        /// Ser/de `NaiveTime` to/from `Time64(0)` (seconds).
+        pub mod secs {
+            use super::*;
+
+            option!(
+                NaiveTime,
+                "Ser/de `Option<NaiveTime>` to/from `Nullable(Time64(0))`."
+            );
+
+            pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1)
+            }
+        }
+
+        /// Ser/de `NaiveTime` to/from `Time64(3)` (milliseconds).
+        pub mod millis {
+            use super::*;
+
+            option!(
+                NaiveTime,
+                "Ser/de `Option<NaiveTime>` to/from `Nullable(Time64(3))`."
+            );
+
+            pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000)
+            }
+        }
+
+        /// Ser/de `NaiveTime` to/from `Time64(6)` (microseconds).
+        pub mod micros {
+            use super::*;
+
+            option!(
+                NaiveTime,
+                "Ser/de `Option<NaiveTime>` to/from `Nullable(Time64(6))`."
+            );
+
+            pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000_000)
+            }
+        }
+
+        /// Ser/de `NaiveTime` to/from `Time64(9)` (nanoseconds).
+        pub mod nanos {
+            use super::*;
+
+            option!(
+                NaiveTime,
+                "Ser/de `Option<NaiveTime>` to/from `Nullable(Time64(9))`."
+            );
+
+            pub fn serialize<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000_000_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000_000_000)
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use serde::de::value::Error;
+
+            #[test]
+            fn it_converts_ticks() {
+                let time = NaiveTime::from_hms_nano_opt(1, 2, 3, 456_789_012).unwrap();
+                assert_eq!(to_ticks(&time, 1), 3723);
+                assert_eq!(to_ticks(&time, 1_000), 3_723_456);
+                assert_eq!(to_ticks(&time, 1_000_000_000), 3_723_456_789_012);
+
+                let parsed: NaiveTime = from_ticks::<Error>(3_723_456, 1_000).unwrap();
+                assert_eq!(parsed, NaiveTime::from_hms_milli_opt(1, 2, 3, 456).unwrap());
+                assert!(from_ticks::<Error>(-1, 1).is_err());
+                assert!(from_ticks::<Error>(86_400_000, 1_000).is_err());
+            }
+
+            #[test]
+            fn it_clamps_leap_seconds() {
+                let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+                assert_eq!(to_ticks(&leap, 1), 86_399);
+                assert_eq!(to_ticks(&leap, 1_000), 86_399_500);
+
+                let ticks = to_ticks(&leap, 1_000_000);
+                let parsed: NaiveTime = from_ticks::<Error>(ticks, 1_000_000).unwrap();
+                assert_eq!(
+                    parsed,
+                    NaiveTime::from_hms_milli_opt(23, 59, 59, 500).unwrap()
+                );
+            }
+        }
+    }
+}
+
+/// Ser/de [`::time::OffsetDateTime`] and [`::time::Date`].
+#[cfg(feature = "time")]
+pub mod time {
+    use std::convert::TryFrom;
+
+    use ::time::{error::ComponentRange, Date, Duration, OffsetDateTime};
+    use serde::{de::Error as _, ser::Error as _};
+
+    use super::*;
+
+    /// Ser/de `OffsetDateTime` to/from `DateTime`.
+    pub mod datetime {
+        use super::*;
+
+        option!(
+            OffsetDateTime,
+            "Ser/de `Option<OffsetDateTime>` to/from `Nullable(DateTime)`."
+        );
+
+        pub fn serialize<S>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let ts = dt.unix_timestamp();
+
+            u32::try_from(ts)
+                .map_err(|_| S::Error::custom(format!("{dt} cannot be represented as DateTime")))?
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let ts: u32 = Deserialize::deserialize(deserializer)?;
+            OffsetDateTime::from_unix_timestamp(i64::from(ts)).map_err(D::Error::custom)
+        }
+    }
+
+    /// Contains modules to ser/de `OffsetDateTime` to/from `DateTime64(_)`.
+    pub mod datetime64 {
+        use super::*;
+
+        /// Ser/de `OffsetDateTime` to/from `DateTime64(0)`.
+        pub mod secs {
+            use super::*;
 
             option!(
                 OffsetDateTime,
@@ -651,6 +1339,168 @@ pub mod time {
         }
     }
 
+    /// Ser/de `OffsetDateTime` to/from `DateTime`, keeping the offset of the column time zone.
+    ///
+    /// When deserializing, the time zone of the column (e.g. `DateTime('Asia/Tokyo')`) is used
+    /// to get the offset of values if validation is enabled (default). Otherwise, or if the column
+    /// has no explicit time zone, values are in UTC. When serializing, values are converted to UTC.
+    #[cfg(feature = "time-tz")]
+    pub mod datetime_tz {
+        use super::*;
+        use crate::rowbinary::timezone;
+        use time_tz::OffsetDateTimeExt;
+
+        option!(
+            OffsetDateTime,
+            "Ser/de `Option<OffsetDateTime>` to/from `Nullable(DateTime)`."
+        );
+
+        pub fn serialize<S>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            datetime::serialize(dt, serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let (ts, tz) = timezone::deserialize(deserializer, timezone::DATETIME)?;
+            let dt = OffsetDateTime::from_unix_timestamp(ts).map_err(D::Error::custom)?;
+            with_timezone(dt, tz)
+        }
+
+        pub(super) fn with_timezone<E: serde::de::Error>(
+            dt: OffsetDateTime,
+            tz: Option<String>,
+        ) -> Result<OffsetDateTime, E> {
+            let Some(name) = tz else {
+                return Ok(dt);
+            };
+
+            let tz = time_tz::timezones::get_by_name(&name)
+                .ok_or_else(|| E::custom(format!("unknown time zone {name}")))?;
+            Ok(dt.to_timezone(tz))
+        }
+    }
+
+    /// Contains modules to ser/de `OffsetDateTime` to/from `DateTime64(_)`,
+    /// keeping the offset of the column time zone.
+    ///
+    /// Time zones are handled the same way as in [`datetime_tz`].
+    #[cfg(feature = "time-tz")]
+    pub mod datetime64_tz {
+        use super::{datetime_tz::with_timezone, *};
+        use crate::rowbinary::timezone;
+
+        /// Ser/de `OffsetDateTime` to/from `DateTime64(0)`.
+        pub mod secs {
+            use super::*;
+
+            option!(
+                OffsetDateTime,
+                "Ser/de `Option<OffsetDateTime>` to/from `Nullable(DateTime64(0))`."
+            );
+
+            pub fn serialize<S>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                datetime64::secs::serialize(dt, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000_000_000)
+            }
+        }
+
+        /// Ser/de `OffsetDateTime` to/from `DateTime64(3)`.
+        pub mod millis {
+            use super::*;
+
+            option!(
+                OffsetDateTime,
+                "Ser/de `Option<OffsetDateTime>` to/from `Nullable(DateTime64(3))`."
+            );
+
+            pub fn serialize<S>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                datetime64::millis::serialize(dt, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000_000)
+            }
+        }
+
+        /// Ser/de `OffsetDateTime` to/from `DateTime64(6)`.
+        pub mod micros {
+            use super::*;
+
+            option!(
+                OffsetDateTime,
+                "Ser/de `Option<OffsetDateTime>` to/from `Nullable(DateTime64(6))`."
+            );
+
+            pub fn serialize<S>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                datetime64::micros::serialize(dt, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000)
+            }
+        }
+
+        /// Ser/de `OffsetDateTime` to/from `DateTime64(9)`.
+        pub mod nanos {
+            use super::*;
+
+            option!(
+                OffsetDateTime,
+                "Ser/de `Option<OffsetDateTime>` to/from `Nullable(DateTime64(9))`."
+            );
+
+            pub fn serialize<S>(dt: &OffsetDateTime, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                datetime64::nanos::serialize(dt, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1)
+            }
+        }
+
+        fn do_deserialize<'de, D>(deserializer: D, mul: i128) -> Result<OffsetDateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let (ts, tz) = timezone::deserialize(deserializer, timezone::DATETIME64)?;
+            let ts = i128::from(ts) * mul; // cannot overflow: `mul` fits in `i64`
+            let dt = OffsetDateTime::from_unix_timestamp_nanos(ts).map_err(D::Error::custom)?;
+            with_timezone(dt, tz)
+        }
+    }
+
     /// Ser/de `time::Date` to/from `Date`.
     pub mod date {
         use super::*;
@@ -885,4 +1735,1670 @@ pub mod time {
             }
         }
     }
+
+    /// Ser/de `time::Time` (a wall-clock time) to/from `Time`,
+    /// and to/from `Time64(_)` using the submodules.
+    ///
+    /// Unlike [`time`] and [`time64`] working with durations,
+    /// only values in the `[00:00:00, 24:00:00)` range can be deserialized.
+    pub mod time_of_day {
+        use super::*;
+        use ::time::Time;
+
+        option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time)`.");
+
+        pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let seconds = to_ticks(time, 1) as i32; // cannot overflow: less than a day
+            seconds.serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let seconds: i32 = Deserialize::deserialize(deserializer)?;
+            from_ticks(i64::from(seconds), 1)
+        }
+
+        /// Returns the number of ticks since midnight, truncating extra precision.
+        fn to_ticks(time: &Time, ticks_per_second: i64) -> i64 {
+            let (hours, minutes, seconds, nanos) = time.as_hms_nano();
+            let seconds = i64::from(hours) * 3600 + i64::from(minutes) * 60 + i64::from(seconds);
+            seconds * ticks_per_second + i64::from(nanos) / (1_000_000_000 / ticks_per_second)
+        }
+
+        fn from_ticks<E: serde::de::Error>(ticks: i64, ticks_per_second: i64) -> Result<Time, E> {
+            let seconds = ticks.div_euclid(ticks_per_second);
+            let nanos = ticks.rem_euclid(ticks_per_second) * (1_000_000_000 / ticks_per_second);
+            if !(0..86_400).contains(&seconds) {
+                return Err(E::custom(format!("{ticks} is out of range for time::Time")));
+            }
+            // cannot fail: all components are checked above
+            Time::from_hms_nano(
+                (seconds / 3600) as u8,
+                (seconds / 60 % 60) as u8,
+                (seconds % 60) as u8,
+                nanos as u32,
+            )
+            .map_err(E::custom)
+        }
+
+        /// Ser/de `Time` to/from `Time64(0)` (seconds).
+        pub mod secs {
+            use super::*;
+
+            option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time64(0))`.");
+
+            pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1)
+            }
+        }
+
+        /// Ser/de `Time` to/from `Time64(3)` (milliseconds).
+        pub mod millis {
+            use super::*;
+
+            option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time64(3))`.");
+
+            pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000)
+            }
+        }
+
+        /// Ser/de `Time` to/from `Time64(6)` (microseconds).
+        pub mod micros {
+            use super::*;
+
+            option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time64(6))`.");
+
+            pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000_000)
+            }
+        }
+
+        /// Ser/de `Time` to/from `Time64(9)` (nanoseconds).
+        pub mod nanos {
+            use super::*;
+
+            option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time64(9))`.");
+
+            pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000_000_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000_000_000)
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use serde::de::value::Error;
+
+            #[test]
+            fn it_converts_ticks() {
+                let time = Time::from_hms_nano(1, 2, 3, 456_789_012).unwrap();
+                assert_eq!(to_ticks(&time, 1), 3723);
+                assert_eq!(to_ticks(&time, 1_000), 3_723_456);
+                assert_eq!(to_ticks(&time, 1_000_000_000), 3_723_456_789_012);
+
+                let parsed: Time = from_ticks::<Error>(3_723_456, 1_000).unwrap();
+                assert_eq!(parsed, Time::from_hms_milli(1, 2, 3, 456).unwrap());
+                assert!(from_ticks::<Error>(-1, 1).is_err());
+                assert!(from_ticks::<Error>(86_400_000, 1_000).is_err());
+            }
+        }
+    }
+}
+
+/// Ser/de [`::jiff::Timestamp`], [`::jiff::Zoned`], [`::jiff::civil::Date`],
+/// [`::jiff::civil::Time`] and [`::jiff::SignedDuration`].
+#[cfg(feature = "jiff")]
+pub mod jiff {
+    use std::convert::TryFrom;
+
+    use ::jiff::{civil::Date, tz::TimeZone, SignedDuration, Timestamp, Zoned};
+    use serde::{de::Error as _, ser::Error as _};
+
+    use super::*;
+    use crate::rowbinary::timezone;
+
+    /// Ser/de `Timestamp` to/from `DateTime`.
+    pub mod datetime {
+        use super::*;
+
+        option!(
+            Timestamp,
+            "Ser/de `Option<Timestamp>` to/from `Nullable(DateTime)`."
+        );
+
+        pub fn serialize<S>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            u32::try_from(ts.as_second())
+                .map_err(|_| S::Error::custom(format!("{ts} cannot be represented as DateTime")))?
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let ts: u32 = Deserialize::deserialize(deserializer)?;
+            Timestamp::from_second(i64::from(ts)).map_err(D::Error::custom)
+        }
+    }
+
+    /// Contains modules to ser/de `Timestamp` to/from `DateTime64(_)`.
+    pub mod datetime64 {
+        use super::*;
+
+        /// Ser/de `Timestamp` to/from `DateTime64(0)`.
+        pub mod secs {
+            use super::*;
+
+            option!(
+                Timestamp,
+                "Ser/de `Option<Timestamp>` to/from `Nullable(DateTime64(0))`."
+            );
+
+            pub fn serialize<S>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(ts, 1_000_000_000, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000_000_000)
+            }
+        }
+
+        /// Ser/de `Timestamp` to/from `DateTime64(3)`.
+        pub mod millis {
+            use super::*;
+
+            option!(
+                Timestamp,
+                "Ser/de `Option<Timestamp>` to/from `Nullable(DateTime64(3))`."
+            );
+
+            pub fn serialize<S>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(ts, 1_000_000, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000_000)
+            }
+        }
+
+        /// Ser/de `Timestamp` to/from `DateTime64(6)`.
+        pub mod micros {
+            use super::*;
+
+            option!(
+                Timestamp,
+                "Ser/de `Option<Timestamp>` to/from `Nullable(DateTime64(6))`."
+            );
+
+            pub fn serialize<S>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(ts, 1_000, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000)
+            }
+        }
+
+        /// Ser/de `Timestamp` to/from `DateTime64(9)`.
+        pub mod nanos {
+            use super::*;
+
+            option!(
+                Timestamp,
+                "Ser/de `Option<Timestamp>` to/from `Nullable(DateTime64(9))`."
+            );
+
+            pub fn serialize<S>(ts: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(ts, 1, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1)
+            }
+        }
+
+        pub(super) fn do_serialize<S>(
+            ts: &Timestamp,
+            div: i128,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let ticks = ts.as_nanosecond() / div;
+
+            i64::try_from(ticks)
+                .map_err(|_| S::Error::custom(format!("{ts} cannot be represented as DateTime64")))?
+                .serialize(serializer)
+        }
+
+        pub(super) fn from_ticks<E: serde::de::Error>(
+            ticks: i64,
+            mul: i128,
+        ) -> Result<Timestamp, E> {
+            let nanos = i128::from(ticks) * mul; // cannot overflow: `mul` fits in `i64`
+            Timestamp::from_nanosecond(nanos).map_err(E::custom)
+        }
+    }
+
+    /// Ser/de `Zoned` to/from `DateTime`.
+    ///
+    /// When deserializing, the time zone of the column (e.g. `DateTime('Asia/Tokyo')`) is used
+    /// if validation is enabled (default). Otherwise, or if the column has no explicit
+    /// time zone, values are in UTC. When serializing, only the instant is written,
+    /// i.e. the time zone of the value is ignored.
+    pub mod zoned {
+        use super::*;
+
+        option!(
+            Zoned,
+            "Ser/de `Option<Zoned>` to/from `Nullable(DateTime)`."
+        );
+
+        pub fn serialize<S>(zoned: &Zoned, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            datetime::serialize(&zoned.timestamp(), serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Zoned, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let (ts, tz) = timezone::deserialize(deserializer, timezone::DATETIME)?;
+            let ts = Timestamp::from_second(ts).map_err(D::Error::custom)?;
+            with_timezone(ts, tz)
+        }
+
+        pub(super) fn with_timezone<E: serde::de::Error>(
+            ts: Timestamp,
+            tz: Option<String>,
+        ) -> Result<Zoned, E> {
+            let tz = match tz {
+                Some(name) => TimeZone::get(&name).map_err(E::custom)?,
+                None => TimeZone::UTC,
+            };
+            Ok(Zoned::new(ts, tz))
+        }
+    }
+
+    /// Contains modules to ser/de `Zoned` to/from `DateTime64(_)`.
+    ///
+    /// Time zones are handled the same way as in [`zoned`].
+    pub mod zoned64 {
+        use super::{datetime64::do_serialize, *};
+
+        /// Ser/de `Zoned` to/from `DateTime64(0)`.
+        pub mod secs {
+            use super::*;
+
+            option!(
+                Zoned,
+                "Ser/de `Option<Zoned>` to/from `Nullable(DateTime64(0))`."
+            );
+
+            pub fn serialize<S>(zoned: &Zoned, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(&zoned.timestamp(), 1_000_000_000, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Zoned, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000_000_000)
+            }
+        }
+
+        /// Ser/de `Zoned` to/from `DateTime64(3)`.
+        pub mod millis {
+            use super::*;
+
+            option!(
+                Zoned,
+                "Ser/de `Option<Zoned>` to/from `Nullable(DateTime64(3))`."
+            );
+
+            pub fn serialize<S>(zoned: &Zoned, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(&zoned.timestamp(), 1_000_000, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Zoned, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000_000)
+            }
+        }
+
+        /// Ser/de `Zoned` to/from `DateTime64(6)`.
+        pub mod micros {
+            use super::*;
+
+            option!(
+                Zoned,
+                "Ser/de `Option<Zoned>` to/from `Nullable(DateTime64(6))`."
+            );
+
+            pub fn serialize<S>(zoned: &Zoned, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(&zoned.timestamp(), 1_000, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Zoned, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1_000)
+            }
+        }
+
+        /// Ser/de `Zoned` to/from `DateTime64(9)`.
+        pub mod nanos {
+            use super::*;
+
+            option!(
+                Zoned,
+                "Ser/de `Option<Zoned>` to/from `Nullable(DateTime64(9))`."
+            );
+
+            pub fn serialize<S>(zoned: &Zoned, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(&zoned.timestamp(), 1, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Zoned, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                do_deserialize(deserializer, 1)
+            }
+        }
+
+        fn do_deserialize<'de, D>(deserializer: D, mul: i128) -> Result<Zoned, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let (ticks, tz) = timezone::deserialize(deserializer, timezone::DATETIME64)?;
+            let ts = datetime64::from_ticks(ticks, mul)?;
+            zoned::with_timezone(ts, tz)
+        }
+    }
+
+    /// Ser/de `jiff::civil::Date` to/from `Date`.
+    pub mod date {
+        use super::*;
+
+        option!(
+            Date,
+            "Ser/de `Option<jiff::civil::Date>` to/from `Nullable(Date)`."
+        );
+
+        const ORIGIN: Date = ::jiff::civil::date(1970, 1, 1);
+
+        pub fn serialize<S>(date: &Date, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let days = date.duration_since(ORIGIN).as_hours() / 24;
+
+            u16::try_from(days)
+                .map_err(|_| S::Error::custom(format!("{date} cannot be represented as Date")))?
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let days: u16 = Deserialize::deserialize(deserializer)?;
+            Ok(ORIGIN + SignedDuration::from_hours(i64::from(days) * 24)) // cannot overflow: always < `Date::MAX`
+        }
+    }
+
+    /// Ser/de `jiff::civil::Date` to/from `Date32`.
+    pub mod date32 {
+        use super::*;
+
+        option!(
+            Date,
+            "Ser/de `Option<jiff::civil::Date>` to/from `Nullable(Date32)`."
+        );
+
+        const ORIGIN: Date = ::jiff::civil::date(1970, 1, 1);
+
+        // NOTE: actually, it's 1925 and 2283 with a tail for versions before 22.8-lts.
+        const MIN: Date = ::jiff::civil::date(1900, 1, 1);
+        const MAX: Date = ::jiff::civil::date(2299, 12, 31);
+
+        pub fn serialize<S>(date: &Date, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if *date < MIN || *date > MAX {
+                let msg = format!("{date} cannot be represented as Date32");
+                return Err(S::Error::custom(msg));
+            }
+
+            let days = date.duration_since(ORIGIN).as_hours() / 24;
+
+            i32::try_from(days)
+                .map_err(|_| S::Error::custom(format!("{date} cannot be represented as Date32")))?
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let days: i32 = Deserialize::deserialize(deserializer)?;
+            ORIGIN
+                .checked_add(SignedDuration::from_hours(i64::from(days) * 24))
+                .map_err(D::Error::custom)
+        }
+    }
+
+    /// Ser/de `SignedDuration` to/from `Time`.
+    pub mod time {
+        use super::*;
+
+        option!(
+            SignedDuration,
+            "Ser/de `Option<SignedDuration>` to/from `Nullable(Time)`."
+        );
+
+        pub fn serialize<S>(duration: &SignedDuration, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            i32::try_from(duration.as_secs())
+                .map_err(|_| S::Error::custom(format!("{duration} cannot be represented as Time")))?
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<SignedDuration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let seconds: i32 = Deserialize::deserialize(deserializer)?;
+            Ok(SignedDuration::from_secs(i64::from(seconds)))
+        }
+    }
+
+    /// Contains modules to ser/de `SignedDuration` to/from `Time64(_)`.
+    pub mod time64 {
+        use super::*;
+
+        /// Ser/de `SignedDuration` to/from `Time64(0)` (seconds).
+        pub mod secs {
+            use super::*;
+
+            option!(
+                SignedDuration,
+                "Ser/de `Option<SignedDuration>` to/from `Nullable(Time64(0))`."
+            );
+
+            pub fn serialize<S>(duration: &SignedDuration, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                duration.as_secs().serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<SignedDuration, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let seconds: i64 = Deserialize::deserialize(deserializer)?;
+                Ok(SignedDuration::from_secs(seconds))
+            }
+        }
+
+        /// Ser/de `SignedDuration` to/from `Time64(3)` (milliseconds).
+        pub mod millis {
+            use super::*;
+
+            option!(
+                SignedDuration,
+                "Ser/de `Option<SignedDuration>` to/from `Nullable(Time64(3))`."
+            );
+
+            pub fn serialize<S>(duration: &SignedDuration, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(duration.as_millis(), duration, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<SignedDuration, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let millis: i64 = Deserialize::deserialize(deserializer)?;
+                Ok(SignedDuration::from_millis(millis))
+            }
+        }
+
+        /// Ser/de `SignedDuration` to/from `Time64(6)` (microseconds).
+        pub mod micros {
+            use super::*;
+
+            option!(
+                SignedDuration,
+                "Ser/de `Option<SignedDuration>` to/from `Nullable(Time64(6))`."
+            );
+
+            pub fn serialize<S>(duration: &SignedDuration, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(duration.as_micros(), duration, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<SignedDuration, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let micros: i64 = Deserialize::deserialize(deserializer)?;
+                Ok(SignedDuration::from_micros(micros))
+            }
+        }
+
+        /// Ser/de `SignedDuration` to/from `Time64(9)` (nanoseconds).
+        pub mod nanos {
+            use super::*;
+
+            option!(
+                SignedDuration,
+                "Ser/de `Option<SignedDuration>` to/from `Nullable(Time64(9))`."
+            );
+
+            pub fn serialize<S>(duration: &SignedDuration, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                do_serialize(duration.as_nanos(), duration, serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<SignedDuration, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let nanos: i64 = Deserialize::deserialize(deserializer)?;
+                Ok(SignedDuration::from_nanos(nanos))
+            }
+        }
+
+        fn do_serialize<S>(
+            ticks: i128,
+            duration: &SignedDuration,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            i64::try_from(ticks)
+                .map_err(|_| {
+                    S::Error::custom(format!("{duration} cannot be represented as Time64"))
+                })?
+                .serialize(serializer)
+        }
+    }
+
+    /// Ser/de `jiff::civil::Time` (a wall-clock time) to/from `Time`,
+    /// and to/from `Time64(_)` using the submodules.
+    ///
+    /// Unlike [`time`] and [`time64`] working with durations,
+    /// only values in the `[00:00:00, 24:00:00)` range can be deserialized.
+    pub mod time_of_day {
+        use super::*;
+        use ::jiff::civil::Time;
+
+        option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time)`.");
+
+        pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let seconds = to_ticks(time, 1) as i32; // cannot overflow: less than a day
+            seconds.serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let seconds: i32 = Deserialize::deserialize(deserializer)?;
+            from_ticks(i64::from(seconds), 1)
+        }
+
+        /// Returns the number of ticks since midnight, truncating extra precision.
+        fn to_ticks(time: &Time, ticks_per_second: i64) -> i64 {
+            let nanos = time.duration_since(Time::midnight()).as_nanos() as i64; // less than a day
+            nanos / (1_000_000_000 / ticks_per_second)
+        }
+
+        fn from_ticks<E: serde::de::Error>(ticks: i64, ticks_per_second: i64) -> Result<Time, E> {
+            let seconds = ticks.div_euclid(ticks_per_second);
+            let nanos = ticks.rem_euclid(ticks_per_second) * (1_000_000_000 / ticks_per_second);
+            if !(0..86_400).contains(&seconds) {
+                return Err(E::custom(format!(
+                    "{ticks} is out of range for jiff::civil::Time"
+                )));
+            }
+            // cannot fail: all components are checked above
+            Time::new(
+                (seconds / 3600) as i8,
+                (seconds / 60 % 60) as i8,
+                (seconds % 60) as i8,
+                nanos as i32,
+            )
+            .map_err(E::custom)
+        }
+
+        /// Ser/de `Time` to/from `Time64(0)` (seconds).
+        pub mod secs {
+            use super::*;
+
+            option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time64(0))`.");
+
+            pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1)
+            }
+        }
+
+        /// Ser/de `Time` to/from `Time64(3)` (milliseconds).
+        pub mod millis {
+            use super::*;
+
+            option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time64(3))`.");
+
+            pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000)
+            }
+        }
+
+        /// Ser/de `Time` to/from `Time64(6)` (microseconds).
+        pub mod micros {
+            use super::*;
+
+            option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time64(6))`.");
+
+            pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000_000)
+            }
+        }
+
+        /// Ser/de `Time` to/from `Time64(9)` (nanoseconds).
+        pub mod nanos {
+            use super::*;
+
+            option!(Time, "Ser/de `Option<Time>` to/from `Nullable(Time64(9))`.");
+
+            pub fn serialize<S>(time: &Time, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                to_ticks(time, 1_000_000_000).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Time, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let ticks: i64 = Deserialize::deserialize(deserializer)?;
+                from_ticks(ticks, 1_000_000_000)
+            }
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+            use serde::de::value::Error;
+
+            #[test]
+            fn it_converts_ticks() {
+                let time = ::jiff::civil::time(1, 2, 3, 456_789_012);
+                assert_eq!(to_ticks(&time, 1), 3723);
+                assert_eq!(to_ticks(&time, 1_000), 3_723_456);
+                assert_eq!(to_ticks(&time, 1_000_000_000), 3_723_456_789_012);
+
+                let parsed: Time = from_ticks::<Error>(3_723_456, 1_000).unwrap();
+                assert_eq!(parsed, ::jiff::civil::time(1, 2, 3, 456_000_000));
+                assert!(from_ticks::<Error>(-1, 1).is_err());
+                assert!(from_ticks::<Error>(86_400_000, 1_000).is_err());
+            }
+        }
+    }
+}
+
+/// Ser/de decimals to/from `Decimal32(S)`, `Decimal64(S)`, `Decimal128(S)` and `Decimal256(S)`.
+///
+/// Supported Rust types are [`rust_decimal::Decimal`] (the `rust_decimal` feature) and
+/// [`bigdecimal::BigDecimal`] (the `bigdecimal` feature), as well as `Option`s of them
+/// for `Nullable(Decimal(P, S))` columns.
+///
+/// A decimal is stored by ClickHouse as an integer scaled by `10^S`, so the scale of the column
+/// has to be known. Hence, helpers of this module are types with the scale as a const generic
+/// parameter. Values with more fractional digits than the scale allows are truncated,
+/// the same way ClickHouse does when converting a string to a decimal.
+///
+/// ```
+/// # #[cfg(feature = "rust_decimal")]
+/// # {
+/// use rust_decimal::Decimal;
+///
+/// #[derive(clickhouse::Row, serde::Serialize, serde::Deserialize)]
+/// struct MyRow {
+///     #[serde(with = "clickhouse::serde::decimal::d64::<4>")]
+///     price: Decimal, // Decimal(18, 4)
+///     #[serde(with = "clickhouse::serde::decimal::d32::<2>")]
+///     discount: Option<Decimal>, // Nullable(Decimal(9, 2))
+/// }
+///
+/// // Decimals can be used as query arguments and server-side parameters as well:
+/// let price = Decimal::new(12345, 2);
+/// let query = clickhouse::Client::default()
+///     .query("SELECT ?fields FROM some WHERE price > ? AND price < {max:Decimal(18, 4)}")
+///     .bind(clickhouse::serde::decimal::d64::<4>::wrap(price))
+///     .param("max", clickhouse::serde::decimal::d64::<4>::wrap(price * Decimal::TWO));
+/// # }
+/// ```
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+pub mod decimal {
+    use std::marker::PhantomData;
+
+    use sealed::sealed;
+    use serde::{de::Error as _, ser::Error as _};
+
+    use super::*;
+    use crate::sql::ser::typed_literal;
+
+    /// A decimal type supported by the helpers in this module.
+    #[sealed]
+    pub trait DecimalValue: Sized {
+        #[doc(hidden)]
+        fn to_i128(&self, scale: u32) -> Option<i128>;
+        #[doc(hidden)]
+        fn from_i128(mantissa: i128, scale: u32) -> Option<Self>;
+        #[doc(hidden)]
+        fn to_i256(&self, scale: u32) -> Option<[u8; 32]>;
+        #[doc(hidden)]
+        fn from_i256(mantissa: [u8; 32], scale: u32) -> Option<Self>;
+        #[doc(hidden)]
+        fn to_scaled_string(&self, scale: u32) -> Option<String>;
+        #[doc(hidden)]
+        fn parse(s: &str) -> Option<Self>;
+    }
+
+    /// A field type that can be used with the helpers in this module:
+    /// either a [`DecimalValue`] or an `Option` of it.
+    #[sealed]
+    pub trait DecimalField<Kind>: Sized {
+        #[doc(hidden)]
+        fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+        #[doc(hidden)]
+        fn deserialize_field<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+    }
+
+    /// A wrapper returned by `wrap()` methods to bind decimals in queries.
+    pub struct Wrapped<Kind, T> {
+        value: T,
+        _kind: PhantomData<Kind>,
+    }
+
+    impl<Kind, T: DecimalField<Kind>> Serialize for Wrapped<Kind, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.value.serialize_field(serializer)
+        }
+    }
+
+    /// Used to ser/de `Option<T>` by delegating to `T`.
+    struct Inner<Kind, T>(T, PhantomData<Kind>);
+
+    impl<Kind, T: DecimalField<Kind>> Serialize for Inner<Kind, &'_ T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize_field(serializer)
+        }
+    }
+
+    impl<'de, Kind, T: DecimalField<Kind>> Deserialize<'de> for Inner<Kind, T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize_field(deserializer).map(|v| Inner(v, PhantomData))
+        }
+    }
+
+    macro_rules! decimal_kind {
+        ($name:ident, $max_scale:literal, $doc:literal, $literal:expr, $ser:ident, $de:ident) => {
+            #[doc = $doc]
+            ///
+            /// `SCALE` must be equal to the scale of the column.
+            #[allow(non_camel_case_types)]
+            pub struct $name<const SCALE: u32>;
+
+            impl<const SCALE: u32> $name<SCALE> {
+                const CHECKED_SCALE: u32 = {
+                    assert!(SCALE <= $max_scale, "decimal scale is out of bounds");
+                    SCALE
+                };
+
+                /// Serializes a decimal or an optional decimal.
+                pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    T: DecimalField<Self>,
+                    S: Serializer,
+                {
+                    value.serialize_field(serializer)
+                }
+
+                /// Deserializes a decimal or an optional decimal.
+                pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                where
+                    T: DecimalField<Self>,
+                    D: Deserializer<'de>,
+                {
+                    T::deserialize_field(deserializer)
+                }
+
+                /// Wraps the value to be used in [`crate::query::Query::bind`]
+                /// and [`crate::query::Query::param`].
+                pub fn wrap<T: DecimalField<Self>>(value: T) -> Wrapped<Self, T> {
+                    Wrapped {
+                        value,
+                        _kind: PhantomData,
+                    }
+                }
+            }
+
+            #[sealed]
+            impl<T: DecimalValue, const SCALE: u32> DecimalField<$name<SCALE>> for T {
+                fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let scale = $name::<SCALE>::CHECKED_SCALE;
+                    if serializer.is_human_readable() {
+                        let text = $ser(self, scale).and_then(|_| self.to_scaled_string(scale));
+                        let text = text.ok_or_else(|| {
+                            S::Error::custom(concat!("value cannot be represented as ", $doc))
+                        })?;
+                        serializer.serialize_newtype_struct($literal, &text)
+                    } else {
+                        $ser(self, scale)
+                            .ok_or_else(|| {
+                                S::Error::custom(concat!("value cannot be represented as ", $doc))
+                            })?
+                            .serialize(serializer)
+                    }
+                }
+
+                fn deserialize_field<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let scale = $name::<SCALE>::CHECKED_SCALE;
+                    if deserializer.is_human_readable() {
+                        let text = String::deserialize(deserializer)?;
+                        T::parse(&text)
+                            .ok_or_else(|| D::Error::custom(format!("invalid decimal: {text}")))
+                    } else {
+                        let mantissa = Deserialize::deserialize(deserializer)?;
+                        $de(mantissa, scale).ok_or_else(|| {
+                            D::Error::custom(concat!("value of ", $doc, " is out of range"))
+                        })
+                    }
+                }
+            }
+
+            #[sealed]
+            impl<T: DecimalValue, const SCALE: u32> DecimalField<$name<SCALE>> for Option<T> {
+                fn serialize_field<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.as_ref()
+                        .map(|v| Inner::<$name<SCALE>, _>(v, PhantomData))
+                        .serialize(serializer)
+                }
+
+                fn deserialize_field<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let opt: Option<Inner<$name<SCALE>, T>> =
+                        Deserialize::deserialize(deserializer)?;
+                    Ok(opt.map(|v| v.0))
+                }
+            }
+        };
+    }
+
+    fn to_i32<T: DecimalValue>(value: &T, scale: u32) -> Option<i32> {
+        value.to_i128(scale).and_then(|m| i32::try_from(m).ok())
+    }
+
+    fn from_i32<T: DecimalValue>(mantissa: i32, scale: u32) -> Option<T> {
+        T::from_i128(mantissa.into(), scale)
+    }
+
+    fn to_i64<T: DecimalValue>(value: &T, scale: u32) -> Option<i64> {
+        value.to_i128(scale).and_then(|m| i64::try_from(m).ok())
+    }
+
+    fn from_i64<T: DecimalValue>(mantissa: i64, scale: u32) -> Option<T> {
+        T::from_i128(mantissa.into(), scale)
+    }
+
+    fn to_i128<T: DecimalValue>(value: &T, scale: u32) -> Option<i128> {
+        value.to_i128(scale)
+    }
+
+    fn from_i128<T: DecimalValue>(mantissa: i128, scale: u32) -> Option<T> {
+        T::from_i128(mantissa, scale)
+    }
+
+    fn to_i256<T: DecimalValue>(value: &T, scale: u32) -> Option<[u8; 32]> {
+        value.to_i256(scale)
+    }
+
+    fn from_i256<T: DecimalValue>(mantissa: [u8; 32], scale: u32) -> Option<T> {
+        T::from_i256(mantissa, scale)
+    }
+
+    decimal_kind!(
+        d32,
+        9,
+        "Decimal32(S)",
+        typed_literal::DECIMAL32,
+        to_i32,
+        from_i32
+    );
+    decimal_kind!(
+        d64,
+        18,
+        "Decimal64(S)",
+        typed_literal::DECIMAL64,
+        to_i64,
+        from_i64
+    );
+    decimal_kind!(
+        d128,
+        38,
+        "Decimal128(S)",
+        typed_literal::DECIMAL128,
+        to_i128,
+        from_i128
+    );
+    decimal_kind!(
+        d256,
+        76,
+        "Decimal256(S)",
+        typed_literal::DECIMAL256,
+        to_i256,
+        from_i256
+    );
+
+    /// Formats an integer `mantissa` as a decimal with `scale` fractional digits.
+    fn format_scaled(mantissa: &str, scale: u32) -> String {
+        let (sign, digits) = match mantissa.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", mantissa),
+        };
+        let scale = scale as usize;
+        if scale == 0 {
+            return format!("{sign}{digits}");
+        }
+        let digits = format!("{digits:0>width$}", width = scale + 1);
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        format!("{sign}{int}.{frac}")
+    }
+
+    /// Sign-extends a 128-bit mantissa to a 256-bit little-endian one.
+    #[cfg(feature = "rust_decimal")]
+    fn i128_to_i256(mantissa: i128) -> [u8; 32] {
+        let fill = if mantissa < 0 { 0xff } else { 0 };
+        let mut bytes = [fill; 32];
+        bytes[..16].copy_from_slice(&mantissa.to_le_bytes());
+        bytes
+    }
+
+    /// Truncates a 256-bit little-endian mantissa to a 128-bit one, if it fits.
+    #[cfg(feature = "rust_decimal")]
+    fn i256_to_i128(mantissa: [u8; 32]) -> Option<i128> {
+        let (low, high) = mantissa.split_at(16);
+        let value = i128::from_le_bytes(low.try_into().unwrap());
+        let fill = if value < 0 { 0xff } else { 0 };
+        high.iter().all(|b| *b == fill).then_some(value)
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[sealed]
+    impl DecimalValue for ::rust_decimal::Decimal {
+        fn to_i128(&self, scale: u32) -> Option<i128> {
+            let truncated = self.trunc_with_scale(scale.min(Self::MAX_SCALE));
+            let exp = scale.checked_sub(truncated.scale())?;
+            truncated.mantissa().checked_mul(10i128.checked_pow(exp)?)
+        }
+
+        fn from_i128(mantissa: i128, scale: u32) -> Option<Self> {
+            Self::try_from_i128_with_scale(mantissa, scale).ok()
+        }
+
+        fn to_i256(&self, scale: u32) -> Option<[u8; 32]> {
+            self.to_i128(scale).map(i128_to_i256)
+        }
+
+        fn from_i256(mantissa: [u8; 32], scale: u32) -> Option<Self> {
+            Self::from_i128(i256_to_i128(mantissa)?, scale)
+        }
+
+        fn to_scaled_string(&self, scale: u32) -> Option<String> {
+            Some(format_scaled(&self.to_i128(scale)?.to_string(), scale))
+        }
+
+        fn parse(s: &str) -> Option<Self> {
+            s.parse().ok()
+        }
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[sealed]
+    impl DecimalValue for ::bigdecimal::BigDecimal {
+        fn to_i128(&self, scale: u32) -> Option<i128> {
+            i128::try_from(scaled_bigint(self, scale)).ok()
+        }
+
+        fn from_i128(mantissa: i128, scale: u32) -> Option<Self> {
+            Some(Self::new(mantissa.into(), scale.into()))
+        }
+
+        fn to_i256(&self, scale: u32) -> Option<[u8; 32]> {
+            let bytes = scaled_bigint(self, scale).to_signed_bytes_le();
+            if bytes.len() > 32 {
+                return None;
+            }
+            let fill = if bytes.last().is_some_and(|b| *b >= 0x80) {
+                0xff
+            } else {
+                0
+            };
+            let mut mantissa = [fill; 32];
+            mantissa[..bytes.len()].copy_from_slice(&bytes);
+            Some(mantissa)
+        }
+
+        fn from_i256(mantissa: [u8; 32], scale: u32) -> Option<Self> {
+            let mantissa = ::bigdecimal::num_bigint::BigInt::from_signed_bytes_le(&mantissa);
+            Some(Self::new(mantissa, scale.into()))
+        }
+
+        fn to_scaled_string(&self, scale: u32) -> Option<String> {
+            Some(format_scaled(
+                &scaled_bigint(self, scale).to_string(),
+                scale,
+            ))
+        }
+
+        fn parse(s: &str) -> Option<Self> {
+            s.parse().ok()
+        }
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    fn scaled_bigint(
+        value: &::bigdecimal::BigDecimal,
+        scale: u32,
+    ) -> ::bigdecimal::num_bigint::BigInt {
+        let scaled = value.with_scale_round(scale.into(), ::bigdecimal::RoundingMode::Down);
+        scaled.into_bigint_and_exponent().0
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn it_formats_scaled() {
+            assert_eq!(format_scaled("0", 0), "0");
+            assert_eq!(format_scaled("0", 2), "0.00");
+            assert_eq!(format_scaled("5", 2), "0.05");
+            assert_eq!(format_scaled("-5", 2), "-0.05");
+            assert_eq!(format_scaled("12345", 2), "123.45");
+            assert_eq!(format_scaled("-12345", 5), "-0.12345");
+            assert_eq!(format_scaled("-12345", 0), "-12345");
+        }
+
+        #[cfg(feature = "rust_decimal")]
+        #[test]
+        fn it_converts_i256() {
+            for value in [0, 1, -1, i128::MAX, i128::MIN, 42, -42] {
+                assert_eq!(i256_to_i128(i128_to_i256(value)), Some(value));
+            }
+            let mut overflow = [0; 32];
+            overflow[16] = 1;
+            assert_eq!(i256_to_i128(overflow), None);
+        }
+
+        #[cfg(feature = "rust_decimal")]
+        #[test]
+        fn it_scales_rust_decimal() {
+            use ::rust_decimal::Decimal;
+
+            let value = Decimal::new(-123_456, 3); // -123.456
+            assert_eq!(value.to_i128(0), Some(-123));
+            assert_eq!(value.to_i128(2), Some(-12_345));
+            assert_eq!(value.to_i128(5), Some(-12_345_600));
+            assert_eq!(value.to_i128(38), None);
+            assert_eq!(value.to_scaled_string(4).unwrap(), "-123.4560");
+            assert_eq!(
+                Decimal::from_i128(-12_345, 2),
+                Some(Decimal::new(-12_345, 2))
+            );
+            assert_eq!(
+                Decimal::from_i256(i128_to_i256(-12_345), 2),
+                Some(Decimal::new(-12_345, 2))
+            );
+        }
+
+        #[cfg(feature = "bigdecimal")]
+        #[test]
+        fn it_scales_bigdecimal() {
+            use ::bigdecimal::BigDecimal;
+
+            let value: BigDecimal = "-123.456".parse().unwrap();
+            assert_eq!(value.to_i128(0), Some(-123));
+            assert_eq!(value.to_i128(2), Some(-12_345));
+            assert_eq!(value.to_i128(5), Some(-12_345_600));
+            let mantissa = value.to_i256(2).unwrap();
+            assert_eq!(mantissa[..2], (-12_345i16).to_le_bytes());
+            assert!(mantissa[2..].iter().all(|b| *b == 0xff));
+            assert_eq!(value.to_scaled_string(4).unwrap(), "-123.4560");
+
+            let big: BigDecimal = "1e70".parse().unwrap();
+            assert_eq!(big.to_i128(0), None);
+            let mantissa = big.to_i256(4).unwrap();
+            assert_eq!(BigDecimal::from_i256(mantissa, 4), Some(big));
+        }
+    }
+}
+
+/// Ser/de [`geo_types`] geometries to/from ClickHouse geo types.
+///
+/// Only `f64` coordinates are supported, as ClickHouse stores them as `Float64`.
+///
+/// Note that [`geo_types::Polygon`] always closes its rings, so rings of polygons
+/// read from ClickHouse get the first point repeated at the end if it is missing.
+///
+/// Geometries can also be bound in queries using the `wrap()` function of each module,
+/// e.g. [`geo::point::wrap`].
+#[cfg(feature = "geo")]
+pub mod geo {
+    use ::geo_types::{Coord, LineString, MultiLineString, MultiPolygon, Point, Polygon};
+
+    use super::*;
+    use crate::sql::ser::typed_literal;
+
+    struct SerCoord<'a>(&'a Coord<f64>);
+
+    impl Serialize for SerCoord<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            (self.0.x, self.0.y).serialize(serializer)
+        }
+    }
+
+    struct SerLineString<'a>(&'a LineString<f64>);
+
+    impl Serialize for SerLineString<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.0 .0.iter().map(SerCoord))
+        }
+    }
+
+    struct SerPolygon<'a>(&'a Polygon<f64>);
+
+    impl Serialize for SerPolygon<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let rings = std::iter::once(self.0.exterior()).chain(self.0.interiors());
+            serializer.collect_seq(rings.map(SerLineString))
+        }
+    }
+
+    struct SerMultiLineString<'a>(&'a MultiLineString<f64>);
+
+    impl Serialize for SerMultiLineString<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.0 .0.iter().map(SerLineString))
+        }
+    }
+
+    struct SerMultiPolygon<'a>(&'a MultiPolygon<f64>);
+
+    impl Serialize for SerMultiPolygon<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.0 .0.iter().map(SerPolygon))
+        }
+    }
+
+    type CoordRepr = (f64, f64);
+    type LineStringRepr = Vec<CoordRepr>;
+    type PolygonRepr = Vec<LineStringRepr>;
+
+    fn to_polygon(rings: PolygonRepr) -> Polygon<f64> {
+        let mut rings = rings.into_iter().map(LineString::from);
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(Vec::new()));
+        Polygon::new(exterior, rings.collect())
+    }
+
+    /// In human-readable formats, wraps the geometry into a typed literal,
+    /// so it is rendered as `CAST(.., 'Point')` etc. in queries.
+    fn serialize_geometry<S: Serializer>(
+        value: impl Serialize,
+        literal: &'static str,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_newtype_struct(literal, &value)
+        } else {
+            value.serialize(serializer)
+        }
+    }
+
+    macro_rules! wrap {
+        ($ty:ty) => {
+            /// Wraps the value to be used in [`crate::query::Query::bind`]
+            /// and [`crate::query::Query::param`].
+            pub fn wrap(value: &$ty) -> impl Serialize + '_ {
+                struct Wrapped<'a>(&'a $ty);
+
+                impl Serialize for Wrapped<'_> {
+                    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        serialize(self.0, serializer)
+                    }
+                }
+
+                Wrapped(value)
+            }
+        };
+    }
+
+    /// Ser/de [`Point<f64>`] to/from `Point`.
+    pub mod point {
+        use super::*;
+
+        pub fn serialize<S>(point: &Point<f64>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_geometry(SerCoord(&point.0), typed_literal::POINT, serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Point<f64>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let point: CoordRepr = Deserialize::deserialize(deserializer)?;
+            Ok(Point::from(point))
+        }
+
+        wrap!(Point<f64>);
+    }
+
+    /// Ser/de [`LineString<f64>`] to/from `Ring`.
+    pub mod ring {
+        use super::*;
+
+        pub fn serialize<S>(ring: &LineString<f64>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_geometry(SerLineString(ring), typed_literal::RING, serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<LineString<f64>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let ring: LineStringRepr = Deserialize::deserialize(deserializer)?;
+            Ok(LineString::from(ring))
+        }
+
+        wrap!(LineString<f64>);
+    }
+
+    /// Ser/de [`LineString<f64>`] to/from `LineString`.
+    pub mod line_string {
+        use super::*;
+
+        pub fn serialize<S>(line: &LineString<f64>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_geometry(SerLineString(line), typed_literal::LINE_STRING, serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<LineString<f64>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let line: LineStringRepr = Deserialize::deserialize(deserializer)?;
+            Ok(LineString::from(line))
+        }
+
+        wrap!(LineString<f64>);
+    }
+
+    /// Ser/de [`MultiLineString<f64>`] to/from `MultiLineString`.
+    pub mod multi_line_string {
+        use super::*;
+
+        pub fn serialize<S>(lines: &MultiLineString<f64>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_geometry(
+                SerMultiLineString(lines),
+                typed_literal::MULTI_LINE_STRING,
+                serializer,
+            )
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<MultiLineString<f64>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let lines: Vec<LineStringRepr> = Deserialize::deserialize(deserializer)?;
+            Ok(MultiLineString::new(
+                lines.into_iter().map(LineString::from).collect(),
+            ))
+        }
+
+        wrap!(MultiLineString<f64>);
+    }
+
+    /// Ser/de [`Polygon<f64>`] to/from `Polygon`.
+    ///
+    /// The first ring is the exterior one, the rest are interiors (holes).
+    pub mod polygon {
+        use super::*;
+
+        pub fn serialize<S>(polygon: &Polygon<f64>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_geometry(SerPolygon(polygon), typed_literal::POLYGON, serializer)
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Polygon<f64>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let rings: PolygonRepr = Deserialize::deserialize(deserializer)?;
+            Ok(to_polygon(rings))
+        }
+
+        wrap!(Polygon<f64>);
+    }
+
+    /// Ser/de [`MultiPolygon<f64>`] to/from `MultiPolygon`.
+    pub mod multi_polygon {
+        use super::*;
+
+        pub fn serialize<S>(polygons: &MultiPolygon<f64>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_geometry(
+                SerMultiPolygon(polygons),
+                typed_literal::MULTI_POLYGON,
+                serializer,
+            )
+        }
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<MultiPolygon<f64>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let polygons: Vec<PolygonRepr> = Deserialize::deserialize(deserializer)?;
+            Ok(MultiPolygon::new(
+                polygons.into_iter().map(to_polygon).collect(),
+            ))
+        }
+
+        wrap!(MultiPolygon<f64>);
+    }
+}
+
+/// Ser/de [`Value`](crate::value::Value) and [`serde_json::Value`] to/from `Dynamic`.
+///
+/// [`Value`](crate::value::Value) implements `Serialize` and `Deserialize` for `Dynamic` columns
+/// on its own, so this module is required only for [`serde_json::Value`] (the `serde_json` feature).
+/// See [`Value`](crate::value::Value) for details on the mapping of ClickHouse types.
+///
+/// ```
+/// # #[cfg(feature = "serde_json")]
+/// #[derive(clickhouse::Row, serde::Serialize, serde::Deserialize)]
+/// struct MyRow {
+///     #[serde(with = "clickhouse::serde::dynamic")]
+///     dynamic: serde_json::Value,
+/// }
+/// ```
+pub mod dynamic {
+    use std::borrow::Cow;
+
+    use sealed::sealed;
+    use serde::de::Error as _;
+
+    use super::*;
+    use crate::{error::Error, value::Value};
+
+    /// A value type supported by the helpers of this module and [`super::json`].
+    #[sealed]
+    pub trait DynamicValue: Sized {
+        #[doc(hidden)]
+        fn to_value(&self) -> Cow<'_, Value>;
+        #[doc(hidden)]
+        fn from_value(value: Value) -> Result<Self, Error>;
+    }
+
+    #[sealed]
+    impl DynamicValue for Value {
+        fn to_value(&self) -> Cow<'_, Value> {
+            Cow::Borrowed(self)
+        }
+
+        fn from_value(value: Value) -> Result<Self, Error> {
+            Ok(value)
+        }
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[sealed]
+    impl DynamicValue for serde_json::Value {
+        fn to_value(&self) -> Cow<'_, Value> {
+            Cow::Owned(Value::from(self.clone()))
+        }
+
+        fn from_value(value: Value) -> Result<Self, Error> {
+            Self::try_from(value)
+        }
+    }
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: DynamicValue,
+        S: Serializer,
+    {
+        value.to_value().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: DynamicValue,
+        D: Deserializer<'de>,
+    {
+        T::from_value(Value::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// Ser/de [`Value`](crate::value::Value) and [`serde_json::Value`] to/from `JSON`.
+///
+/// Paths of `JSON` are flat, e.g. `{"a": {"b": 1}}` is represented as
+/// `Value::Json(vec![("a.b".into(), Value::Int64(1))])`, while `serde_json::Value`
+/// gets nested objects. Only objects can be written.
+///
+/// Typed paths (e.g. `JSON(a.b UInt32)`) can be read only if validation is enabled
+/// (default), as their types are taken from the column definition. Inserting into
+/// columns with typed paths is not supported, as all paths are written as `Dynamic`.
+///
+/// ```
+/// # #[cfg(feature = "serde_json")]
+/// #[derive(clickhouse::Row, serde::Serialize, serde::Deserialize)]
+/// struct MyRow {
+///     #[serde(with = "clickhouse::serde::json")]
+///     data: serde_json::Value,
+/// }
+/// ```
+pub mod json {
+    use serde::{de::Error as _, ser::Error as _};
+
+    use super::{dynamic::DynamicValue, *};
+    use crate::value::{self, Value};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: DynamicValue,
+        S: Serializer,
+    {
+        match &*value.to_value() {
+            Value::Json(paths) => value::serialize_json(paths, serializer),
+            value if serializer.is_human_readable() => value.serialize(serializer),
+            value => Err(S::Error::custom(format!(
+                "only objects can be written to JSON columns, got {value:?}"
+            ))),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: DynamicValue,
+        D: Deserializer<'de>,
+    {
+        T::from_value(value::deserialize_json(deserializer)?).map_err(D::Error::custom)
+    }
 }
@@ -0,0 +1,258 @@
+//! A minimal ClickHouse SQL tokenizer, just enough to find placeholders.
+//!
+//! See https://github.com/ClickHouse/ClickHouse/blob/master/src/Parsers/Lexer.cpp
+
+/// A segment of a query template.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Segment<'a> {
+    /// SQL code, where `?` means a placeholder.
+    Code(&'a str),
+    /// A string literal, a quoted identifier, a comment or a heredoc.
+    /// `?` is a regular symbol here.
+    Quoted(&'a str),
+}
+
+/// Splits a query template into code and quoted segments.
+///
+/// Unterminated literals and comments last until the end of the template,
+/// the server reports them as syntax errors anyway.
+pub(crate) fn segments(template: &str) -> impl Iterator<Item = Segment<'_>> {
+    let mut rest = template;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        if let Some(len) = quoted_len(rest) {
+            let (quoted, after) = rest.split_at(len);
+            rest = after;
+            return Some(Segment::Quoted(quoted));
+        }
+
+        let len = code_len(rest);
+        let (code, after) = rest.split_at(len);
+        rest = after;
+        Some(Segment::Code(code))
+    })
+}
+
+/// Splits a script into trimmed statements separated by `;`.
+///
+/// Empty statements and ones consisting only of comments are skipped.
+pub(crate) fn statements(script: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    let mut has_code = false;
+
+    let mut push = |start: usize, end: usize, has_code: bool| {
+        if has_code {
+            statements.push(script[start..end].trim());
+        }
+    };
+
+    for segment in segments(script) {
+        match segment {
+            Segment::Code(code) => {
+                for (idx, c) in code.char_indices() {
+                    if c == ';' {
+                        push(start, offset + idx, has_code);
+                        start = offset + idx + 1;
+                        has_code = false;
+                    } else if !c.is_whitespace() {
+                        has_code = true;
+                    }
+                }
+                offset += code.len();
+            }
+            Segment::Quoted(quoted) => {
+                has_code |= !is_comment(quoted);
+                offset += quoted.len();
+            }
+        }
+    }
+
+    push(start, script.len(), has_code);
+    statements
+}
+
+fn is_comment(quoted: &str) -> bool {
+    quoted.starts_with("--") || quoted.starts_with('#') || quoted.starts_with("/*")
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$' || !c.is_ascii()
+}
+
+// Returns the length of code until the next quoted segment (if any).
+// Words are skipped as a whole, because `$` is allowed inside identifiers
+// and doesn't start a heredoc there.
+fn code_len(s: &str) -> usize {
+    let mut iter = s.char_indices().peekable();
+    while let Some((idx, c)) = iter.next() {
+        if is_word_char(c) && c != '$' {
+            while iter.next_if(|&(_, c)| is_word_char(c)).is_some() {}
+        } else if idx != 0 && quoted_len(&s[idx..]).is_some() {
+            return idx;
+        }
+    }
+    s.len()
+}
+
+// Returns the length of a quoted segment at the start of `s`, if any.
+fn quoted_len(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    match bytes.first()? {
+        quote @ (b'\'' | b'"' | b'`') => Some(quoted_literal_len(bytes, *quote)),
+        b'-' if s.starts_with("--") => Some(line_comment_len(s)),
+        b'#' if s.starts_with("# ") || s.starts_with("#!") => Some(line_comment_len(s)),
+        b'/' if s.starts_with("/*") => Some(block_comment_len(s)),
+        b'$' => heredoc_len(s),
+        _ => None,
+    }
+}
+
+// `'string'`, `"identifier"` or `` `identifier` `` with backslash escapes.
+// Doubled quotes (`''`) are handled as two adjacent literals.
+fn quoted_literal_len(bytes: &[u8], quote: u8) -> usize {
+    let mut idx = 1;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' => idx += 2,
+            b if b == quote => return idx + 1,
+            _ => idx += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn line_comment_len(s: &str) -> usize {
+    s.find('\n').map_or(s.len(), |idx| idx + 1)
+}
+
+// Multiline comments can be nested in ClickHouse.
+fn block_comment_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut depth = 0usize;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let rest = &bytes[idx..];
+        if rest.starts_with(b"/*") {
+            depth += 1;
+            idx += 2;
+        } else if rest.starts_with(b"*/") {
+            depth -= 1;
+            idx += 2;
+            if depth == 0 {
+                return idx;
+            }
+        } else {
+            idx += 1;
+        }
+    }
+    bytes.len()
+}
+
+// `$tag$ ... $tag$`, where `tag` can be empty.
+fn heredoc_len(s: &str) -> Option<usize> {
+    let tag_len = s[1..].find('$')?;
+    if !s[1..=tag_len]
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+    {
+        return None;
+    }
+
+    let tag = &s[..tag_len + 2];
+    let end = s[tag.len()..].find(tag)?;
+    Some(tag.len() + end + tag.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Segment::*, *};
+
+    fn t(template: &str, expected: &[Segment<'_>]) {
+        assert_eq!(segments(template).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn literals_and_identifiers() {
+        t(
+            r"SELECT 'a?b', `c?`, ? FROM t",
+            &[
+                Code("SELECT "),
+                Quoted("'a?b'"),
+                Code(", "),
+                Quoted("`c?`"),
+                Code(", ? FROM t"),
+            ],
+        );
+        t(
+            r#"SELECT 'it''s \'?\'', "x\"?" WHERE ?"#,
+            &[
+                Code("SELECT "),
+                Quoted("'it'"),
+                Quoted(r"'s \'?\''"),
+                Code(", "),
+                Quoted(r#""x\"?""#),
+                Code(" WHERE ?"),
+            ],
+        );
+    }
+
+    #[test]
+    fn comments() {
+        t(
+            "SELECT ? -- why?\n, ? /* what? /* nested? */ */ # ok?\n#!?",
+            &[
+                Code("SELECT ? "),
+                Quoted("-- why?\n"),
+                Code(", ? "),
+                Quoted("/* what? /* nested? */ */"),
+                Code(" "),
+                Quoted("# ok?\n"),
+                Quoted("#!?"),
+            ],
+        );
+        t("SELECT 1-?, 2/?", &[Code("SELECT 1-?, 2/?")]);
+    }
+
+    #[test]
+    fn heredocs() {
+        t(
+            "SELECT $$a?$$, $tag$ $b? $tag$, a$b, $1 ?",
+            &[
+                Code("SELECT "),
+                Quoted("$$a?$$"),
+                Code(", "),
+                Quoted("$tag$ $b? $tag$"),
+                Code(", a$b, $1 ?"),
+            ],
+        );
+    }
+
+    #[test]
+    fn split_statements() {
+        assert_eq!(
+            statements(
+                "CREATE TABLE t (s String) ENGINE = Memory;\n\
+                 INSERT INTO t VALUES ('a;b'), ($$c;$$); -- d;\n\
+                 ;; /* e; */\n\
+                 SELECT `f;` FROM t # g;\n"
+            ),
+            [
+                "CREATE TABLE t (s String) ENGINE = Memory",
+                "INSERT INTO t VALUES ('a;b'), ($$c;$$)",
+                "/* e; */\nSELECT `f;` FROM t # g;",
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated() {
+        t("SELECT ? 'a?", &[Code("SELECT ? "), Quoted("'a?")]);
+        t("SELECT ? /* a?", &[Code("SELECT ? "), Quoted("/* a?")]);
+        t("SELECT ? $$ ?", &[Code("SELECT ? $$ ?")]);
+    }
+}
@@ -1,14 +1,22 @@
 use std::fmt::{self, Display, Write};
 
+use clickhouse_types::data_types::DataTypeNode;
+
 use crate::{
     error::{Error, Result},
     row::{self, Row},
 };
 
+use self::lexer::Segment;
+
 pub use bind::{Bind, Identifier};
+pub(crate) use lexer::statements;
+pub use param::ClickHouseType;
 
 mod bind;
 pub(crate) mod escape;
+pub(crate) mod lexer;
+mod param;
 pub(crate) mod ser;
 
 #[derive(Debug, Clone)]
@@ -20,6 +28,9 @@ pub(crate) enum SqlBuilder {
 #[derive(Debug, Clone)]
 pub(crate) enum Part {
     Arg,
+    Named(String),
+    /// The index of `?param` among all `?param` placeholders.
+    Param(usize),
     Fields,
     Text(String),
 }
@@ -32,6 +43,8 @@ impl fmt::Display for SqlBuilder {
                 for part in parts {
                     match part {
                         Part::Arg => f.write_char('?')?,
+                        Part::Named(name) => write!(f, "?{{{name}}}")?,
+                        Part::Param(_) => f.write_str("?param")?,
                         Part::Fields => f.write_str("?fields")?,
                         Part::Text(text) => f.write_str(text)?,
                     }
@@ -47,29 +60,61 @@ impl fmt::Display for SqlBuilder {
 }
 
 impl SqlBuilder {
+    /// Creates a builder for SQL used as is, without placeholders.
+    pub(crate) fn raw(sql: &str) -> Self {
+        Self::InProgress(vec![Part::Text(sql.to_string())], None)
+    }
+
     pub(crate) fn new(template: &str) -> Self {
         let mut parts = Vec::new();
-        let mut rest = template;
-        while let Some(idx) = rest.find('?') {
-            if rest[idx + 1..].starts_with('?') {
-                parts.push(Part::Text(rest[..idx + 1].to_string()));
-                rest = &rest[idx + 2..];
-                continue;
-            } else if idx != 0 {
-                parts.push(Part::Text(rest[..idx].to_string()));
-            }
+        let mut text = String::new();
+        let mut param_count = 0;
+
+        for segment in lexer::segments(template) {
+            let mut rest = match segment {
+                // `?` inside literals and comments isn't a placeholder,
+                // but `??` is still unescaped for backward compatibility.
+                Segment::Quoted(quoted) => {
+                    text.push_str(&quoted.replace("??", "?"));
+                    continue;
+                }
+                Segment::Code(code) => code,
+            };
+
+            while let Some(idx) = rest.find('?') {
+                text.push_str(&rest[..idx]);
+                rest = &rest[idx + 1..];
+
+                if let Some(restescaped) = rest.strip_prefix('?') {
+                    text.push('?');
+                    rest = restescaped;
+                    continue;
+                }
+
+                if !text.is_empty() {
+                    parts.push(Part::Text(std::mem::take(&mut text)));
+                }
 
-            rest = &rest[idx + 1..];
-            if let Some(restfields) = rest.strip_prefix("fields") {
-                parts.push(Part::Fields);
-                rest = restfields;
-            } else {
-                parts.push(Part::Arg);
+                if let Some(restfields) = rest.strip_prefix("fields") {
+                    parts.push(Part::Fields);
+                    rest = restfields;
+                } else if let Some(restparam) = rest.strip_prefix("param") {
+                    parts.push(Part::Param(param_count));
+                    param_count += 1;
+                    rest = restparam;
+                } else if let Some((name, restnamed)) = strip_named(rest) {
+                    parts.push(Part::Named(name.to_string()));
+                    rest = restnamed;
+                } else {
+                    parts.push(Part::Arg);
+                }
             }
+
+            text.push_str(rest);
         }
 
-        if !rest.is_empty() {
-            parts.push(Part::Text(rest.to_string()));
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
         }
 
         SqlBuilder::InProgress(parts, None)
@@ -99,6 +144,105 @@ impl SqlBuilder {
         }
     }
 
+    pub(crate) fn bind_named(&mut self, name: &str, value: impl Bind) {
+        let Self::InProgress(parts, _) = self else {
+            return;
+        };
+
+        let is_bound = |p: &Part| matches!(p, Part::Named(n) if n == name);
+        if !parts.iter().any(is_bound) {
+            return self.error(format_args!(
+                "unexpected bind_named(), there is no unbound argument ?{{{name}}}"
+            ));
+        }
+
+        let mut s = String::new();
+        if let Err(err) = value.write(&mut s) {
+            return self.error(format_args!("invalid argument ?{{{name}}}: {err}"));
+        }
+
+        for part in parts.iter_mut().filter(|p| is_bound(p)) {
+            *part = Part::Text(s.clone());
+        }
+    }
+
+    /// Replaces the next `?param` with `{pN: Type}`, returning the name `pN`.
+    pub(crate) fn bind_param(&mut self, data_type: &DataTypeNode) -> Option<String> {
+        let Self::InProgress(parts, _) = self else {
+            return None;
+        };
+
+        let Some(part) = parts.iter_mut().find(|p| matches!(p, Part::Param(_))) else {
+            self.error("unexpected bind_param(), all ?param arguments are already bound");
+            return None;
+        };
+
+        let Part::Param(idx) = part else {
+            unreachable!()
+        };
+
+        let name = format!("p{idx}");
+        *part = Part::Text(format!("{{{name}: {data_type}}}"));
+        Some(name)
+    }
+
+    /// Replaces the next `?` with the name of a table `prefixN`, returning the name.
+    pub(crate) fn bind_arg_table(&mut self, prefix: &str) -> Option<String> {
+        let Self::InProgress(parts, _) = self else {
+            return None;
+        };
+
+        let Some(idx) = parts.iter().position(|p| matches!(p, Part::Arg)) else {
+            self.error("unexpected bind(), all arguments are already bound");
+            return None;
+        };
+
+        let name = format!("{prefix}{idx}");
+        parts[idx] = Part::Text(name.clone());
+        Some(name)
+    }
+
+    /// Replaces all `?{name}` with `{name: Type}`, if any.
+    pub(crate) fn bind_named_param(&mut self, name: &str, data_type: &DataTypeNode) {
+        let Self::InProgress(parts, _) = self else {
+            return;
+        };
+
+        for part in parts.iter_mut() {
+            if matches!(part, Part::Named(n) if n == name) {
+                *part = Part::Text(format!("{{{name}: {data_type}}}"));
+            }
+        }
+    }
+
+    /// Appends SQL as is, without parsing placeholders.
+    #[cfg(feature = "builder")]
+    pub(crate) fn push_text(&mut self, text: &str) {
+        if let Self::InProgress(parts, _) = self {
+            parts.push(Part::Text(text.to_string()));
+        }
+    }
+
+    /// Appends another builder, keeping its unbound arguments.
+    #[cfg(feature = "builder")]
+    pub(crate) fn append(&mut self, other: SqlBuilder) {
+        let Self::InProgress(parts, _) = self else {
+            return;
+        };
+
+        match other {
+            Self::InProgress(other_parts, _) => {
+                // Keep `?param` indices unique across the whole query.
+                let offset = parts.iter().filter(|p| matches!(p, Part::Param(_))).count();
+                parts.extend(other_parts.into_iter().map(|part| match part {
+                    Part::Param(idx) => Part::Param(offset + idx),
+                    part => part,
+                }));
+            }
+            Self::Failed(err) => *self = Self::Failed(err),
+        }
+    }
+
     pub(crate) fn bind_fields<T: Row>(&mut self) {
         let Self::InProgress(parts, _) = self else {
             return;
@@ -124,6 +268,15 @@ impl SqlBuilder {
                         self.error("unbound query argument");
                         break;
                     }
+                    Part::Named(name) => {
+                        let err = format!("unbound query argument ?{{{name}}}");
+                        self.error(err);
+                        break;
+                    }
+                    Part::Param(_) => {
+                        self.error("unbound query argument ?param");
+                        break;
+                    }
                     Part::Fields => {
                         self.error("unbound query argument ?fields");
                         break;
@@ -143,11 +296,18 @@ impl SqlBuilder {
         }
     }
 
-    fn error(&mut self, err: impl Display) {
+    pub(crate) fn error(&mut self, err: impl Display) {
         *self = Self::Failed(format!("invalid SQL: {err}"));
     }
 }
 
+// Parses `{name}` of a named argument `?{name}`, returning the name and the rest.
+fn strip_named(s: &str) -> Option<(&str, &str)> {
+    let (name, rest) = s.strip_prefix('{')?.split_once('}')?;
+    let is_valid = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    (!name.is_empty() && name.chars().all(is_valid)).then_some((name, rest))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,6 +402,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn question_marks_in_literals_and_comments() {
+        let mut sql = SqlBuilder::new(
+            "SELECT `a?` FROM test /* b? */ WHERE match(s, 'a?b') AND c = ? -- d?\nAND e = $$?$$",
+        );
+        sql.bind_arg(42);
+        assert_eq!(
+            sql.finish().unwrap(),
+            "SELECT `a?` FROM test /* b? */ WHERE match(s, 'a?b') AND c = 42 -- d?\nAND e = $$?$$"
+        );
+
+        let sql = SqlBuilder::new("SELECT ?? FROM test WHERE match(s, 'a??b')");
+        assert_eq!(
+            sql.finish().unwrap(),
+            "SELECT ? FROM test WHERE match(s, 'a?b')"
+        );
+    }
+
+    #[test]
+    fn named_args() {
+        let mut sql = SqlBuilder::new(
+            "SELECT ?fields FROM test WHERE a = ?{a} AND b < ? AND (c = ?{c} OR d = ?{a})",
+        );
+        assert_eq!(
+            sql.to_string(),
+            "SELECT ?fields FROM test WHERE a = ?{a} AND b < ? AND (c = ?{c} OR d = ?{a})"
+        );
+
+        sql.bind_named("a", "foo");
+        sql.bind_arg(42);
+        sql.bind_named("c", None::<u32>);
+        sql.bind_fields::<Row>();
+        assert_eq!(
+            sql.finish().unwrap(),
+            r"SELECT `a`,`b` FROM test WHERE a = 'foo' AND b < 42 AND (c = NULL OR d = 'foo')"
+        );
+
+        // Not a valid name, so it's a positional argument.
+        let mut sql = SqlBuilder::new("SELECT ?{a b}");
+        sql.bind_arg(1);
+        assert_eq!(sql.finish().unwrap(), "SELECT 1{a b}");
+    }
+
+    #[test]
+    fn typed_params() {
+        let mut sql = SqlBuilder::new(
+            "SELECT ?fields FROM test WHERE a = ?param AND b IN ?{b} AND c = ?param",
+        );
+        assert_eq!(
+            sql.to_string(),
+            "SELECT ?fields FROM test WHERE a = ?param AND b IN ?{b} AND c = ?param"
+        );
+
+        assert_eq!(sql.bind_param(&DataTypeNode::String).as_deref(), Some("p0"));
+        sql.bind_named_param("b", &<Vec<u32>>::data_type());
+        assert_eq!(sql.bind_param(&DataTypeNode::UInt8).as_deref(), Some("p1"));
+        sql.bind_fields::<Row>();
+        assert_eq!(
+            sql.finish().unwrap(),
+            r"SELECT `a`,`b` FROM test WHERE a = {p0: String} AND b IN {b: Array(UInt32)} AND c = {p1: UInt8}"
+        );
+    }
+
+    #[test]
+    fn args_as_tables() {
+        let mut sql = SqlBuilder::new("SELECT 1 FROM test WHERE a IN ? AND b IN ? AND c = ?");
+        sql.bind_arg(&[1, 2][..]);
+        let name = sql.bind_arg_table("_set");
+        assert_eq!(name.as_deref(), Some("_set3"));
+        sql.bind_arg(3);
+        assert_eq!(
+            sql.finish().unwrap(),
+            "SELECT 1 FROM test WHERE a IN [1,2] AND b IN _set3 AND c = 3"
+        );
+
+        let mut sql = SqlBuilder::new("SELECT 1");
+        assert_eq!(sql.bind_arg_table("_set"), None);
+        let err = sql.finish().unwrap_err();
+        assert!(err.to_string().contains("all arguments are already bound"));
+    }
+
     #[test]
     fn option_as_null() {
         let mut sql = SqlBuilder::new("SELECT 1 FROM test WHERE a = ?");
@@ -279,5 +520,32 @@ mod tests {
         sql.bind_arg(42);
         let err = sql.finish().unwrap_err();
         assert!(err.to_string().contains("unbound query argument ?fields"));
+
+        let mut sql = SqlBuilder::new("SELECT a FROM test WHERE b = ?{b} AND c = ?{c}");
+        sql.bind_named("b", 42);
+        let err = sql.finish().unwrap_err();
+        assert!(matches!(err, Error::InvalidParams(_)));
+        assert!(err.to_string().contains("unbound query argument ?{c}"));
+
+        let mut sql = SqlBuilder::new("SELECT a FROM test WHERE b = ?{b}");
+        sql.bind_named("b", 42);
+        sql.bind_named("c", 42);
+        let err = sql.finish().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("there is no unbound argument ?{c}"));
+
+        let mut sql = SqlBuilder::new("SELECT a FROM test WHERE b = ?param AND c = ?param");
+        sql.bind_param(&DataTypeNode::UInt8);
+        let err = sql.finish().unwrap_err();
+        assert!(err.to_string().contains("unbound query argument ?param"));
+
+        let mut sql = SqlBuilder::new("SELECT a FROM test WHERE b = ?param");
+        sql.bind_param(&DataTypeNode::UInt8);
+        assert_eq!(sql.bind_param(&DataTypeNode::UInt8), None);
+        let err = sql.finish().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("all ?param arguments are already bound"));
     }
 }
@@ -0,0 +1,569 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Write},
+    hash::BuildHasher,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use clickhouse_types::data_types::DataTypeNode;
+
+use super::escape;
+
+/// A Rust type with a known ClickHouse counterpart.
+///
+/// Values of such types can be used as typed server-side parameters,
+/// see [`Query::param_typed`] and [`Query::bind_param`].
+///
+/// Implemented for primitives, strings, `Option<T>` (as `Nullable(T)`),
+/// slices and `Vec<T>` (as `Array(T)`), tuples, maps, IP addresses and,
+/// depending on enabled features, for `Uuid`, date and time types of
+/// `chrono` and `time` (as `Date32` and `DateTime64(6)`, truncating nanoseconds
+/// and rejecting years outside of 1900 to 2299) and decimals of `rust_decimal`
+/// and `bigdecimal`.
+///
+/// [`Query::param_typed`]: crate::query::Query::param_typed
+/// [`Query::bind_param`]: crate::query::Query::bind_param
+pub trait ClickHouseType {
+    /// Returns the ClickHouse type of values, e.g. `Array(Nullable(String))`.
+    fn data_type() -> DataTypeNode;
+
+    /// Writes the value in the text format of server-side parameters.
+    ///
+    /// Values nested into arrays, tuples and maps are written as literals,
+    /// e.g. strings are quoted, while top-level ones are written as is.
+    fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result;
+}
+
+// Writes values that are quoted if nested, e.g. strings and dates.
+fn write_text(text: &str, nested: bool, mut dst: &mut dyn Write) -> fmt::Result {
+    if nested {
+        escape::string(text, &mut dst)
+    } else {
+        escape::escape(text, &mut dst)
+    }
+}
+
+fn write_items<'a, T: ClickHouseType + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    dst: &mut dyn Write,
+) -> fmt::Result {
+    dst.write_char('[')?;
+    for (idx, item) in items.into_iter().enumerate() {
+        if idx > 0 {
+            dst.write_char(',')?;
+        }
+        item.write_param(true, dst)?;
+    }
+    dst.write_char(']')
+}
+
+fn write_entries<'a, K, V>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+    dst: &mut dyn Write,
+) -> fmt::Result
+where
+    K: ClickHouseType + 'a,
+    V: ClickHouseType + 'a,
+{
+    dst.write_char('{')?;
+    for (idx, (key, value)) in entries.into_iter().enumerate() {
+        if idx > 0 {
+            dst.write_char(',')?;
+        }
+        key.write_param(true, dst)?;
+        dst.write_char(':')?;
+        value.write_param(true, dst)?;
+    }
+    dst.write_char('}')
+}
+
+macro_rules! impl_displayed {
+    ($($ty:ty => $data_type:ident),* $(,)?) => {
+        $(
+            impl ClickHouseType for $ty {
+                fn data_type() -> DataTypeNode {
+                    DataTypeNode::$data_type
+                }
+
+                fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+                    write!(dst, "{self}")
+                }
+            }
+        )*
+    };
+}
+
+impl_displayed!(
+    bool => Bool,
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    i128 => Int128,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    u128 => UInt128,
+    f32 => Float32,
+    f64 => Float64,
+);
+
+macro_rules! impl_quoted {
+    ($($ty:ty => $data_type:ident),* $(,)?) => {
+        $(
+            impl ClickHouseType for $ty {
+                fn data_type() -> DataTypeNode {
+                    DataTypeNode::$data_type
+                }
+
+                fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+                    write_text(&self.to_string(), nested, dst)
+                }
+            }
+        )*
+    };
+}
+
+impl_quoted!(
+    Ipv4Addr => IPv4,
+    Ipv6Addr => IPv6,
+);
+
+#[cfg(feature = "uuid")]
+impl_quoted!(::uuid::Uuid => UUID);
+
+impl ClickHouseType for str {
+    fn data_type() -> DataTypeNode {
+        DataTypeNode::String
+    }
+
+    fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        write_text(self, nested, dst)
+    }
+}
+
+impl ClickHouseType for String {
+    fn data_type() -> DataTypeNode {
+        DataTypeNode::String
+    }
+
+    fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        write_text(self, nested, dst)
+    }
+}
+
+impl<T: ClickHouseType + ?Sized> ClickHouseType for &T {
+    fn data_type() -> DataTypeNode {
+        T::data_type()
+    }
+
+    fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        (**self).write_param(nested, dst)
+    }
+}
+
+impl<T: ClickHouseType> ClickHouseType for Option<T> {
+    fn data_type() -> DataTypeNode {
+        DataTypeNode::Nullable(Box::new(T::data_type()))
+    }
+
+    fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        match self {
+            Some(value) => value.write_param(nested, dst),
+            None if nested => dst.write_str("NULL"),
+            None => dst.write_str("\\N"),
+        }
+    }
+}
+
+impl<T: ClickHouseType> ClickHouseType for [T] {
+    fn data_type() -> DataTypeNode {
+        DataTypeNode::Array(Box::new(T::data_type()))
+    }
+
+    fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        write_items(self, dst)
+    }
+}
+
+impl<T: ClickHouseType, const N: usize> ClickHouseType for [T; N] {
+    fn data_type() -> DataTypeNode {
+        <[T]>::data_type()
+    }
+
+    fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        write_items(self, dst)
+    }
+}
+
+impl<T: ClickHouseType> ClickHouseType for Vec<T> {
+    fn data_type() -> DataTypeNode {
+        <[T]>::data_type()
+    }
+
+    fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        write_items(self, dst)
+    }
+}
+
+impl<K: ClickHouseType, V: ClickHouseType, S: BuildHasher> ClickHouseType for HashMap<K, V, S> {
+    fn data_type() -> DataTypeNode {
+        DataTypeNode::Map([Box::new(K::data_type()), Box::new(V::data_type())])
+    }
+
+    fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        write_entries(self, dst)
+    }
+}
+
+impl<K: ClickHouseType, V: ClickHouseType> ClickHouseType for BTreeMap<K, V> {
+    fn data_type() -> DataTypeNode {
+        HashMap::<K, V>::data_type()
+    }
+
+    fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        write_entries(self, dst)
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($ty:ident $idx:tt),+) => {
+        impl<$($ty: ClickHouseType),+> ClickHouseType for ($($ty,)+) {
+            fn data_type() -> DataTypeNode {
+                DataTypeNode::Tuple(vec![$($ty::data_type()),+])
+            }
+
+            fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+                dst.write_char('(')?;
+                $(
+                    if $idx > 0 {
+                        dst.write_char(',')?;
+                    }
+                    self.$idx.write_param(true, dst)?;
+                )+
+                dst.write_char(')')
+            }
+        }
+    };
+}
+
+impl_tuple!(A 0);
+impl_tuple!(A 0, B 1);
+impl_tuple!(A 0, B 1, C 2);
+impl_tuple!(A 0, B 1, C 2, D 3);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+// Date and time types are mapped to `Date32` and `DateTime64(6)`, which cover
+// the widest range of dates, 1900-01-01 to 2299-12-31. `DateTime64(9)` is limited
+// to 1900-01-01 to 2262-04-11, so sub-microsecond precision is truncated instead.
+// Values out of the range are rejected, since the server would silently clamp them.
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn check_year(year: i32) -> fmt::Result {
+    if (1900..=2299).contains(&year) {
+        Ok(())
+    } else {
+        Err(fmt::Error)
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn datetime64(tz: Option<&str>) -> DataTypeNode {
+    use clickhouse_types::data_types::DateTimePrecision;
+
+    DataTypeNode::DateTime64(DateTimePrecision::Precision6, tz.map(Into::into))
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use ::chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone};
+
+    use super::*;
+
+    const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f";
+
+    /// Converted to UTC.
+    impl<Tz: TimeZone> ClickHouseType for DateTime<Tz> {
+        fn data_type() -> DataTypeNode {
+            datetime64(Some("UTC"))
+        }
+
+        fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+            self.naive_utc().write_param(nested, dst)
+        }
+    }
+
+    impl ClickHouseType for NaiveDateTime {
+        fn data_type() -> DataTypeNode {
+            datetime64(None)
+        }
+
+        fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+            check_year(self.year())?;
+            write_text(&self.format(DATETIME_FORMAT).to_string(), nested, dst)
+        }
+    }
+
+    impl ClickHouseType for NaiveDate {
+        fn data_type() -> DataTypeNode {
+            DataTypeNode::Date32
+        }
+
+        fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+            check_year(self.year())?;
+            write_text(&self.format("%Y-%m-%d").to_string(), nested, dst)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use ::time::{Date, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+    use super::*;
+
+    fn format_date(date: Date) -> String {
+        let (year, month, day) = (date.year(), u8::from(date.month()), date.day());
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+
+    fn format_datetime(dt: PrimitiveDateTime) -> String {
+        let (hour, minute, second, micros) = dt.as_hms_micro();
+        let date = format_date(dt.date());
+        format!("{date} {hour:02}:{minute:02}:{second:02}.{micros:06}")
+    }
+
+    /// Converted to UTC.
+    impl ClickHouseType for OffsetDateTime {
+        fn data_type() -> DataTypeNode {
+            datetime64(Some("UTC"))
+        }
+
+        fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+            let utc = self.to_offset(UtcOffset::UTC);
+            PrimitiveDateTime::new(utc.date(), utc.time()).write_param(nested, dst)
+        }
+    }
+
+    impl ClickHouseType for PrimitiveDateTime {
+        fn data_type() -> DataTypeNode {
+            datetime64(None)
+        }
+
+        fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+            check_year(self.year())?;
+            write_text(&format_datetime(*self), nested, dst)
+        }
+    }
+
+    impl ClickHouseType for Date {
+        fn data_type() -> DataTypeNode {
+            DataTypeNode::Date32
+        }
+
+        fn write_param(&self, nested: bool, dst: &mut dyn Write) -> fmt::Result {
+            check_year(self.year())?;
+            write_text(&format_date(*self), nested, dst)
+        }
+    }
+}
+
+/// Mapped to `Decimal(76, 28)`, which fits any `rust_decimal::Decimal` exactly.
+#[cfg(feature = "rust_decimal")]
+impl ClickHouseType for ::rust_decimal::Decimal {
+    fn data_type() -> DataTypeNode {
+        DataTypeNode::Decimal(
+            76,
+            28,
+            clickhouse_types::data_types::DecimalType::Decimal256,
+        )
+    }
+
+    fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        write!(dst, "{self}")
+    }
+}
+
+/// Mapped to `Decimal(76, 38)`, so values are limited to 38 digits
+/// both before and after the decimal point.
+#[cfg(feature = "bigdecimal")]
+impl ClickHouseType for ::bigdecimal::BigDecimal {
+    fn data_type() -> DataTypeNode {
+        DataTypeNode::Decimal(
+            76,
+            38,
+            clickhouse_types::data_types::DecimalType::Decimal256,
+        )
+    }
+
+    fn write_param(&self, _nested: bool, dst: &mut dyn Write) -> fmt::Result {
+        dst.write_str(&self.to_plain_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check<T: ClickHouseType>(value: T, data_type: &str, param: &str) {
+        assert_eq!(T::data_type().to_string(), data_type);
+
+        let mut actual = String::new();
+        value.write_param(false, &mut actual).unwrap();
+        assert_eq!(actual, param);
+    }
+
+    #[test]
+    fn primitives() {
+        check(true, "Bool", "true");
+        check(-42i8, "Int8", "-42");
+        check(42u64, "UInt64", "42");
+        check(u128::MAX, "UInt128", &u128::MAX.to_string());
+        check(4.5f32, "Float32", "4.5");
+        check("a'b\\c", "String", r"a\'b\\c");
+        check(String::from("a\tb"), "String", "a\\\tb");
+        check(Ipv4Addr::LOCALHOST, "IPv4", "127.0.0.1");
+        check(Ipv6Addr::LOCALHOST, "IPv6", "::1");
+    }
+
+    #[test]
+    fn compound() {
+        check(Some(42u8), "Nullable(UInt8)", "42");
+        check(None::<u8>, "Nullable(UInt8)", r"\N");
+        check(
+            vec![Some("a'b"), None],
+            "Array(Nullable(String))",
+            r"['a\'b',NULL]",
+        );
+        check(&[1u16, 2][..], "Array(UInt16)", "[1,2]");
+        check(
+            (42u32, "foo", [Ipv4Addr::LOCALHOST]),
+            "Tuple(UInt32, String, Array(IPv4))",
+            "(42,'foo',['127.0.0.1'])",
+        );
+        check(
+            BTreeMap::from([("a", vec![1i32]), ("b", vec![])]),
+            "Map(String, Array(Int32))",
+            "{'a':[1],'b':[]}",
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid() {
+        check(
+            [::uuid::Uuid::nil()],
+            "Array(UUID)",
+            "['00000000-0000-0000-0000-000000000000']",
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use ::chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+
+        let dt: DateTime<FixedOffset> = "2024-05-06T07:08:09.123+03:00".parse().unwrap();
+        check(dt, "DateTime64(6, 'UTC')", "2024-05-06 04:08:09.123000");
+        check(
+            dt.naive_local(),
+            "DateTime64(6)",
+            "2024-05-06 07:08:09.123000",
+        );
+
+        // Out of the range of `DateTime64(9)`, sub-microseconds are truncated.
+        let dt: DateTime<Utc> = "2299-12-31T23:59:59.999999999Z".parse().unwrap();
+        check(dt, "DateTime64(6, 'UTC')", "2299-12-31 23:59:59.999999");
+        let dt: DateTime<Utc> = "1900-01-01T00:00:00.000000001Z".parse().unwrap();
+        check(dt, "DateTime64(6, 'UTC')", "1900-01-01 00:00:00.000000");
+        check(
+            vec![NaiveDate::from_ymd_opt(1900, 1, 2).unwrap()],
+            "Array(Date32)",
+            "['1900-01-02']",
+        );
+
+        // Out of the range of `DateTime64(6)` and `Date32`.
+        let dt: DateTime<FixedOffset> = "2300-01-01T01:00:00+02:00".parse().unwrap();
+        assert!(dt.write_param(false, &mut String::new()).is_ok());
+        let dt: DateTime<Utc> = "2300-01-01T00:00:00Z".parse().unwrap();
+        assert!(dt.write_param(false, &mut String::new()).is_err());
+        let dt: DateTime<Utc> = "1899-12-31T23:59:59.999999999Z".parse().unwrap();
+        assert!(dt.write_param(false, &mut String::new()).is_err());
+        let dates = vec![NaiveDate::from_ymd_opt(1899, 12, 31).unwrap()];
+        assert!(dates.write_param(false, &mut String::new()).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        use ::time::macros::{date, datetime};
+
+        check(
+            datetime!(2024-05-06 07:08:09.000123 +03:00),
+            "DateTime64(6, 'UTC')",
+            "2024-05-06 04:08:09.000123",
+        );
+        check(
+            datetime!(2024-05-06 07:08:09),
+            "DateTime64(6)",
+            "2024-05-06 07:08:09.000000",
+        );
+
+        // Out of the range of `DateTime64(9)`, sub-microseconds are truncated.
+        check(
+            datetime!(2299-12-31 23:59:59.999999999),
+            "DateTime64(6)",
+            "2299-12-31 23:59:59.999999",
+        );
+        check(
+            datetime!(2300-01-01 01:00 +02:00),
+            "DateTime64(6, 'UTC')",
+            "2299-12-31 23:00:00.000000",
+        );
+
+        // Out of the range of `DateTime64(6)` and `Date32`.
+        let mut text = String::new();
+        assert!(datetime!(2300-01-01 0:00)
+            .write_param(false, &mut text)
+            .is_err());
+        assert!(datetime!(1899-12-31 23:00 -02:00)
+            .write_param(false, &mut text)
+            .is_ok());
+        assert!(datetime!(1899-12-31 23:00 +02:00)
+            .write_param(false, &mut text)
+            .is_err());
+        assert!(Some(date!(2300 - 01 - 01))
+            .write_param(false, &mut text)
+            .is_err());
+        check(
+            Some(date!(2024 - 05 - 06)),
+            "Nullable(Date32)",
+            "2024-05-06",
+        );
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn rust_decimal() {
+        use ::rust_decimal::Decimal;
+
+        check(
+            vec![Decimal::new(-12345, 2)],
+            "Array(Decimal(76, 28))",
+            "[-123.45]",
+        );
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn bigdecimal() {
+        use ::bigdecimal::BigDecimal;
+
+        let value: BigDecimal = "1e20".parse().unwrap();
+        check(value, "Decimal(76, 38)", "100000000000000000000");
+    }
+}
@@ -1,7 +1,10 @@
 use std::fmt::{self, Write};
 
 use serde::{
-    ser::{self, SerializeSeq, SerializeTuple, Serializer},
+    ser::{
+        self, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple, SerializeTupleStruct,
+        Serializer,
+    },
     Serialize,
 };
 use thiserror::Error;
@@ -37,6 +40,9 @@ type Impossible = ser::Impossible<(), SerializerError>;
 
 struct SqlSerializer<'a, W> {
     writer: &'a mut W,
+    // Maps and structs nested into params are written in the param syntax,
+    // e.g. `{k:v}` instead of `map(k,v)`.
+    in_param: bool,
 }
 
 macro_rules! unsupported {
@@ -75,19 +81,15 @@ macro_rules! forward_to_display {
 impl<'a, W: Write> Serializer for SqlSerializer<'a, W> {
     type Error = SerializerError;
     type Ok = ();
-    type SerializeMap = Impossible;
+    type SerializeMap = SqlMapSerializer<'a, W>;
     type SerializeSeq = SqlListSerializer<'a, W>;
-    type SerializeStruct = Impossible;
+    type SerializeStruct = SqlStructSerializer<'a, W>;
     type SerializeStructVariant = Impossible;
     type SerializeTuple = SqlListSerializer<'a, W>;
-    type SerializeTupleStruct = Impossible;
+    type SerializeTupleStruct = SqlListSerializer<'a, W>;
     type SerializeTupleVariant = Impossible;
 
-    unsupported!(
-        serialize_map(Option<usize>) -> Result<Impossible>,
-        serialize_unit,
-        serialize_unit_struct(&'static str),
-    );
+    unsupported!(serialize_unit, serialize_unit_struct(&'static str),);
 
     #[inline]
     fn serialize_bytes(self, value: &[u8]) -> Result {
@@ -135,22 +137,18 @@ impl<'a, W: Write> Serializer for SqlSerializer<'a, W> {
 
     #[inline]
     fn serialize_seq(self, _len: Option<usize>) -> Result<SqlListSerializer<'a, W>> {
-        self.writer.write_char('[')?;
-        Ok(SqlListSerializer {
-            writer: self.writer,
-            has_items: false,
-            closing_char: ']',
-        })
+        SqlListSerializer::new(self.writer, "[", ']', self.in_param)
     }
 
     #[inline]
     fn serialize_tuple(self, _len: usize) -> Result<SqlListSerializer<'a, W>> {
-        self.writer.write_char('(')?;
-        Ok(SqlListSerializer {
-            writer: self.writer,
-            has_items: false,
-            closing_char: ')',
-        })
+        SqlListSerializer::new(self.writer, "(", ')', self.in_param)
+    }
+
+    // `map('a',1,'b',2)`, or `{'a':1,'b':2}` in params.
+    #[inline]
+    fn serialize_map(self, _len: Option<usize>) -> Result<SqlMapSerializer<'a, W>> {
+        SqlMapSerializer::new(self.writer, self.in_param)
     }
 
     #[inline]
@@ -178,9 +176,15 @@ impl<'a, W: Write> Serializer for SqlSerializer<'a, W> {
     #[inline]
     fn serialize_newtype_struct<T: Serialize + ?Sized>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result {
+        if let Some(write) = typed_literal::writer(name) {
+            let mut text = String::new();
+            value.serialize(ParamSerializer { writer: &mut text })?;
+            return write(&text, self.writer);
+        }
+
         value.serialize(self)
     }
 
@@ -195,9 +199,16 @@ impl<'a, W: Write> Serializer for SqlSerializer<'a, W> {
         Err(SerializerError::Unsupported("serialize_newtype_variant"))
     }
 
+    // Tuple structs are written as unnamed tuples, e.g. `tuple(42,'foo')` or `(42,'foo')`
+    // in params. `tuple(..)` is used instead of `(..)` to support single-field structs.
     #[inline]
-    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Impossible> {
-        Err(SerializerError::Unsupported("serialize_tuple_struct"))
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<SqlListSerializer<'a, W>> {
+        let opening = if self.in_param { "(" } else { "tuple(" };
+        SqlListSerializer::new(self.writer, opening, ')', self.in_param)
     }
 
     #[inline]
@@ -212,8 +223,12 @@ impl<'a, W: Write> Serializer for SqlSerializer<'a, W> {
     }
 
     #[inline]
-    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
-        Err(SerializerError::Unsupported("serialize_struct"))
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<SqlStructSerializer<'a, W>> {
+        SqlStructSerializer::new(self.writer, self.in_param)
     }
 
     #[inline]
@@ -233,12 +248,125 @@ impl<'a, W: Write> Serializer for SqlSerializer<'a, W> {
     }
 }
 
+// === Typed literals ===
+
+/// Names of newtype structs used by [`crate::serde`] helpers to represent values
+/// that have no native serde counterpart, e.g. decimals.
+///
+/// The wrapped value is always a string. Other human-readable serializers,
+/// including [`ParamSerializer`], see only this string, while [`SqlSerializer`]
+/// renders an expression of the corresponding ClickHouse type.
+pub(crate) mod typed_literal {
+    use std::fmt::Write;
+
+    use super::{escape, Result, SerializerError};
+
+    pub(crate) const DECIMAL32: &str = "$clickhouse::Decimal32";
+    pub(crate) const DECIMAL64: &str = "$clickhouse::Decimal64";
+    pub(crate) const DECIMAL128: &str = "$clickhouse::Decimal128";
+    pub(crate) const DECIMAL256: &str = "$clickhouse::Decimal256";
+    pub(crate) const INT256: &str = "$clickhouse::Int256";
+    pub(crate) const UINT256: &str = "$clickhouse::UInt256";
+    pub(crate) const POINT: &str = "$clickhouse::Point";
+    pub(crate) const RING: &str = "$clickhouse::Ring";
+    pub(crate) const LINE_STRING: &str = "$clickhouse::LineString";
+    pub(crate) const MULTI_LINE_STRING: &str = "$clickhouse::MultiLineString";
+    pub(crate) const POLYGON: &str = "$clickhouse::Polygon";
+    pub(crate) const MULTI_POLYGON: &str = "$clickhouse::MultiPolygon";
+    pub(crate) const IPV4: &str = "$clickhouse::IPv4";
+    pub(crate) const IPV6: &str = "$clickhouse::IPv6";
+
+    type WriteFn = fn(&str, &mut dyn Write) -> Result;
+
+    pub(super) fn writer(name: &str) -> Option<WriteFn> {
+        Some(match name {
+            DECIMAL32 => |text, w| write_decimal("toDecimal32", text, w),
+            DECIMAL64 => |text, w| write_decimal("toDecimal64", text, w),
+            DECIMAL128 => |text, w| write_decimal("toDecimal128", text, w),
+            DECIMAL256 => |text, w| write_decimal("toDecimal256", text, w),
+            INT256 => |text, w| write_integer("Int256", text, w),
+            UINT256 => |text, w| write_integer("UInt256", text, w),
+            POINT => |text, w| write_geometry("Point", text, w),
+            RING => |text, w| write_geometry("Ring", text, w),
+            LINE_STRING => |text, w| write_geometry("LineString", text, w),
+            MULTI_LINE_STRING => |text, w| write_geometry("MultiLineString", text, w),
+            POLYGON => |text, w| write_geometry("Polygon", text, w),
+            MULTI_POLYGON => |text, w| write_geometry("MultiPolygon", text, w),
+            IPV4 => |text, w| write_ip("toIPv4", text, w),
+            IPV6 => |text, w| write_ip("toIPv6", text, w),
+            _ => return None,
+        })
+    }
+
+    // The type of the value written by `writer(name)`, e.g. `Decimal64(2)` or `IPv4`.
+    pub(super) fn data_type(name: &str, text: &str) -> String {
+        let scale = text.split_once('.').map_or(0, |(_, frac)| frac.len());
+        match name {
+            DECIMAL32 => format!("Decimal32({scale})"),
+            DECIMAL64 => format!("Decimal64({scale})"),
+            DECIMAL128 => format!("Decimal128({scale})"),
+            DECIMAL256 => format!("Decimal256({scale})"),
+            _ => name.trim_start_matches("$clickhouse::").into(),
+        }
+    }
+
+    // `42::Int256`, the same as `i128` and `u128` are written.
+    fn write_integer(ty: &str, text: &str, writer: &mut dyn Write) -> Result {
+        let digits = text.strip_prefix('-').unwrap_or(text);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(SerializerError::Custom(format!("invalid {ty}: {text}")));
+        }
+        write!(writer, "{text}::{ty}")?;
+        Ok(())
+    }
+
+    // `CAST([(1,2.5),(3,4)], 'Ring')`, the cast is required to avoid inferring integer tuples.
+    fn write_geometry(ty: &str, text: &str, writer: &mut dyn Write) -> Result {
+        let is_allowed = |c: char| c.is_ascii_alphanumeric() || "()[],.+-".contains(c);
+        if !text.chars().all(is_allowed) {
+            return Err(SerializerError::Custom(format!("invalid {ty}: {text}")));
+        }
+        write!(writer, "CAST({text}, '{ty}')")?;
+        Ok(())
+    }
+
+    // `toIPv6('::ffff:10.0.0.1')`
+    fn write_ip(func: &str, text: &str, mut writer: &mut dyn Write) -> Result {
+        write!(writer, "{func}(")?;
+        escape::string(text, &mut writer)?;
+        writer.write_char(')')?;
+        Ok(())
+    }
+
+    // `toDecimal64('1.50', 2)`
+    fn write_decimal(func: &str, text: &str, mut writer: &mut dyn Write) -> Result {
+        let scale = text.split_once('.').map_or(0, |(_, frac)| frac.len());
+        write!(writer, "{func}(")?;
+        escape::string(text, &mut writer)?;
+        write!(writer, ", {scale})")?;
+        Ok(())
+    }
+}
+
 // === SqlListSerializer ===
 
 struct SqlListSerializer<'a, W> {
     writer: &'a mut W,
     has_items: bool,
     closing_char: char,
+    in_param: bool,
+}
+
+impl<'a, W: Write> SqlListSerializer<'a, W> {
+    fn new(writer: &'a mut W, opening: &str, closing_char: char, in_param: bool) -> Result<Self> {
+        writer.write_str(opening)?;
+        Ok(Self {
+            writer,
+            has_items: false,
+            closing_char,
+            in_param,
+        })
+    }
 }
 
 impl<W: Write> SerializeSeq for SqlListSerializer<'_, W> {
@@ -258,6 +386,7 @@ impl<W: Write> SerializeSeq for SqlListSerializer<'_, W> {
 
         value.serialize(SqlSerializer {
             writer: self.writer,
+            in_param: self.in_param,
         })
     }
 
@@ -286,6 +415,475 @@ impl<W: Write> SerializeTuple for SqlListSerializer<'_, W> {
     }
 }
 
+impl<W: Write> SerializeTupleStruct for SqlListSerializer<'_, W> {
+    type Error = SerializerError;
+    type Ok = ();
+
+    #[inline]
+    fn serialize_field<T>(&mut self, value: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    #[inline]
+    fn end(self) -> Result {
+        SerializeSeq::end(self)
+    }
+}
+
+// === SqlStructSerializer ===
+
+// Structs are written as named tuples, e.g. `CAST(tuple(42,'foo'), 'Tuple(`a` UInt32, `b` String)')`,
+// because ClickHouse infers only unnamed tuples from `tuple(..)`. Types of elements are inferred
+// by `TypeSerializer`. In params, names are defined by the type of the placeholder,
+// e.g. `{p: Tuple(a UInt32, b String)}`, so only values are written, e.g. `(42,'foo')`.
+struct SqlStructSerializer<'a, W> {
+    list: SqlListSerializer<'a, W>,
+    // Elements of the tuple type, e.g. "`a` UInt32, `b` String", or `None` in params.
+    elements: Option<String>,
+}
+
+impl<'a, W: Write> SqlStructSerializer<'a, W> {
+    fn new(writer: &'a mut W, in_param: bool) -> Result<Self> {
+        let opening = if in_param { "(" } else { "CAST(tuple(" };
+        Ok(Self {
+            list: SqlListSerializer::new(writer, opening, ')', in_param)?,
+            elements: (!in_param).then(String::new),
+        })
+    }
+}
+
+impl<W: Write> SerializeStruct for SqlStructSerializer<'_, W> {
+    type Error = SerializerError;
+    type Ok = ();
+
+    #[inline]
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        if let Some(elements) = &mut self.elements {
+            if !elements.is_empty() {
+                elements.push_str(", ");
+            }
+            escape::identifier(key, elements)?;
+            elements.push(' ');
+            elements.push_str(&value.serialize(TypeSerializer)?);
+        }
+
+        SerializeSeq::serialize_element(&mut self.list, value)
+    }
+
+    #[inline]
+    fn end(self) -> Result {
+        let writer = self.list.writer;
+        writer.write_char(')')?;
+
+        if let Some(elements) = self.elements {
+            writer.write_str(", ")?;
+            escape::string(&format!("Tuple({elements})"), writer)?;
+            writer.write_char(')')?;
+        }
+
+        Ok(())
+    }
+}
+
+// === TypeSerializer ===
+
+// Infers the ClickHouse type of a value written by `SqlSerializer`, e.g. `Array(Nullable(UInt32))`.
+// Types of `None` and items of empty arrays and maps are `Nothing`, e.g. `Nullable(Nothing)`,
+// unless they can be taken from other items.
+struct TypeSerializer;
+
+macro_rules! type_of {
+    ($($ser_method:ident($ty:ty) => $data_type:literal),* $(,)?) => {
+        $(
+            #[inline]
+            fn $ser_method(self, _v: $ty) -> Result<String> {
+                Ok($data_type.into())
+            }
+        )*
+    };
+}
+
+const NULLABLE_NOTHING: &str = "Nullable(Nothing)";
+
+// ClickHouse doesn't support nullable composite types, e.g. `Nullable(Array(UInt32))`.
+fn is_composite(data_type: &str) -> bool {
+    ["Array(", "Map(", "Tuple("]
+        .iter()
+        .any(|prefix| data_type.starts_with(prefix))
+}
+
+fn nullable(data_type: String) -> String {
+    if is_composite(&data_type) || data_type.starts_with("Nullable(") {
+        data_type
+    } else {
+        format!("Nullable({data_type})")
+    }
+}
+
+// Picks the first type without `Nothing`, e.g. `UInt32` for `[None, Some(42)]`,
+// which is made nullable if there are any `None` items.
+fn common_type(types: Vec<String>) -> Result<String> {
+    let has_null = types.iter().any(|ty| ty == NULLABLE_NOTHING);
+    let Some(idx) = types.iter().position(|ty| !ty.contains("Nothing")) else {
+        return Ok(types.into_iter().next().unwrap_or_else(|| "Nothing".into()));
+    };
+
+    let data_type = types.into_iter().nth(idx).unwrap();
+    if !has_null {
+        Ok(data_type)
+    } else if is_composite(&data_type) {
+        Err(SerializerError::Custom(format!(
+            "NULL cannot be used along with values of {data_type}, which can't be Nullable"
+        )))
+    } else {
+        Ok(nullable(data_type))
+    }
+}
+
+impl Serializer for TypeSerializer {
+    type Error = SerializerError;
+    type Ok = String;
+    type SerializeMap = TypeMapSerializer;
+    type SerializeSeq = TypeListSerializer;
+    type SerializeStruct = TypeListSerializer;
+    type SerializeStructVariant = ser::Impossible<String, SerializerError>;
+    type SerializeTuple = TypeListSerializer;
+    type SerializeTupleStruct = TypeListSerializer;
+    type SerializeTupleVariant = ser::Impossible<String, SerializerError>;
+
+    unsupported!(serialize_unit_struct(&'static str) -> Result<String>,);
+
+    type_of!(
+        serialize_bool(bool) => "Bool",
+        serialize_i8(i8) => "Int8",
+        serialize_i16(i16) => "Int16",
+        serialize_i32(i32) => "Int32",
+        serialize_i64(i64) => "Int64",
+        serialize_i128(i128) => "Int128",
+        serialize_u8(u8) => "UInt8",
+        serialize_u16(u16) => "UInt16",
+        serialize_u32(u32) => "UInt32",
+        serialize_u64(u64) => "UInt64",
+        serialize_u128(u128) => "UInt128",
+        serialize_f32(f32) => "Float32",
+        serialize_f64(f64) => "Float64",
+        serialize_char(char) => "String",
+        serialize_str(&str) => "String",
+        serialize_bytes(&[u8]) => "String",
+    );
+
+    #[inline]
+    fn serialize_unit(self) -> Result<String> {
+        Err(SerializerError::Unsupported("serialize_unit"))
+    }
+
+    #[inline]
+    fn serialize_none(self) -> Result<String> {
+        Ok(NULLABLE_NOTHING.into())
+    }
+
+    #[inline]
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String> {
+        value.serialize(self).map(nullable)
+    }
+
+    #[inline]
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<String> {
+        Ok("String".into())
+    }
+
+    #[inline]
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<String> {
+        if typed_literal::writer(name).is_some() {
+            let mut text = String::new();
+            value.serialize(ParamSerializer { writer: &mut text })?;
+            return Ok(typed_literal::data_type(name, &text));
+        }
+
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String> {
+        Err(SerializerError::Unsupported("serialize_newtype_variant"))
+    }
+
+    #[inline]
+    fn serialize_seq(self, _len: Option<usize>) -> Result<TypeListSerializer> {
+        Ok(TypeListSerializer::new(false))
+    }
+
+    #[inline]
+    fn serialize_tuple(self, _len: usize) -> Result<TypeListSerializer> {
+        Ok(TypeListSerializer::new(true))
+    }
+
+    #[inline]
+    fn serialize_map(self, _len: Option<usize>) -> Result<TypeMapSerializer> {
+        Ok(TypeMapSerializer::default())
+    }
+
+    #[inline]
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<TypeListSerializer> {
+        Ok(TypeListSerializer::new(true))
+    }
+
+    #[inline]
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(SerializerError::Unsupported("serialize_tuple_variant"))
+    }
+
+    #[inline]
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<TypeListSerializer> {
+        Ok(TypeListSerializer::new(true))
+    }
+
+    #[inline]
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(SerializerError::Unsupported("serialize_struct_variant"))
+    }
+
+    #[inline]
+    fn is_human_readable(&self) -> bool {
+        true
+    }
+}
+
+// === TypeListSerializer ===
+
+struct TypeListSerializer {
+    is_tuple: bool,
+    items: Vec<String>,
+}
+
+impl TypeListSerializer {
+    fn new(is_tuple: bool) -> Self {
+        Self {
+            is_tuple,
+            items: Vec::new(),
+        }
+    }
+}
+
+impl SerializeSeq for TypeListSerializer {
+    type Error = SerializerError;
+    type Ok = String;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        self.items.push(value.serialize(TypeSerializer)?);
+        Ok(())
+    }
+
+    #[inline]
+    fn end(self) -> Result<String> {
+        Ok(if self.is_tuple {
+            format!("Tuple({})", self.items.join(", "))
+        } else {
+            format!("Array({})", common_type(self.items)?)
+        })
+    }
+}
+
+impl SerializeTuple for TypeListSerializer {
+    type Error = SerializerError;
+    type Ok = String;
+
+    #[inline]
+    fn serialize_element<T>(&mut self, value: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<String> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for TypeListSerializer {
+    type Error = SerializerError;
+    type Ok = String;
+
+    #[inline]
+    fn serialize_field<T>(&mut self, value: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    #[inline]
+    fn end(self) -> Result<String> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeStruct for TypeListSerializer {
+    type Error = SerializerError;
+    type Ok = String;
+
+    #[inline]
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut element = String::new();
+        escape::identifier(key, &mut element)?;
+        element.push(' ');
+        element.push_str(&value.serialize(TypeSerializer)?);
+        self.items.push(element);
+        Ok(())
+    }
+
+    #[inline]
+    fn end(self) -> Result<String> {
+        SerializeSeq::end(self)
+    }
+}
+
+// === TypeMapSerializer ===
+
+#[derive(Default)]
+struct TypeMapSerializer {
+    keys: Vec<String>,
+    values: Vec<String>,
+}
+
+impl SerializeMap for TypeMapSerializer {
+    type Error = SerializerError;
+    type Ok = String;
+
+    #[inline]
+    fn serialize_key<T>(&mut self, key: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        self.keys.push(key.serialize(TypeSerializer)?);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_value<T>(&mut self, value: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        self.values.push(value.serialize(TypeSerializer)?);
+        Ok(())
+    }
+
+    #[inline]
+    fn end(self) -> Result<String> {
+        let key = common_type(self.keys)?;
+        let value = common_type(self.values)?;
+        Ok(format!("Map({key}, {value})"))
+    }
+}
+
+// === SqlMapSerializer ===
+
+struct SqlMapSerializer<'a, W> {
+    writer: &'a mut W,
+    has_items: bool,
+    in_param: bool,
+}
+
+impl<'a, W: Write> SqlMapSerializer<'a, W> {
+    fn new(writer: &'a mut W, in_param: bool) -> Result<Self> {
+        writer.write_str(if in_param { "{" } else { "map(" })?;
+        Ok(Self {
+            writer,
+            has_items: false,
+            in_param,
+        })
+    }
+}
+
+impl<W: Write> SerializeMap for SqlMapSerializer<'_, W> {
+    type Error = SerializerError;
+    type Ok = ();
+
+    #[inline]
+    fn serialize_key<T>(&mut self, key: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        if self.has_items {
+            self.writer.write_char(',')?;
+        }
+
+        self.has_items = true;
+
+        key.serialize(SqlSerializer {
+            writer: self.writer,
+            in_param: self.in_param,
+        })
+    }
+
+    #[inline]
+    fn serialize_value<T>(&mut self, value: &T) -> Result
+    where
+        T: Serialize + ?Sized,
+    {
+        self.writer
+            .write_char(if self.in_param { ':' } else { ',' })?;
+
+        value.serialize(SqlSerializer {
+            writer: self.writer,
+            in_param: self.in_param,
+        })
+    }
+
+    #[inline]
+    fn end(self) -> Result {
+        self.writer
+            .write_char(if self.in_param { '}' } else { ')' })?;
+        Ok(())
+    }
+}
+
 // === ParamSerializer ===
 
 struct ParamSerializer<'a, W> {
@@ -295,16 +893,15 @@ struct ParamSerializer<'a, W> {
 impl<'a, W: Write> Serializer for ParamSerializer<'a, W> {
     type Error = SerializerError;
     type Ok = ();
-    type SerializeMap = Impossible;
+    type SerializeMap = SqlMapSerializer<'a, W>;
     type SerializeSeq = SqlListSerializer<'a, W>;
-    type SerializeStruct = Impossible;
+    type SerializeStruct = SqlStructSerializer<'a, W>;
     type SerializeStructVariant = Impossible;
     type SerializeTuple = SqlListSerializer<'a, W>;
-    type SerializeTupleStruct = Impossible;
+    type SerializeTupleStruct = SqlListSerializer<'a, W>;
     type SerializeTupleVariant = Impossible;
 
     unsupported!(
-        serialize_map(Option<usize>) -> Result<Impossible>,
         serialize_bytes(&[u8]),
         serialize_unit,
         serialize_unit_struct(&'static str),
@@ -341,22 +938,17 @@ impl<'a, W: Write> Serializer for ParamSerializer<'a, W> {
 
     #[inline]
     fn serialize_seq(self, _len: Option<usize>) -> Result<SqlListSerializer<'a, W>> {
-        self.writer.write_char('[')?;
-        Ok(SqlListSerializer {
-            writer: self.writer,
-            has_items: false,
-            closing_char: ']',
-        })
+        SqlListSerializer::new(self.writer, "[", ']', true)
     }
 
     #[inline]
     fn serialize_tuple(self, _len: usize) -> Result<SqlListSerializer<'a, W>> {
-        self.writer.write_char('(')?;
-        Ok(SqlListSerializer {
-            writer: self.writer,
-            has_items: false,
-            closing_char: ')',
-        })
+        SqlListSerializer::new(self.writer, "(", ')', true)
+    }
+
+    #[inline]
+    fn serialize_map(self, _len: Option<usize>) -> Result<SqlMapSerializer<'a, W>> {
+        SqlMapSerializer::new(self.writer, true)
     }
 
     #[inline]
@@ -402,8 +994,12 @@ impl<'a, W: Write> Serializer for ParamSerializer<'a, W> {
     }
 
     #[inline]
-    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Impossible> {
-        Err(SerializerError::Unsupported("serialize_tuple_struct"))
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<SqlListSerializer<'a, W>> {
+        SqlListSerializer::new(self.writer, "(", ')', true)
     }
 
     #[inline]
@@ -418,8 +1014,12 @@ impl<'a, W: Write> Serializer for ParamSerializer<'a, W> {
     }
 
     #[inline]
-    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
-        Err(SerializerError::Unsupported("serialize_struct"))
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<SqlStructSerializer<'a, W>> {
+        SqlStructSerializer::new(self.writer, true)
     }
 
     #[inline]
@@ -443,7 +1043,10 @@ impl<'a, W: Write> Serializer for ParamSerializer<'a, W> {
 
 pub(crate) fn write_arg(writer: &mut impl Write, value: &impl Serialize) -> Result<(), String> {
     value
-        .serialize(SqlSerializer { writer })
+        .serialize(SqlSerializer {
+            writer,
+            in_param: false,
+        })
         .map_err(|err| err.to_string())
 }
 
@@ -532,6 +1135,126 @@ mod tests {
         assert_eq!(check(F(42.5)), "42.5");
     }
 
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn it_writes_decimals() {
+        use crate::serde::decimal::{d128, d256, d32, d64};
+        use rust_decimal::Decimal;
+
+        let value = Decimal::new(-12345, 3);
+        assert_eq!(check(d32::<2>::wrap(value)), "toDecimal32('-12.34', 2)");
+        assert_eq!(check(d64::<4>::wrap(value)), "toDecimal64('-12.3450', 4)");
+        assert_eq!(check(d128::<0>::wrap(value)), "toDecimal128('-12', 0)");
+        assert_eq!(check(d256::<1>::wrap(value)), "toDecimal256('-12.3', 1)");
+        assert_eq!(check(d64::<2>::wrap(None::<Decimal>)), "NULL");
+        assert_eq!(
+            check(vec![d32::<1>::wrap(Decimal::ONE)]),
+            "[toDecimal32('1.0', 1)]"
+        );
+
+        let mut out = String::new();
+        write_param(&mut out, &d64::<4>::wrap(value)).unwrap();
+        assert_eq!(out, "-12.3450");
+
+        let out = &mut String::new();
+        assert!(write_arg(out, &d32::<9>::wrap(Decimal::from(10))).is_err());
+    }
+
+    #[cfg(feature = "ethnum")]
+    #[test]
+    fn it_writes_int256() {
+        use crate::serde::{int256, uint256};
+        use ethnum::{I256, U256};
+
+        assert_eq!(check(int256::wrap(I256::new(42))), "42::Int256");
+        assert_eq!(
+            check(int256::wrap(I256::MIN)),
+            format!("{}::Int256", I256::MIN)
+        );
+        assert_eq!(
+            check(uint256::wrap(U256::MAX)),
+            format!("{}::UInt256", U256::MAX)
+        );
+        assert_eq!(
+            check(vec![Some(uint256::wrap(U256::ONE)), None]),
+            "[1::UInt256,NULL]"
+        );
+
+        let mut out = String::new();
+        write_param(&mut out, &int256::wrap(I256::MINUS_ONE)).unwrap();
+        assert_eq!(out, "-1");
+    }
+
+    #[cfg(feature = "geo")]
+    #[test]
+    fn it_writes_geo() {
+        use crate::serde::geo::{line_string, multi_polygon, point, polygon, ring};
+        use geo_types::{line_string, point, polygon, MultiPolygon};
+
+        let p = point!(x: 1.0, y: -2.5);
+        assert_eq!(check(point::wrap(&p)), "CAST((1,-2.5), 'Point')");
+
+        let line = line_string![(x: 0.0, y: 0.0), (x: 1.5, y: 1.0)];
+        assert_eq!(
+            check(line_string::wrap(&line)),
+            "CAST([(0,0),(1.5,1)], 'LineString')"
+        );
+        assert_eq!(check(ring::wrap(&line)), "CAST([(0,0),(1.5,1)], 'Ring')");
+
+        let poly = polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 0.0, y: 1.0)];
+        assert_eq!(
+            check(polygon::wrap(&poly)),
+            "CAST([[(0,0),(1,0),(0,1),(0,0)]], 'Polygon')"
+        );
+        assert_eq!(
+            check(multi_polygon::wrap(&MultiPolygon::new(vec![]))),
+            "CAST([], 'MultiPolygon')"
+        );
+
+        let mut out = String::new();
+        write_param(&mut out, &point::wrap(&p)).unwrap();
+        assert_eq!(out, "(1,-2.5)");
+    }
+
+    #[test]
+    fn it_writes_ip_addrs() {
+        use crate::serde::{ip, ipv4};
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        let v4 = Ipv4Addr::new(10, 0, 0, 1);
+        let v6 = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        assert_eq!(check(v4), "'10.0.0.1'");
+        assert_eq!(check(ipv4::wrap(v4)), "toIPv4('10.0.0.1')");
+        assert_eq!(check(ip::wrap(IpAddr::V4(v4))), "toIPv6('::ffff:10.0.0.1')");
+        assert_eq!(check(ip::wrap(IpAddr::V6(v6))), "toIPv6('2001:db8::1')");
+        assert_eq!(
+            check(vec![Some(ip::wrap(v4.into())), None]),
+            "[toIPv6('::ffff:10.0.0.1'),NULL]"
+        );
+
+        let mut out = String::new();
+        write_param(&mut out, &ipv4::wrap(v4)).unwrap();
+        assert_eq!(out, "10.0.0.1");
+
+        let mut out = String::new();
+        write_param(&mut out, &ip::wrap(v4.into())).unwrap();
+        assert_eq!(out, "::ffff:10.0.0.1");
+    }
+
+    #[cfg(feature = "ipnet")]
+    #[test]
+    fn it_writes_ip_nets() {
+        use crate::serde::ipnet;
+
+        #[derive(Serialize)]
+        struct Wrapped(#[serde(with = "ipnet")] ::ipnet::IpNet);
+
+        let net = "10.1.0.0/16".parse().unwrap();
+        assert_eq!(check(Wrapped(net)), "(toIPv6('::ffff:10.1.0.0'),112)");
+        let net = "2001:db8::/32".parse().unwrap();
+        assert_eq!(check(Wrapped(net)), "(toIPv6('2001:db8::'),32)");
+    }
+
     #[test]
     fn it_writes_arrays() {
         assert_eq!(check(&[42, 43][..]), "[42,43]");
@@ -543,6 +1266,146 @@ mod tests {
         assert_eq!(check((42, 43)), "(42,43)");
     }
 
+    #[test]
+    fn it_writes_maps() {
+        use std::collections::BTreeMap;
+
+        assert_eq!(check(BTreeMap::<u32, u32>::new()), "map()");
+        assert_eq!(
+            check(BTreeMap::from([("a", "b'c"), ("d", "e")])),
+            "map('a','b\\'c','d','e')"
+        );
+        assert_eq!(
+            check(BTreeMap::from([(1, vec![Some(2)]), (3, vec![None])])),
+            "map(1,[2],3,[NULL])"
+        );
+
+        let mut out = String::new();
+        let value = vec![BTreeMap::from([("a", BTreeMap::from([(1, "b")]))])];
+        write_param(&mut out, &value).unwrap();
+        assert_eq!(out, "[{'a':{1:'b'}}]");
+
+        let mut out = String::new();
+        write_param(&mut out, &BTreeMap::from([("a", 1), ("b", 2)])).unwrap();
+        assert_eq!(out, "{'a':1,'b':2}");
+    }
+
+    #[test]
+    fn it_writes_structs() {
+        #[derive(Serialize)]
+        struct Struct<'a> {
+            a: u32,
+            b: &'a str,
+        }
+        #[derive(Serialize)]
+        struct Single {
+            a: u32,
+        }
+        #[derive(Serialize)]
+        struct TupleStruct(u32, Single);
+
+        assert_eq!(
+            check(Struct { a: 42, b: "foo" }),
+            r"CAST(tuple(42,'foo'), 'Tuple(\`a\` UInt32, \`b\` String)')"
+        );
+        assert_eq!(
+            check(Single { a: 42 }),
+            r"CAST(tuple(42), 'Tuple(\`a\` UInt32)')"
+        );
+        assert_eq!(
+            check(vec![TupleStruct(1, Single { a: 2 })]),
+            r"[tuple(1,CAST(tuple(2), 'Tuple(\`a\` UInt32)'))]"
+        );
+
+        let mut out = String::new();
+        write_param(&mut out, &Struct { a: 42, b: "foo" }).unwrap();
+        assert_eq!(out, "(42,'foo')");
+
+        let mut out = String::new();
+        write_param(&mut out, &vec![TupleStruct(1, Single { a: 2 })]).unwrap();
+        assert_eq!(out, "[(1,(2))]");
+    }
+
+    #[test]
+    fn it_infers_types_of_struct_fields() {
+        use std::collections::BTreeMap;
+
+        fn data_type(v: impl Serialize) -> String {
+            v.serialize(TypeSerializer).unwrap()
+        }
+
+        #[derive(Serialize)]
+        enum Enum {
+            A,
+        }
+        #[derive(Serialize)]
+        struct Inner {
+            #[serde(rename = "x y")]
+            x: Option<i8>,
+        }
+        #[derive(Serialize)]
+        struct Outer {
+            inner: Vec<Inner>,
+            labels: BTreeMap<&'static str, Option<Vec<f64>>>,
+        }
+
+        assert_eq!(data_type(42u32), "UInt32");
+        assert_eq!(data_type(-42i128), "Int128");
+        assert_eq!(data_type(true), "Bool");
+        assert_eq!(data_type('a'), "String");
+        assert_eq!(data_type(Enum::A), "String");
+        assert_eq!(data_type(None::<u8>), "Nullable(Nothing)");
+        assert_eq!(data_type(Some(42u8)), "Nullable(UInt8)");
+        assert_eq!(data_type(Some(vec![42u8])), "Array(UInt8)");
+        assert_eq!(data_type(Vec::<u8>::new()), "Array(Nothing)");
+        assert_eq!(data_type(vec![None, Some(1u16)]), "Array(Nullable(UInt16))");
+        assert_eq!(
+            data_type(vec![vec![], vec![Some(1u16)]]),
+            "Array(Array(Nullable(UInt16)))"
+        );
+        assert_eq!(data_type((1u8, "a")), "Tuple(UInt8, String)");
+        assert_eq!(
+            data_type(Outer {
+                inner: vec![Inner { x: None }, Inner { x: Some(1) }],
+                labels: BTreeMap::from([("a", Some(vec![])), ("b", Some(vec![1.5]))]),
+            }),
+            "Tuple(`inner` Array(Tuple(`x y` Nullable(Int8))), \
+             `labels` Map(String, Array(Float64)))"
+        );
+
+        // Composite types can't be `Nullable`.
+        let labels = BTreeMap::from([("a", None), ("b", Some(vec![1.5]))]);
+        assert!(labels.serialize(TypeSerializer).is_err());
+        assert!(vec![None, Some((1u8, "a"))]
+            .serialize(TypeSerializer)
+            .is_err());
+
+        assert!(().serialize(TypeSerializer).is_err());
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn it_infers_types_of_typed_literals() {
+        use crate::serde::{decimal::d64, ipv4};
+        use rust_decimal::Decimal;
+        use std::net::Ipv4Addr;
+
+        #[derive(Serialize)]
+        struct Struct<P, A> {
+            price: P,
+            addr: A,
+        }
+
+        let value = Struct {
+            price: d64::<2>::wrap(Decimal::new(150, 2)),
+            addr: ipv4::wrap(Ipv4Addr::LOCALHOST),
+        };
+        assert_eq!(
+            check(value),
+            r"CAST(tuple(toDecimal64('1.50', 2),toIPv4('127.0.0.1')), 'Tuple(\`price\` Decimal64(2), \`addr\` IPv4)')"
+        );
+    }
+
     #[test]
     fn it_writes_options() {
         assert_eq!(check(None::<i32>), "NULL");
@@ -553,23 +1416,12 @@ mod tests {
     #[test]
     fn it_fails_on_unsupported() {
         let mut out = String::new();
-        assert!(write_arg(&mut out, &std::collections::HashMap::<u32, u32>::new()).is_err());
         assert!(write_arg(&mut out, &()).is_err());
 
         #[derive(Serialize)]
         struct Unit;
         assert!(write_arg(&mut out, &Unit).is_err());
 
-        #[derive(Serialize)]
-        struct Struct {
-            a: u32,
-        }
-        assert!(write_arg(&mut out, &Struct { a: 42 }).is_err());
-
-        #[derive(Serialize)]
-        struct TupleStruct(u32, u32);
-        assert!(write_arg(&mut out, &TupleStruct(42, 42)).is_err());
-
         #[derive(Serialize)]
         enum Enum {
             Newtype(u32),
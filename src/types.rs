@@ -0,0 +1,23 @@
+//! ClickHouse data types, as reported by the server in `RowBinaryWithNamesAndTypes` headers.
+//!
+//! [`DataTypeNode::new`] parses a type definition, and its `Display` implementation renders
+//! it back in the canonical form, so that `DataTypeNode::new(&t.to_string())` returns `t`.
+//!
+//! ```
+//! use clickhouse::types::DataTypeNode;
+//!
+//! let data_type = DataTypeNode::new("Array(Nullable(DateTime64(3, 'UTC')))").unwrap();
+//! assert!(matches!(data_type, DataTypeNode::Array(_)));
+//! assert_eq!(data_type.to_string(), "Array(Nullable(DateTime64(3, 'UTC')))");
+//! ```
+//!
+//! Unlike the `clickhouse-types` crate, which is an implementation detail,
+//! this module is a part of the public API and follows semver.
+//! [`DataTypeNode`] is `#[non_exhaustive]`, so new types can be added in minor releases.
+
+pub use clickhouse_types::{
+    data_types::{
+        Column, DataTypeNode, DateTimePrecision, DecimalType, EnumType, IntervalKind, JsonType,
+    },
+    error::TypesError,
+};
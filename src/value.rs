@@ -0,0 +1,531 @@
+//! Contains [`Value`], a dynamically typed ClickHouse value.
+
+use std::{
+    fmt,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
+    ser::{self, Serialize, SerializeMap, SerializeTuple, Serializer},
+};
+
+use crate::rowbinary::dynamic;
+
+/// A dynamically typed value, used to read and write `Dynamic` and `JSON` columns.
+///
+/// In `RowBinary`, `Value` is (de)serialized as `Dynamic`:
+/// ```
+/// use clickhouse::value::Value;
+///
+/// #[derive(clickhouse::Row, serde::Serialize, serde::Deserialize)]
+/// struct MyRow {
+///     dynamic: Value,
+///     #[serde(with = "clickhouse::serde::json")]
+///     json: Value, // always `Value::Json`
+/// }
+/// ```
+///
+/// When decoding, the following ClickHouse types have no variants of their own:
+/// * `LowCardinality(T)`, `Nullable(T)` and `Variant(..)` are decoded as their inner values.
+/// * `Enum8` and `Enum16` are decoded as [`Value::String`] with the name of the variant.
+/// * Geo types are decoded as arrays of [`Value::Tuple`] points.
+/// * Time zones of `DateTime` and `DateTime64` are not preserved.
+/// * Other types (decimals, `(U)Int256`, `FixedString(N)`, etc.) are decoded as [`Value::Other`].
+///
+/// When encoding, the ClickHouse type is inferred from the value. Elements of
+/// [`Value::Array`] and [`Value::Map`] having different types are encoded as `Dynamic`,
+/// and `NULL` among them turns the type of elements into `Nullable(T)`.
+///
+/// In human-readable formats (e.g. when binding a value in [`crate::query::Query::bind`]
+/// or serializing it with `serde_json`), `Value` is serialized as a plain value;
+/// [`Value::Json`] becomes an object with paths split on dots.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Value {
+    /// `NULL`.
+    Null,
+    /// `Bool`.
+    Bool(bool),
+    /// `UInt8`.
+    UInt8(u8),
+    /// `UInt16`.
+    UInt16(u16),
+    /// `UInt32`.
+    UInt32(u32),
+    /// `UInt64`.
+    UInt64(u64),
+    /// `UInt128`.
+    UInt128(u128),
+    /// `Int8`.
+    Int8(i8),
+    /// `Int16`.
+    Int16(i16),
+    /// `Int32`.
+    Int32(i32),
+    /// `Int64`.
+    Int64(i64),
+    /// `Int128`.
+    Int128(i128),
+    /// `Float32`.
+    Float32(f32),
+    /// `Float64`.
+    Float64(f64),
+    /// `String`.
+    String(String),
+    /// `UUID`, the same as `uuid::Uuid::as_u128()`.
+    UUID(u128),
+    /// `IPv4`.
+    IPv4(Ipv4Addr),
+    /// `IPv6`.
+    IPv6(Ipv6Addr),
+    /// `Date`, days since the Unix epoch.
+    Date(u16),
+    /// `Date32`, days since the Unix epoch.
+    Date32(i32),
+    /// `DateTime`, seconds since the Unix epoch.
+    DateTime(u32),
+    /// `DateTime64(P)`, ticks since the Unix epoch and the precision `P`.
+    DateTime64(i64, u8),
+    /// `Array(T)`.
+    Array(Vec<Value>),
+    /// `Tuple(T1, T2, ...)`.
+    Tuple(Vec<Value>),
+    /// `Map(K, V)`.
+    Map(Vec<(Value, Value)>),
+    /// `JSON`, a list of paths (e.g. `a.b.c`) and their values.
+    Json(Vec<(String, Value)>),
+    /// A value of any other type: the type name and the value encoded in `RowBinary`.
+    Other(String, Vec<u8>),
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serialize_readable(self, serializer);
+        }
+
+        let encoded = dynamic::encode_dynamic(self).map_err(ser::Error::custom)?;
+        serialize_raw(&encoded, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ReadableVisitor)
+        } else {
+            deserializer.deserialize_newtype_struct(dynamic::DYNAMIC, BinaryVisitor { json: false })
+        }
+    }
+}
+
+/// Serializes paths of [`Value::Json`]. Used by [`crate::serde::json`].
+pub(crate) fn serialize_json<S: Serializer>(
+    paths: &[(String, Value)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        return JsonObject::new(paths).serialize(serializer);
+    }
+
+    let encoded = dynamic::encode_json(paths).map_err(ser::Error::custom)?;
+    serialize_raw(&encoded, serializer)
+}
+
+/// Deserializes a `JSON` value. Used by [`crate::serde::json`].
+pub(crate) fn deserialize_json<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Value, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(ReadableVisitor)
+    } else {
+        deserializer.deserialize_newtype_struct(dynamic::JSON, BinaryVisitor { json: true })
+    }
+}
+
+/// Writes already encoded data as is.
+fn serialize_raw<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(data.len())?;
+    for byte in data {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+fn serialize_readable<S: Serializer>(value: &Value, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Value::Null => serializer.serialize_none(),
+        Value::Bool(v) => serializer.serialize_bool(*v),
+        Value::UInt8(v) => serializer.serialize_u8(*v),
+        Value::UInt16(v) => serializer.serialize_u16(*v),
+        Value::UInt32(v) => serializer.serialize_u32(*v),
+        Value::UInt64(v) => serializer.serialize_u64(*v),
+        Value::UInt128(v) => serializer.serialize_u128(*v),
+        Value::Int8(v) => serializer.serialize_i8(*v),
+        Value::Int16(v) => serializer.serialize_i16(*v),
+        Value::Int32(v) => serializer.serialize_i32(*v),
+        Value::Int64(v) => serializer.serialize_i64(*v),
+        Value::Int128(v) => serializer.serialize_i128(*v),
+        Value::Float32(v) => serializer.serialize_f32(*v),
+        Value::Float64(v) => serializer.serialize_f64(*v),
+        Value::String(v) => serializer.serialize_str(v),
+        Value::UUID(v) => serializer.collect_str(&format_args!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            v >> 96,
+            (v >> 80) & 0xFFFF,
+            (v >> 64) & 0xFFFF,
+            (v >> 48) & 0xFFFF,
+            v & 0xFFFF_FFFF_FFFF
+        )),
+        Value::IPv4(v) => serializer.collect_str(v),
+        Value::IPv6(v) => serializer.collect_str(v),
+        Value::Date(v) => {
+            serializer.collect_str(&DateTime::new(i64::from(*v) * 86400, 0, 0, false))
+        }
+        Value::Date32(v) => {
+            serializer.collect_str(&DateTime::new(i64::from(*v) * 86400, 0, 0, false))
+        }
+        Value::DateTime(v) => serializer.collect_str(&DateTime::new(i64::from(*v), 0, 0, true)),
+        Value::DateTime64(ticks, precision) => {
+            let divisor = 10i64.pow(u32::from(*precision).min(9));
+            let seconds = ticks.div_euclid(divisor);
+            let fraction = ticks.rem_euclid(divisor) as u64;
+            serializer.collect_str(&DateTime::new(seconds, fraction, *precision, true))
+        }
+        Value::Array(values) | Value::Tuple(values) => serializer.collect_seq(values),
+        Value::Map(entries) => serializer.collect_map(entries.iter().map(|(k, v)| (k, v))),
+        Value::Json(paths) => JsonObject::new(paths).serialize(serializer),
+        Value::Other(type_name, _) => Err(ser::Error::custom(format!(
+            "{type_name} values cannot be serialized in human-readable formats"
+        ))),
+    }
+}
+
+/// Nests `JSON` paths, so that `{"a.b": 1, "a.c": 2}` becomes `{"a": {"b": 1, "c": 2}}`.
+struct JsonObject<'a>(Vec<(&'a str, &'a Value)>);
+
+impl<'a> JsonObject<'a> {
+    fn new(paths: &'a [(String, Value)]) -> Self {
+        Self(
+            paths
+                .iter()
+                .map(|(path, value)| (path.as_str(), value))
+                .collect(),
+        )
+    }
+}
+
+impl Serialize for JsonObject<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        let mut processed = vec![false; self.0.len()];
+
+        for (idx, (path, value)) in self.0.iter().enumerate() {
+            if processed[idx] {
+                continue;
+            }
+            let Some((head, _)) = path.split_once('.') else {
+                map.serialize_entry(path, value)?;
+                continue;
+            };
+
+            // Collect all paths with the same first segment.
+            let mut nested = Vec::new();
+            for (other_idx, (other, value)) in self.0.iter().enumerate().skip(idx) {
+                if let Some((other_head, rest)) = other.split_once('.') {
+                    if other_head == head {
+                        processed[other_idx] = true;
+                        nested.push((rest, *value));
+                    }
+                }
+            }
+            map.serialize_entry(head, &JsonObject(nested))?;
+        }
+
+        map.end()
+    }
+}
+
+/// Formats dates as `YYYY-MM-DD` and date times as `YYYY-MM-DD hh:mm:ss[.fff]` in UTC.
+struct DateTime {
+    seconds: i64,
+    fraction: u64,
+    precision: u8,
+    with_time: bool,
+}
+
+impl DateTime {
+    fn new(seconds: i64, fraction: u64, precision: u8, with_time: bool) -> Self {
+        Self {
+            seconds,
+            fraction,
+            precision,
+            with_time,
+        }
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = self.seconds.div_euclid(86400);
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        write!(f, "{year:04}-{month:02}-{day:02}")?;
+
+        if self.with_time {
+            let time = self.seconds.rem_euclid(86400);
+            let (h, m, s) = (time / 3600, time / 60 % 60, time % 60);
+            write!(f, " {h:02}:{m:02}:{s:02}")?;
+            if self.precision > 0 {
+                write!(
+                    f,
+                    ".{:0width$}",
+                    self.fraction,
+                    width = self.precision as usize
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes values provided by `RowBinaryDeserializer` for the `Dynamic` and `JSON` markers.
+struct BinaryVisitor {
+    json: bool,
+}
+
+impl Visitor<'_> for BinaryVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.json {
+            formatter.write_str("a JSON value")
+        } else {
+            formatter.write_str("a Dynamic value")
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, mut input: &[u8]) -> Result<Value, E> {
+        let value = if self.json {
+            dynamic::decode_json(&mut input, None).map(Value::Json)
+        } else {
+            dynamic::decode_dynamic(&mut input)
+        };
+
+        let value = value.map_err(E::custom)?;
+        if !input.is_empty() {
+            return Err(E::custom("unexpected trailing data"));
+        }
+        Ok(value)
+    }
+}
+
+/// Deserializes values of human-readable formats, e.g. JSON.
+struct ReadableVisitor;
+
+impl<'de> Visitor<'de> for ReadableVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::Int64(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Value, E> {
+        Ok(Value::Int128(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+        Ok(match i64::try_from(v) {
+            Ok(v) => Value::Int64(v),
+            Err(_) => Value::UInt64(v),
+        })
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Value, E> {
+        Ok(Value::UInt128(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::Float64(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut paths = Vec::new();
+        while let Some((key, value)) = map.next_entry()? {
+            flatten_into(&mut paths, key, value);
+        }
+        Ok(Value::Json(paths))
+    }
+}
+
+/// Nested objects are flattened into paths, e.g. `{"a": {"b": 1}}` becomes `{"a.b": 1}`.
+fn flatten_into(paths: &mut Vec<(String, Value)>, key: String, value: Value) {
+    match value {
+        Value::Json(nested) => {
+            for (path, value) in nested {
+                paths.push((format!("{key}.{path}"), value));
+            }
+        }
+        value => paths.push((key, value)),
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Value> for Value {
+    /// Converts a JSON value, flattening nested objects into paths.
+    fn from(value: serde_json::Value) -> Self {
+        use serde_json::Value as Json;
+
+        match value {
+            Json::Null => Value::Null,
+            Json::Bool(v) => Value::Bool(v),
+            Json::Number(v) => {
+                if let Some(v) = v.as_i64() {
+                    Value::Int64(v)
+                } else if let Some(v) = v.as_u64() {
+                    Value::UInt64(v)
+                } else {
+                    Value::Float64(v.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            Json::String(v) => Value::String(v),
+            Json::Array(values) => Value::Array(values.into_iter().map(Value::from).collect()),
+            Json::Object(object) => {
+                let mut paths = Vec::with_capacity(object.len());
+                for (key, value) in object {
+                    flatten_into(&mut paths, key, Value::from(value));
+                }
+                Value::Json(paths)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl TryFrom<Value> for serde_json::Value {
+    type Error = crate::error::Error;
+
+    /// Converts a value the same way as it's serialized in human-readable formats.
+    /// Fails on [`Value::Other`] and on integers not fitting into `i64` or `u64`.
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        serde_json::to_value(value).map_err(|err| crate::error::Error::Custom(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_json(value: &Value) -> String {
+        serde_json::to_string(value).unwrap()
+    }
+
+    #[test]
+    fn it_serializes_readable() {
+        assert_eq!(to_json(&Value::Null), "null");
+        assert_eq!(to_json(&Value::UInt8(42)), "42");
+        assert_eq!(
+            to_json(&Value::UUID(0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10)),
+            r#""01020304-0506-0708-090a-0b0c0d0e0f10""#
+        );
+        assert_eq!(to_json(&Value::Date(19_000)), r#""2022-01-08""#);
+        assert_eq!(to_json(&Value::Date32(-1)), r#""1969-12-31""#);
+        assert_eq!(
+            to_json(&Value::DateTime(1_700_000_000)),
+            r#""2023-11-14 22:13:20""#
+        );
+        assert_eq!(
+            to_json(&Value::DateTime64(-1, 3)),
+            r#""1969-12-31 23:59:59.999""#
+        );
+        assert_eq!(
+            to_json(&Value::Json(vec![
+                ("a.b".into(), Value::UInt8(1)),
+                ("c".into(), Value::Array(vec![Value::Bool(true)])),
+                ("a.d.e".into(), Value::String("x".into())),
+            ])),
+            r#"{"a":{"b":1,"d":{"e":"x"}},"c":[true]}"#
+        );
+        assert!(serde_json::to_string(&Value::Other("Int256".into(), vec![0; 32])).is_err());
+    }
+
+    #[test]
+    fn it_deserializes_readable() {
+        let value: Value = serde_json::from_str(r#"{"a":{"b":1,"c":[null,"x"]},"d":1.5}"#).unwrap();
+        assert_eq!(
+            value,
+            Value::Json(vec![
+                ("a.b".into(), Value::Int64(1)),
+                (
+                    "a.c".into(),
+                    Value::Array(vec![Value::Null, Value::String("x".into())])
+                ),
+                ("d".into(), Value::Float64(1.5)),
+            ])
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn it_converts_serde_json() {
+        let json = serde_json::json!({"a": {"b": [1, "x"]}, "c": null});
+        let value = Value::from(json.clone());
+        assert_eq!(
+            value,
+            Value::Json(vec![
+                (
+                    "a.b".into(),
+                    Value::Array(vec![Value::Int64(1), Value::String("x".into())])
+                ),
+                ("c".into(), Value::Null),
+            ])
+        );
+        assert_eq!(serde_json::Value::try_from(value).unwrap(), json);
+        assert!(serde_json::Value::try_from(Value::UInt128(u128::MAX)).is_err());
+    }
+}
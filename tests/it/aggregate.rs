@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use clickhouse::{
+    aggregate::{
+        ArgMaxState, AvgState, CountState, GroupArrayState, MaxState, MinState, SumState,
+        UniqExactState,
+    },
+    Row,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+struct MyRow {
+    id: u32,
+    count: CountState,
+    sum: SumState<u64>,
+    min: MinState<String>,
+    max: MaxState<f64>,
+    avg: AvgState<i64>,
+    uniq: UniqExactState<u64>,
+    group: GroupArrayState<String>,
+    arg_max: ArgMaxState<String, u32>,
+}
+
+#[tokio::test]
+async fn states_roundtrip() {
+    let client = prepare_database!();
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                id      UInt32,
+                count   AggregateFunction(count),
+                sum     AggregateFunction(sum, UInt32),
+                min     AggregateFunction(min, String),
+                max     AggregateFunction(max, Float64),
+                avg     AggregateFunction(avg, Int16),
+                uniq    AggregateFunction(uniqExact, UInt64),
+                group   AggregateFunction(groupArray, String),
+                arg_max AggregateFunction(argMax, String, UInt32)
+            ) ENGINE = AggregatingMergeTree ORDER BY id
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let rows = vec![
+        MyRow {
+            id: 1,
+            count: CountState(3),
+            sum: SumState(300),
+            min: MinState(Some("bar".into())),
+            max: MaxState(Some(2.5)),
+            avg: AvgState {
+                numerator: -6,
+                denominator: 3,
+            },
+            uniq: UniqExactState(vec![7]),
+            group: GroupArrayState(vec!["foo".into(), "bar".into(), "baz".into()]),
+            arg_max: ArgMaxState {
+                result: Some("baz".into()),
+                value: Some(500),
+            },
+        },
+        MyRow {
+            id: 2,
+            count: CountState(0),
+            sum: SumState(0),
+            min: MinState(None),
+            max: MaxState(None),
+            avg: AvgState::default(),
+            uniq: UniqExactState(vec![]),
+            group: GroupArrayState(vec![]),
+            arg_max: ArgMaxState::default(),
+        },
+    ];
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    for row in &rows {
+        insert.write(row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let fetched = client
+        .query("SELECT ?fields FROM test ORDER BY id")
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+    assert_eq!(fetched, rows);
+
+    // Inserted states are merged by the server.
+    #[derive(Debug, PartialEq, Deserialize, Row)]
+    struct Merged {
+        count: u64,
+        sum: u64,
+        min: String,
+        avg: f64,
+        group: Vec<String>,
+        arg_max: String,
+    }
+
+    let merged = client
+        .query(
+            "
+            SELECT
+                countMerge(count)        AS count,
+                sumMerge(sum)            AS sum,
+                minMerge(min)            AS min,
+                avgMerge(avg)            AS avg,
+                groupArrayMerge(group)   AS group,
+                argMaxMerge(arg_max)     AS arg_max
+            FROM test WHERE id = 1
+            ",
+        )
+        .fetch_one::<Merged>()
+        .await
+        .unwrap();
+    assert_eq!(
+        merged,
+        Merged {
+            count: 3,
+            sum: 300,
+            min: "bar".into(),
+            avg: -2.0,
+            group: vec!["foo".into(), "bar".into(), "baz".into()],
+            arg_max: "baz".into(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn states_produced_by_server() {
+    let client = prepare_database!();
+
+    let mut fetched = client
+        .query(
+            "
+            SELECT
+                1 :: UInt32 AS id,
+                countState() AS count,
+                sumState(number :: UInt32) AS sum,
+                minState(toString(number)) AS min,
+                maxState(number / 2) AS max,
+                avgState(-(number :: Int16)) AS avg,
+                uniqExactState(number % 2) AS uniq,
+                groupArrayState(toString(number)) AS group,
+                argMaxState(toString(number), number :: UInt32) AS arg_max
+            FROM numbers(4)
+            ",
+        )
+        .fetch_one::<MyRow>()
+        .await
+        .unwrap();
+
+    fetched.uniq.0.sort();
+    assert_eq!(
+        fetched,
+        MyRow {
+            id: 1,
+            count: CountState(4),
+            sum: SumState(6),
+            min: MinState(Some("0".into())),
+            max: MaxState(Some(1.5)),
+            avg: AvgState {
+                numerator: -6,
+                denominator: 4,
+            },
+            uniq: UniqExactState(vec![0, 1]),
+            group: GroupArrayState(vec!["0".into(), "1".into(), "2".into(), "3".into()]),
+            arg_max: ArgMaxState {
+                result: Some("3".into()),
+                value: Some(3),
+            },
+        }
+    );
+}
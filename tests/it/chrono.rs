@@ -519,3 +519,177 @@ async fn time64_negative_roundtrip() {
 
     assert_eq!(fetched, negative_row);
 }
+
+#[tokio::test]
+async fn time_of_day_roundtrip() {
+    let client = prepare_database!();
+
+    client
+        .query(
+            r#"
+            CREATE TABLE test_time_of_day (
+                t        Time,
+                t_opt    Nullable(Time),
+                t64s     Time64(0),
+                t64s_opt Nullable(Time64(0)),
+                t64ms    Time64(3),
+                t64us    Time64(6),
+                t64ns    Time64(9),
+                t64ns_opt Nullable(Time64(9))
+            ) ENGINE = MergeTree ORDER BY tuple()
+            SETTINGS enable_time_time64_type = 1;
+            "#,
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct TimeRow {
+        #[serde(with = "clickhouse::serde::chrono::time_of_day")]
+        t: NaiveTime,
+        #[serde(with = "clickhouse::serde::chrono::time_of_day::option")]
+        t_opt: Option<NaiveTime>,
+        #[serde(with = "clickhouse::serde::chrono::time_of_day::secs")]
+        t64s: NaiveTime,
+        #[serde(with = "clickhouse::serde::chrono::time_of_day::secs::option")]
+        t64s_opt: Option<NaiveTime>,
+        #[serde(with = "clickhouse::serde::chrono::time_of_day::millis")]
+        t64ms: NaiveTime,
+        #[serde(with = "clickhouse::serde::chrono::time_of_day::micros")]
+        t64us: NaiveTime,
+        #[serde(with = "clickhouse::serde::chrono::time_of_day::nanos")]
+        t64ns: NaiveTime,
+        #[serde(with = "clickhouse::serde::chrono::time_of_day::nanos::option")]
+        t64ns_opt: Option<NaiveTime>,
+    }
+
+    let row = TimeRow {
+        t: NaiveTime::from_hms_opt(12, 34, 56).unwrap(),
+        t_opt: None,
+        t64s: NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+        t64s_opt: Some(NaiveTime::from_hms_opt(23, 59, 59).unwrap()),
+        t64ms: NaiveTime::from_hms_milli_opt(1, 2, 3, 456).unwrap(),
+        t64us: NaiveTime::from_hms_micro_opt(1, 2, 3, 456_789).unwrap(),
+        t64ns: NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+        t64ns_opt: Some(NaiveTime::from_hms_nano_opt(0, 0, 0, 1).unwrap()),
+    };
+
+    let mut insert = client.insert::<TimeRow>("test_time_of_day").unwrap();
+    insert.write(&row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let fetched = client
+        .query("SELECT ?fields FROM test_time_of_day")
+        .fetch_one::<TimeRow>()
+        .await
+        .unwrap();
+    assert_eq!(fetched, row);
+
+    let as_str = client
+        .query("SELECT toString(t64ms) FROM test_time_of_day")
+        .fetch_one::<String>()
+        .await
+        .unwrap();
+    assert_eq!(as_str, "01:02:03.456");
+
+    // Values out of a day cannot be represented as `NaiveTime`.
+    #[derive(Debug, Deserialize, Row)]
+    struct Data {
+        #[serde(with = "clickhouse::serde::chrono::time_of_day")]
+        #[allow(dead_code)]
+        t: NaiveTime,
+    }
+
+    let err = client
+        .query("SELECT '-01:00:00'::Time AS t")
+        .with_option("enable_time_time64_type", "1")
+        .fetch_one::<Data>()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+}
+
+#[cfg(feature = "chrono-tz")]
+#[tokio::test]
+async fn datetime_tz() {
+    use chrono::TimeZone;
+    use chrono_tz::Tz;
+
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::chrono::datetime_tz")]
+        dt: DateTime<Tz>,
+        #[serde(with = "clickhouse::serde::chrono::datetime_tz::option")]
+        dt_opt: Option<DateTime<Tz>>,
+        #[serde(with = "clickhouse::serde::chrono::datetime64_tz::secs")]
+        dt64s: DateTime<Tz>,
+        #[serde(with = "clickhouse::serde::chrono::datetime64_tz::millis")]
+        dt64ms: DateTime<Tz>,
+        #[serde(with = "clickhouse::serde::chrono::datetime64_tz::micros::option")]
+        dt64us_opt: Option<DateTime<Tz>>,
+        #[serde(with = "clickhouse::serde::chrono::datetime64_tz::nanos")]
+        dt64ns: DateTime<Tz>,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                dt          DateTime('Asia/Tokyo'),
+                dt_opt      Nullable(DateTime),
+                dt64s       DateTime64(0, 'Europe/Berlin'),
+                dt64ms      DateTime64(3, 'America/New_York'),
+                dt64us_opt  Nullable(DateTime64(6, 'Asia/Kolkata')),
+                dt64ns      DateTime64(9, 'Australia/Sydney')
+            )
+            ENGINE = MergeTree ORDER BY dt
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    // Written values are converted to UTC, their time zones don't matter.
+    let moscow = chrono_tz::Europe::Moscow;
+    let dt_s = moscow.timestamp_opt(1_668_353_262, 0).unwrap(); // 2022-11-13 15:27:42 UTC
+    let dt_ms = moscow.timestamp_opt(1_668_353_262, 123_000_000).unwrap();
+    let dt_us = moscow.timestamp_opt(1_668_353_262, 123_456_000).unwrap();
+    let dt_ns = moscow.timestamp_opt(1_668_353_262, 123_456_789).unwrap();
+
+    let original_row = MyRow {
+        dt: dt_s,
+        dt_opt: Some(dt_s),
+        dt64s: dt_s,
+        dt64ms: dt_ms,
+        dt64us_opt: Some(dt_us),
+        dt64ns: dt_ns,
+    };
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    insert.write(&original_row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let (row, dt_str, dt64ms_str) = client
+        .query("SELECT ?fields, toString(dt), toString(dt64ms) FROM test")
+        .fetch_one::<(MyRow, String, String)>()
+        .await
+        .unwrap();
+
+    // `DateTime<Tz>` values are equal if they're the same instant.
+    assert_eq!(row, original_row);
+
+    // Read values are in time zones of the columns, UTC if not specified.
+    assert_eq!(row.dt.timezone(), chrono_tz::Asia::Tokyo);
+    assert_eq!(row.dt_opt.unwrap().timezone(), chrono_tz::UTC);
+    assert_eq!(row.dt64s.timezone(), chrono_tz::Europe::Berlin);
+    assert_eq!(row.dt64ms.timezone(), chrono_tz::America::New_York);
+    assert_eq!(row.dt64us_opt.unwrap().timezone(), chrono_tz::Asia::Kolkata);
+    assert_eq!(row.dt64ns.timezone(), chrono_tz::Australia::Sydney);
+
+    assert_eq!(dt_str, row.dt.format("%Y-%m-%d %H:%M:%S").to_string());
+    assert_eq!(dt_str, "2022-11-14 00:27:42");
+    assert_eq!(dt64ms_str, "2022-11-13 10:27:42.123");
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "rust_decimal")]
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use clickhouse::{
+    serde::decimal::{d128, d256, d32, d64},
+    Row,
+};
+
+#[tokio::test]
+async fn rust_decimal() {
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "d32::<2>")]
+        d32: Decimal,
+        #[serde(with = "d64::<4>")]
+        d64: Decimal,
+        #[serde(with = "d128::<10>")]
+        d128: Decimal,
+        #[serde(with = "d256::<20>")]
+        d256: Decimal,
+        #[serde(with = "d64::<4>")]
+        d64_opt: Option<Decimal>,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                d32      Decimal(9, 2),
+                d64      Decimal(18, 4),
+                d128     Decimal(38, 10),
+                d256     Decimal(76, 20),
+                d64_opt  Nullable(Decimal(18, 4))
+            ) ENGINE = MergeTree ORDER BY d32
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let dec = |s: &str| Decimal::from_str(s).unwrap();
+    let original_rows = vec![
+        MyRow {
+            d32: dec("-1234567.89"),
+            d64: dec("12345678901234.5678"),
+            d128: dec("-1234567890.1234567891"),
+            d256: dec("12345678.12345678901234567890"),
+            d64_opt: None,
+        },
+        MyRow {
+            d32: dec("0.01"),
+            d64: dec("-0.0001"),
+            d128: dec("0"),
+            d256: dec("-0.00000000000000000001"),
+            d64_opt: Some(dec("42.4242")),
+        },
+    ];
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    for row in &original_rows {
+        insert.write(row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let rows = client
+        .query("SELECT ?fields FROM test ORDER BY d32")
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+
+    assert_eq!(rows, original_rows);
+
+    // Extra fractional digits are truncated.
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    insert
+        .write(&MyRow {
+            d32: dec("1.999"),
+            d64: dec("1.99999"),
+            d128: dec("1"),
+            d256: dec("1"),
+            d64_opt: Some(dec("-1.99999")),
+        })
+        .await
+        .unwrap();
+    insert.end().await.unwrap();
+
+    let (s32, s64_opt) = client
+        .query("SELECT toString(d32), toString(d64_opt) FROM test WHERE d128 = 1")
+        .fetch_one::<(String, String)>()
+        .await
+        .unwrap();
+    assert_eq!(s32, "1.99");
+    assert_eq!(s64_opt, "-1.9999");
+
+    // Both query arguments and server-side parameters are supported.
+    let count = client
+        .query("SELECT count() FROM test WHERE d64 = ? OR d64_opt = {value:Decimal(18, 4)}")
+        .bind(d64::<4>::wrap(dec("-0.0001")))
+        .param("value", d64::<4>::wrap(dec("-1.9999")))
+        .fetch_one::<u64>()
+        .await
+        .unwrap();
+    assert_eq!(count, 2);
+}
+
+#[cfg(feature = "bigdecimal")]
+#[tokio::test]
+async fn bigdecimal() {
+    use bigdecimal::BigDecimal;
+
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "d64::<4>")]
+        d64: BigDecimal,
+        #[serde(with = "d256::<30>")]
+        d256: BigDecimal,
+        #[serde(with = "d256::<30>")]
+        d256_opt: Option<BigDecimal>,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                d64      Decimal(18, 4),
+                d256     Decimal(76, 30),
+                d256_opt Nullable(Decimal(76, 30))
+            ) ENGINE = MergeTree ORDER BY d64
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let dec = |s: &str| BigDecimal::from_str(s).unwrap();
+    let original_rows = vec![
+        MyRow {
+            d64: dec("-12345678901234.5678"),
+            d256: dec("-1234567890123456789012345678901234567890.123456789012345678901234567890"),
+            d256_opt: None,
+        },
+        MyRow {
+            d64: dec("0.0001"),
+            d256: dec("0.000000000000000000000000000001"),
+            d256_opt: Some(dec("42")),
+        },
+    ];
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    for row in &original_rows {
+        insert.write(row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let rows = client
+        .query("SELECT ?fields FROM test WHERE d256 < ? ORDER BY d64")
+        .bind(d256::<30>::wrap(dec("1")))
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+
+    assert_eq!(rows, original_rows);
+}
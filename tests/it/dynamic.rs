@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+use clickhouse::{value::Value, Row};
+
+#[tokio::test]
+async fn dynamic_and_json() {
+    let client = prepare_database!()
+        .with_option("allow_experimental_dynamic_type", "1")
+        .with_option("allow_experimental_json_type", "1");
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        id: u32,
+        dynamic: Value,
+        #[serde(with = "clickhouse::serde::json")]
+        json: Value,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                id      UInt32,
+                dynamic Dynamic,
+                json    JSON
+            ) ENGINE = MergeTree ORDER BY id
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let rows = vec![
+        MyRow {
+            id: 1,
+            dynamic: Value::String("foo".into()),
+            json: Value::Json(vec![
+                ("a.b".into(), Value::Int64(42)),
+                ("c".into(), Value::Array(vec![Value::Float64(1.5)])),
+            ]),
+        },
+        MyRow {
+            id: 2,
+            dynamic: Value::Array(vec![Value::Int64(1), Value::Null]),
+            json: Value::Json(vec![]),
+        },
+        MyRow {
+            id: 3,
+            dynamic: Value::Null,
+            json: Value::Json(vec![("d".into(), Value::Bool(true))]),
+        },
+    ];
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    for row in &rows {
+        insert.write(row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let fetched = client
+        .query("SELECT ?fields FROM test ORDER BY id")
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+    assert_eq!(fetched, rows);
+
+    // Values of any types are decoded.
+    let (dynamic, json) = client
+        .query(
+            "
+            SELECT
+                toDecimal32(1.5, 2)::Dynamic,
+                '{\"a\": {\"b\": \"2024-01-01\"}, \"e\": [\"x\", null]}'::JSON::Dynamic
+            ",
+        )
+        .fetch_one::<(Value, Value)>()
+        .await
+        .unwrap();
+    assert_eq!(
+        dynamic,
+        Value::Other("Decimal(9, 2)".into(), vec![150, 0, 0, 0])
+    );
+    assert!(matches!(json, Value::Json(paths) if paths.len() == 2));
+}
+
+#[tokio::test]
+async fn json_typed_paths() {
+    let client = prepare_database!().with_option("allow_experimental_json_type", "1");
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::json")]
+        json: Value,
+    }
+
+    let row = client
+        .query("SELECT '{\"a\": 42, \"b\": \"foo\"}'::JSON(a UInt8) AS json")
+        .fetch_one::<MyRow>()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        row.json,
+        Value::Json(vec![
+            ("a".into(), Value::UInt8(42)),
+            ("b".into(), Value::String("foo".into())),
+        ])
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[tokio::test]
+async fn json_as_serde_json() {
+    use serde_json::json;
+
+    let client = prepare_database!().with_option("allow_experimental_json_type", "1");
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        id: u32,
+        #[serde(with = "clickhouse::serde::json")]
+        data: serde_json::Value,
+    }
+
+    client
+        .query("CREATE TABLE test(id UInt32, data JSON) ENGINE = MergeTree ORDER BY id")
+        .execute()
+        .await
+        .unwrap();
+
+    let original_row = MyRow {
+        id: 1,
+        data: json!({
+            "name": "John Doe",
+            "age": 42,
+            "phones": ["+123 456 789", "+987 654 321"],
+            "address": {"city": "Amsterdam"},
+        }),
+    };
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    insert.write(&original_row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let row = client
+        .query("SELECT ?fields FROM test")
+        .fetch_one::<MyRow>()
+        .await
+        .unwrap();
+    assert_eq!(row, original_row);
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "geo")]
+
+use geo_types::{
+    line_string, point, polygon, LineString, MultiLineString, MultiPolygon, Point, Polygon,
+};
+use serde::{Deserialize, Serialize};
+
+use clickhouse::{serde::geo, Row};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+struct MyRow {
+    id: u32,
+    #[serde(with = "geo::point")]
+    point: Point<f64>,
+    #[serde(with = "geo::ring")]
+    ring: LineString<f64>,
+    #[serde(with = "geo::line_string")]
+    line_string: LineString<f64>,
+    #[serde(with = "geo::multi_line_string")]
+    multi_line_string: MultiLineString<f64>,
+    #[serde(with = "geo::polygon")]
+    polygon: Polygon<f64>,
+    #[serde(with = "geo::multi_polygon")]
+    multi_polygon: MultiPolygon<f64>,
+}
+
+fn sample() -> MyRow {
+    let exterior = polygon!(
+        exterior: [(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)],
+        interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0)]],
+    );
+
+    MyRow {
+        id: 42,
+        point: point!(x: 1.5, y: -2.25),
+        ring: line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0)],
+        line_string: line_string![(x: -1.0, y: 1.0), (x: 3.5, y: 4.5)],
+        multi_line_string: MultiLineString::new(vec![
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)],
+            LineString::new(vec![]),
+        ]),
+        polygon: exterior.clone(),
+        multi_polygon: MultiPolygon::new(vec![
+            exterior,
+            polygon![(x: 20.0, y: 20.0), (x: 30.0, y: 20.0), (x: 30.0, y: 30.0)],
+        ]),
+    }
+}
+
+#[tokio::test]
+async fn geo_types() {
+    let client = prepare_database!();
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                id                UInt32,
+                point             Point,
+                ring              Ring,
+                line_string       LineString,
+                multi_line_string MultiLineString,
+                polygon           Polygon,
+                multi_polygon     MultiPolygon
+            ) ENGINE = MergeTree ORDER BY id
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let original_row = sample();
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    insert.write(&original_row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let row = client
+        .query("SELECT ?fields FROM test")
+        .fetch_one::<MyRow>()
+        .await
+        .unwrap();
+    assert_eq!(row, original_row);
+
+    // Geometries can be bound as literals and as server-side parameters.
+    let (is_equal, is_inside) = client
+        .query("SELECT polygon = ? AND ring = {ring:Ring}, pointInPolygon(?, polygon) FROM test")
+        .bind(geo::polygon::wrap(&original_row.polygon))
+        .bind(geo::point::wrap(&point!(x: 5.0, y: 5.0)))
+        .param("ring", geo::ring::wrap(&original_row.ring))
+        .fetch_one::<(bool, bool)>()
+        .await
+        .unwrap();
+    assert!(is_equal);
+    assert!(is_inside);
+}
+
+#[tokio::test]
+async fn geo_types_unclosed_rings() {
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct Data {
+        #[serde(with = "geo::polygon")]
+        polygon: Polygon<f64>,
+    }
+
+    let data = client
+        .query("SELECT [[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]] :: Polygon AS polygon")
+        .fetch_one::<Data>()
+        .await
+        .unwrap();
+
+    // `geo_types` closes rings, repeating the first point.
+    assert_eq!(
+        data.polygon,
+        polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 0.0, y: 1.0), (x: 0.0, y: 0.0)]
+    );
+}
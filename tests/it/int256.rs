@@ -0,0 +1,95 @@
+#![cfg(feature = "ethnum")]
+
+use ethnum::{I256, U256};
+use serde::{Deserialize, Serialize};
+
+use clickhouse::{
+    serde::{int256, uint256},
+    Row,
+};
+
+#[tokio::test]
+async fn int256() {
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "int256")]
+        i: I256,
+        #[serde(with = "uint256")]
+        u: U256,
+        #[serde(with = "int256::option")]
+        i_opt: Option<I256>,
+        #[serde(with = "uint256::option")]
+        u_opt: Option<U256>,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                i      Int256,
+                u      UInt256,
+                i_opt  Nullable(Int256),
+                u_opt  Nullable(UInt256)
+            ) ENGINE = MergeTree ORDER BY i
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let original_rows = vec![
+        MyRow {
+            i: I256::MIN,
+            u: U256::MAX,
+            i_opt: None,
+            u_opt: Some(U256::from_words(u128::MAX, 0)),
+        },
+        MyRow {
+            i: I256::new(-42),
+            u: U256::ZERO,
+            i_opt: Some(I256::from_words(-1, 42)),
+            u_opt: None,
+        },
+        MyRow {
+            i: I256::MAX,
+            u: U256::new(42),
+            i_opt: Some(I256::ONE),
+            u_opt: Some(U256::ONE),
+        },
+    ];
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    for row in &original_rows {
+        insert.write(row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let rows = client
+        .query("SELECT ?fields FROM test ORDER BY i")
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+    assert_eq!(rows, original_rows);
+
+    // The server agrees with the byte order.
+    let texts = client
+        .query("SELECT toString(i), toString(u) FROM test ORDER BY i")
+        .fetch_all::<(String, String)>()
+        .await
+        .unwrap();
+    for (row, (i, u)) in original_rows.iter().zip(texts) {
+        assert_eq!(i, row.i.to_string());
+        assert_eq!(u, row.u.to_string());
+    }
+
+    let rows = client
+        .query("SELECT ?fields FROM test WHERE i IN ? OR u = {u:UInt256} ORDER BY i")
+        .bind(vec![int256::wrap(I256::MIN), int256::wrap(I256::new(-42))])
+        .param("u", uint256::wrap(U256::new(42)))
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+    assert_eq!(rows, original_rows);
+}
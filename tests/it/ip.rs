@@ -1,4 +1,4 @@
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use serde::{Deserialize, Serialize};
 
@@ -54,3 +54,136 @@ async fn smoke() {
     assert_eq!(row_ipv4_str, original_row.ipv4.to_string());
     assert_eq!(row_ipv6_str, original_row.ipv6.to_string());
 }
+
+#[tokio::test]
+async fn ip_addr_mapped_into_ipv6() {
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::ip")]
+        addr: IpAddr,
+        #[serde(with = "clickhouse::serde::ip::option")]
+        addr_opt: Option<IpAddr>,
+    }
+
+    client
+        .query("CREATE TABLE test(addr IPv6, addr_opt Nullable(IPv6)) ENGINE = Memory")
+        .execute()
+        .await
+        .unwrap();
+
+    let v4 = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1));
+    let v6 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0xafc8, 0x10, 0x1));
+    let rows = vec![
+        MyRow {
+            addr: v4,
+            addr_opt: Some(v6),
+        },
+        MyRow {
+            addr: v6,
+            addr_opt: None,
+        },
+    ];
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    for row in &rows {
+        insert.write(row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let fetched = client
+        .query("SELECT ?fields FROM test ORDER BY addr")
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+    assert_eq!(fetched, rows);
+
+    // IPv4 addresses are stored as IPv4-mapped ones.
+    let count = client
+        .query("SELECT count() FROM test WHERE addr = ?")
+        .bind(clickhouse::serde::ip::wrap(v4))
+        .fetch_one::<u64>()
+        .await
+        .unwrap();
+    assert_eq!(count, 1);
+}
+
+#[cfg(feature = "ipnet")]
+#[tokio::test]
+async fn ip_nets() {
+    use ipnet::IpNet;
+
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::ipnet")]
+        net: IpNet,
+        net_addr: Ipv6Addr,
+        net_prefix: u8,
+    }
+
+    impl MyRow {
+        fn new(net: &str) -> Self {
+            let net = net.parse().unwrap();
+            let (net_addr, net_prefix) = clickhouse::serde::ipnet::split(&net);
+            Self {
+                net,
+                net_addr,
+                net_prefix,
+            }
+        }
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                net Tuple(IPv6, UInt8),
+                net_addr IPv6,
+                net_prefix UInt8,
+            ) ENGINE = Memory
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let rows = vec![MyRow::new("10.1.0.0/16"), MyRow::new("2001:db8::/32")];
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    for row in &rows {
+        insert.write(row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let fetched = client
+        .query("SELECT ?fields FROM test ORDER BY net_addr")
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+    assert_eq!(fetched, rows);
+
+    for row in &fetched {
+        let net = clickhouse::serde::ipnet::join(row.net_addr, row.net_prefix).unwrap();
+        assert_eq!(net, row.net);
+    }
+
+    // The stored prefix covers the same range of IPv6 addresses.
+    let range = client
+        .query(
+            "
+            SELECT toString(range.1), toString(range.2)
+            FROM (SELECT IPv6CIDRToRange(net_addr, net_prefix) AS range, net_prefix FROM test)
+            WHERE net_prefix > 32
+        ",
+        )
+        .fetch_one::<(String, String)>()
+        .await
+        .unwrap();
+    assert_eq!(
+        range,
+        ("::ffff:10.1.0.0".into(), "::ffff:10.1.255.255".into())
+    );
+}
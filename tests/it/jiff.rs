@@ -0,0 +1,626 @@
+#![cfg(feature = "jiff")]
+
+use std::ops::Range;
+
+use jiff::{
+    civil::{self, time, Date, Time},
+    SignedDuration, Timestamp, Zoned,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use clickhouse::Row;
+
+#[tokio::test]
+async fn datetime() {
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::jiff::datetime")]
+        dt: Timestamp,
+        #[serde(with = "clickhouse::serde::jiff::datetime::option")]
+        dt_opt: Option<Timestamp>,
+        #[serde(with = "clickhouse::serde::jiff::datetime64::secs")]
+        dt64s: Timestamp,
+        #[serde(with = "clickhouse::serde::jiff::datetime64::secs::option")]
+        dt64s_opt: Option<Timestamp>,
+        #[serde(with = "clickhouse::serde::jiff::datetime64::millis")]
+        dt64ms: Timestamp,
+        #[serde(with = "clickhouse::serde::jiff::datetime64::millis::option")]
+        dt64ms_opt: Option<Timestamp>,
+        #[serde(with = "clickhouse::serde::jiff::datetime64::micros")]
+        dt64us: Timestamp,
+        #[serde(with = "clickhouse::serde::jiff::datetime64::micros::option")]
+        dt64us_opt: Option<Timestamp>,
+        #[serde(with = "clickhouse::serde::jiff::datetime64::nanos")]
+        dt64ns: Timestamp,
+        #[serde(with = "clickhouse::serde::jiff::datetime64::nanos::option")]
+        dt64ns_opt: Option<Timestamp>,
+    }
+
+    #[derive(Debug, Deserialize, Row)]
+    struct MyRowStr {
+        dt: String,
+        dt64s: String,
+        dt64ms: String,
+        dt64us: String,
+        dt64ns: String,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                dt          DateTime('UTC'),
+                dt_opt      Nullable(DateTime('UTC')),
+                dt64s       DateTime64(0, 'UTC'),
+                dt64s_opt   Nullable(DateTime64(0, 'UTC')),
+                dt64ms      DateTime64(3, 'UTC'),
+                dt64ms_opt  Nullable(DateTime64(3, 'UTC')),
+                dt64us      DateTime64(6, 'UTC'),
+                dt64us_opt  Nullable(DateTime64(6, 'UTC')),
+                dt64ns      DateTime64(9, 'UTC'),
+                dt64ns_opt  Nullable(DateTime64(9, 'UTC'))
+            )
+            ENGINE = MergeTree ORDER BY dt
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let dt_s: Timestamp = "2022-11-13T15:27:42Z".parse().unwrap();
+    let dt_ms: Timestamp = "2022-11-13T15:27:42.123Z".parse().unwrap();
+    let dt_us: Timestamp = "2022-11-13T15:27:42.123456Z".parse().unwrap();
+    let dt_ns: Timestamp = "2022-11-13T15:27:42.123456789Z".parse().unwrap();
+
+    let original_row = MyRow {
+        dt: dt_s,
+        dt_opt: Some(dt_s),
+        dt64s: dt_s,
+        dt64s_opt: Some(dt_s),
+        dt64ms: dt_ms,
+        dt64ms_opt: Some(dt_ms),
+        dt64us: dt_us,
+        dt64us_opt: Some(dt_us),
+        dt64ns: dt_ns,
+        dt64ns_opt: Some(dt_ns),
+    };
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    insert.write(&original_row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let row = client
+        .query("SELECT ?fields FROM test")
+        .fetch_one::<MyRow>()
+        .await
+        .unwrap();
+
+    let row_str = client
+        .query(
+            "
+            SELECT toString(dt)     AS dt,
+                   toString(dt64s)  AS dt64s,
+                   toString(dt64ms) AS dt64ms,
+                   toString(dt64us) AS dt64us,
+                   toString(dt64ns) AS dt64ns
+              FROM test
+        ",
+        )
+        .fetch_one::<MyRowStr>()
+        .await
+        .unwrap();
+
+    assert_eq!(row, original_row);
+    assert_eq!(row_str.dt, "2022-11-13 15:27:42");
+    assert_eq!(row_str.dt64s, "2022-11-13 15:27:42");
+    assert_eq!(row_str.dt64ms, "2022-11-13 15:27:42.123");
+    assert_eq!(row_str.dt64us, "2022-11-13 15:27:42.123456");
+    assert_eq!(row_str.dt64ns, "2022-11-13 15:27:42.123456789");
+}
+
+#[tokio::test]
+async fn zoned() {
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::jiff::zoned")]
+        dt: Zoned,
+        #[serde(with = "clickhouse::serde::jiff::zoned::option")]
+        dt_opt: Option<Zoned>,
+        #[serde(with = "clickhouse::serde::jiff::zoned64::secs")]
+        dt64s: Zoned,
+        #[serde(with = "clickhouse::serde::jiff::zoned64::millis")]
+        dt64ms: Zoned,
+        #[serde(with = "clickhouse::serde::jiff::zoned64::micros::option")]
+        dt64us_opt: Option<Zoned>,
+        #[serde(with = "clickhouse::serde::jiff::zoned64::nanos")]
+        dt64ns: Zoned,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                dt          DateTime('Asia/Tokyo'),
+                dt_opt      Nullable(DateTime('UTC')),
+                dt64s       DateTime64(0, 'Europe/Berlin'),
+                dt64ms      DateTime64(3, 'America/New_York'),
+                dt64us_opt  Nullable(DateTime64(6, 'Asia/Kolkata')),
+                dt64ns      DateTime64(9, 'Australia/Sydney')
+            )
+            ENGINE = MergeTree ORDER BY dt
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    // Written values are converted to UTC, their time zones don't matter.
+    let in_moscow = |s: &str| {
+        s.parse::<Timestamp>()
+            .unwrap()
+            .in_tz("Europe/Moscow")
+            .unwrap()
+    };
+
+    let original_row = MyRow {
+        dt: in_moscow("2022-11-13T15:27:42Z"),
+        dt_opt: Some(in_moscow("2022-11-13T15:27:42Z")),
+        dt64s: in_moscow("2022-11-13T15:27:42Z"),
+        dt64ms: in_moscow("2022-11-13T15:27:42.123Z"),
+        dt64us_opt: Some(in_moscow("2022-11-13T15:27:42.123456Z")),
+        dt64ns: in_moscow("2022-11-13T15:27:42.123456789Z"),
+    };
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    insert.write(&original_row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let (row, dt_str, dt64ms_str) = client
+        .query("SELECT ?fields, toString(dt), toString(dt64ms) FROM test")
+        .fetch_one::<(MyRow, String, String)>()
+        .await
+        .unwrap();
+
+    // `Zoned` values are equal if they're the same instant, time zones are checked separately.
+    assert_eq!(row, original_row);
+
+    // Read values are in time zones of the columns.
+    let tz_name =
+        |zoned: Option<&Zoned>| zoned.and_then(|z| z.time_zone().iana_name().map(str::to_owned));
+    assert_eq!(tz_name(Some(&row.dt)).unwrap(), "Asia/Tokyo");
+    assert_eq!(tz_name(row.dt_opt.as_ref()).unwrap(), "UTC");
+    assert_eq!(tz_name(Some(&row.dt64s)).unwrap(), "Europe/Berlin");
+    assert_eq!(tz_name(Some(&row.dt64ms)).unwrap(), "America/New_York");
+    assert_eq!(tz_name(row.dt64us_opt.as_ref()).unwrap(), "Asia/Kolkata");
+    assert_eq!(tz_name(Some(&row.dt64ns)).unwrap(), "Australia/Sydney");
+
+    assert_eq!(dt_str, row.dt.strftime("%Y-%m-%d %H:%M:%S").to_string());
+    assert_eq!(dt_str, "2022-11-14 00:27:42");
+    assert_eq!(dt64ms_str, "2022-11-13 10:27:42.123");
+}
+
+#[tokio::test]
+async fn date() {
+    let client = prepare_database!();
+
+    #[derive(Debug, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::jiff::date")]
+        date: Date,
+        #[serde(with = "clickhouse::serde::jiff::date::option")]
+        date_opt: Option<Date>,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                date        Date,
+                date_opt    Nullable(Date)
+            ) ENGINE = MergeTree ORDER BY date
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+
+    let dates = generate_dates(1970..2149, 100);
+    for &date in &dates {
+        let original_row = MyRow {
+            date,
+            date_opt: Some(date),
+        };
+
+        insert.write(&original_row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let actual = client
+        .query("SELECT ?fields, toString(date) FROM test ORDER BY date")
+        .fetch_all::<(MyRow, String)>()
+        .await
+        .unwrap();
+
+    assert_eq!(actual.len(), dates.len());
+
+    for ((row, date_str), expected) in actual.iter().zip(dates) {
+        assert_eq!(row.date, expected);
+        assert_eq!(row.date_opt, Some(expected));
+        assert_eq!(date_str, &expected.to_string());
+    }
+}
+
+#[tokio::test]
+async fn date32() {
+    let client = prepare_database!();
+
+    #[derive(Debug, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::jiff::date32")]
+        date: Date,
+        #[serde(with = "clickhouse::serde::jiff::date32::option")]
+        date_opt: Option<Date>,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                date        Date32,
+                date_opt    Nullable(Date32)
+            ) ENGINE = MergeTree ORDER BY date
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+
+    let dates = generate_dates(1925..2283, 100); // TODO: 1900..=2299 for newer versions.
+    for &date in &dates {
+        let original_row = MyRow {
+            date,
+            date_opt: Some(date),
+        };
+
+        insert.write(&original_row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    let actual = client
+        .query("SELECT ?fields, toString(date) FROM test ORDER BY date")
+        .fetch_all::<(MyRow, String)>()
+        .await
+        .unwrap();
+
+    assert_eq!(actual.len(), dates.len());
+
+    for ((row, date_str), expected) in actual.iter().zip(dates) {
+        assert_eq!(row.date, expected);
+        assert_eq!(row.date_opt, Some(expected));
+        assert_eq!(date_str, &expected.to_string());
+    }
+}
+
+fn generate_dates(years: Range<i16>, count: usize) -> Vec<Date> {
+    let mut rng = rand::rng();
+    let mut dates: Vec<_> = (0..count)
+        .map(|_| {
+            let year = rng.random_range(years.clone());
+            let month = rng.random_range(1..=12);
+            let day = rng.random_range(1..=28); // Safe for all months
+            civil::date(year, month, day)
+        })
+        .collect();
+
+    dates.sort_unstable();
+    dates
+}
+
+#[tokio::test]
+async fn time_roundtrip() {
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::jiff::time")]
+        t0: SignedDuration,
+        #[serde(with = "clickhouse::serde::jiff::time::option")]
+        t1: Option<SignedDuration>,
+    }
+
+    client
+        .query(
+            r#"
+            CREATE TABLE test_time (
+                t0  Time,
+                t1  Nullable(Time)
+            ) ENGINE = MergeTree ORDER BY tuple()
+            SETTINGS enable_time_time64_type = 1;
+            "#,
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let positive_value = SignedDuration::from_secs(12 * 3600 + 34 * 60 + 56); // 12:34:56
+    let row = MyRow {
+        t0: positive_value,
+        t1: Some(positive_value),
+    };
+
+    let mut insert = client.insert::<MyRow>("test_time").unwrap();
+    insert.write(&row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let fetched_rows: Vec<MyRow> = client
+        .query("SELECT ?fields FROM test_time")
+        .fetch_all()
+        .await
+        .unwrap();
+
+    assert_eq!(fetched_rows.len(), 1);
+    assert_eq!(fetched_rows[0], row);
+}
+
+#[tokio::test]
+async fn time_negative_roundtrip() {
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::jiff::time")]
+        t0: SignedDuration,
+        #[serde(with = "clickhouse::serde::jiff::time::option")]
+        t1: Option<SignedDuration>,
+    }
+
+    client
+        .query(
+            r#"
+            CREATE TABLE test_time_negative (
+                t0  Time,
+                t1  Nullable(Time)
+            ) ENGINE = MergeTree ORDER BY tuple()
+            SETTINGS enable_time_time64_type = 1;
+            "#,
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    let negative_value = SignedDuration::from_secs(-2 * 3600 - 15 * 60 - 30); // -02:15:30
+    let row = MyRow {
+        t0: negative_value,
+        t1: Some(negative_value),
+    };
+
+    let mut insert = client.insert::<MyRow>("test_time_negative").unwrap();
+    insert.write(&row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let fetched_rows: Vec<MyRow> = client
+        .query("SELECT ?fields FROM test_time_negative")
+        .fetch_all()
+        .await
+        .unwrap();
+
+    assert_eq!(fetched_rows.len(), 1);
+    assert_eq!(fetched_rows[0], row);
+}
+
+#[tokio::test]
+async fn time64_roundtrip() {
+    let client = prepare_database!();
+
+    client
+        .query(
+            r#"
+            CREATE TABLE test_time64 (
+                t0      Time64(0),
+                t0_opt  Nullable(Time64(0)),
+                t3      Time64(3),
+                t6      Time64(6),
+                t9      Time64(9)
+            ) ENGINE = MergeTree
+            ORDER BY tuple()
+            SETTINGS enable_time_time64_type = 1;
+            "#,
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::jiff::time64::secs")]
+        t0: SignedDuration,
+        #[serde(with = "clickhouse::serde::jiff::time64::secs::option")]
+        t0_opt: Option<SignedDuration>,
+        #[serde(with = "clickhouse::serde::jiff::time64::millis")]
+        t3: SignedDuration,
+        #[serde(with = "clickhouse::serde::jiff::time64::micros")]
+        t6: SignedDuration,
+        #[serde(with = "clickhouse::serde::jiff::time64::nanos")]
+        t9: SignedDuration,
+    }
+
+    let base_seconds = 12 * 3600 + 34 * 60 + 56; // 45296 seconds
+    let row = MyRow {
+        t0: SignedDuration::from_secs(base_seconds),
+        t0_opt: Some(SignedDuration::from_secs(base_seconds)),
+        t3: SignedDuration::from_millis(base_seconds * 1000 + 789),
+        t6: SignedDuration::from_micros(base_seconds * 1_000_000 + 789_123),
+        t9: SignedDuration::from_nanos(base_seconds * 1_000_000_000 + 789_123_456),
+    };
+
+    let mut insert = client.insert::<MyRow>("test_time64").unwrap();
+    insert.write(&row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let fetched_rows: Vec<MyRow> = client
+        .query("SELECT ?fields FROM test_time64")
+        .fetch_all()
+        .await
+        .unwrap();
+
+    assert_eq!(fetched_rows.len(), 1);
+    assert_eq!(fetched_rows[0], row);
+}
+
+#[tokio::test]
+async fn time64_negative_roundtrip() {
+    let client = prepare_database!();
+
+    client
+        .query(
+            r#"
+            CREATE TABLE test_time64_negative (
+                t0      Time64(0),
+                t0_opt  Nullable(Time64(0)),
+                t3      Time64(3),
+                t6      Time64(6),
+                t9      Time64(9)
+            ) ENGINE = MergeTree
+            ORDER BY tuple()
+            SETTINGS enable_time_time64_type = 1;
+            "#,
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::jiff::time64::secs")]
+        t0: SignedDuration,
+        #[serde(with = "clickhouse::serde::jiff::time64::secs::option")]
+        t0_opt: Option<SignedDuration>,
+        #[serde(with = "clickhouse::serde::jiff::time64::millis")]
+        t3: SignedDuration,
+        #[serde(with = "clickhouse::serde::jiff::time64::micros")]
+        t6: SignedDuration,
+        #[serde(with = "clickhouse::serde::jiff::time64::nanos")]
+        t9: SignedDuration,
+    }
+
+    let neg_base_seconds = -5 * 3600 - 15 * 60 - 30; // -18930 seconds
+    let row = MyRow {
+        t0: SignedDuration::from_secs(neg_base_seconds),
+        t0_opt: Some(SignedDuration::from_secs(neg_base_seconds)),
+        t3: SignedDuration::from_millis(neg_base_seconds * 1000 - 123),
+        t6: SignedDuration::from_micros(neg_base_seconds * 1_000_000 - 123_456),
+        t9: SignedDuration::from_nanos(neg_base_seconds * 1_000_000_000 - 123_456_789),
+    };
+
+    let mut insert = client.insert::<MyRow>("test_time64_negative").unwrap();
+    insert.write(&row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let fetched_rows: Vec<MyRow> = client
+        .query("SELECT ?fields FROM test_time64_negative")
+        .fetch_all()
+        .await
+        .unwrap();
+
+    assert_eq!(fetched_rows.len(), 1);
+    assert_eq!(fetched_rows[0], row);
+}
+
+#[tokio::test]
+async fn time_of_day_roundtrip() {
+    let client = prepare_database!();
+
+    client
+        .query(
+            r#"
+            CREATE TABLE test_time_of_day (
+                t         Time,
+                t_opt     Nullable(Time),
+                t64s      Time64(0),
+                t64s_opt  Nullable(Time64(0)),
+                t64ms     Time64(3),
+                t64us     Time64(6),
+                t64ns     Time64(9),
+                t64ns_opt Nullable(Time64(9))
+            ) ENGINE = MergeTree ORDER BY tuple()
+            SETTINGS enable_time_time64_type = 1;
+            "#,
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct TimeRow {
+        #[serde(with = "clickhouse::serde::jiff::time_of_day")]
+        t: Time,
+        #[serde(with = "clickhouse::serde::jiff::time_of_day::option")]
+        t_opt: Option<Time>,
+        #[serde(with = "clickhouse::serde::jiff::time_of_day::secs")]
+        t64s: Time,
+        #[serde(with = "clickhouse::serde::jiff::time_of_day::secs::option")]
+        t64s_opt: Option<Time>,
+        #[serde(with = "clickhouse::serde::jiff::time_of_day::millis")]
+        t64ms: Time,
+        #[serde(with = "clickhouse::serde::jiff::time_of_day::micros")]
+        t64us: Time,
+        #[serde(with = "clickhouse::serde::jiff::time_of_day::nanos")]
+        t64ns: Time,
+        #[serde(with = "clickhouse::serde::jiff::time_of_day::nanos::option")]
+        t64ns_opt: Option<Time>,
+    }
+
+    let row = TimeRow {
+        t: time(12, 34, 56, 0),
+        t_opt: None,
+        t64s: Time::midnight(),
+        t64s_opt: Some(time(23, 59, 59, 0)),
+        t64ms: time(1, 2, 3, 456_000_000),
+        t64us: time(1, 2, 3, 456_789_000),
+        t64ns: time(23, 59, 59, 999_999_999),
+        t64ns_opt: Some(time(0, 0, 0, 1)),
+    };
+
+    let mut insert = client.insert::<TimeRow>("test_time_of_day").unwrap();
+    insert.write(&row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let fetched = client
+        .query("SELECT ?fields FROM test_time_of_day")
+        .fetch_one::<TimeRow>()
+        .await
+        .unwrap();
+    assert_eq!(fetched, row);
+
+    let as_str = client
+        .query("SELECT toString(t64ms) FROM test_time_of_day")
+        .fetch_one::<String>()
+        .await
+        .unwrap();
+    assert_eq!(as_str, "01:02:03.456");
+
+    // Values out of a day cannot be represented as `jiff::civil::Time`.
+    #[derive(Debug, Deserialize, Row)]
+    struct Data {
+        #[serde(with = "clickhouse::serde::jiff::time_of_day")]
+        #[allow(dead_code)]
+        t: Time,
+    }
+
+    let err = client
+        .query("SELECT '-01:00:00'::Time AS t")
+        .with_option("enable_time_time64_type", "1")
+        .fetch_one::<Data>()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+}
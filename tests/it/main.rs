@@ -163,21 +163,29 @@ pub(crate) async fn execute_statements(client: &Client, statements: &[&str]) {
     }
 }
 
+mod aggregate;
 mod chrono;
 mod cloud_jwt;
 mod compression;
 mod cursor_error;
 mod cursor_stats;
+mod decimal;
+mod dynamic;
 mod fetch_bytes;
+mod geo;
 mod https_errors;
 mod insert;
 mod inserter;
 mod int128;
+mod int256;
 mod ip;
+mod jiff;
+mod migrations;
 mod mock;
 mod nested;
 mod query;
 mod rbwnat;
+mod schema;
 mod time;
 mod user_agent;
 mod uuid;
@@ -0,0 +1,97 @@
+#![cfg(feature = "migrations")]
+
+use clickhouse::{
+    error::Error,
+    migrations::{embed_migrations, Drift, Migration, Migrator},
+    Client,
+};
+
+async fn columns(client: &Client) -> Vec<String> {
+    client
+        .query(
+            "SELECT name FROM system.columns \
+             WHERE database = currentDatabase() AND table = 'events' ORDER BY position",
+        )
+        .fetch_all()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn run_and_revert() {
+    let client = prepare_database!();
+    let migrator = embed_migrations!("tests/migrations").unwrap();
+
+    assert_eq!(migrator.applied(&client).await.unwrap(), []);
+    assert_eq!(migrator.run(&client).await.unwrap(), [1, 2]);
+    assert!(migrator.run(&client).await.unwrap().is_empty());
+    assert_eq!(columns(&client).await, ["id", "kind"]);
+
+    let applied = migrator.applied(&client).await.unwrap();
+    assert_eq!(applied.len(), 2);
+    assert_eq!(applied[1].name, "add_kind");
+    assert_eq!(applied[1].checksum, migrator.migrations()[1].checksum());
+
+    assert_eq!(migrator.revert(&client, 1).await.unwrap(), [2]);
+    assert_eq!(columns(&client).await, ["id"]);
+
+    let err = migrator.revert(&client, 0).await.unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("1_create_events has no down script"));
+
+    assert_eq!(migrator.run(&client).await.unwrap(), [2]);
+    assert_eq!(columns(&client).await, ["id", "kind"]);
+}
+
+#[tokio::test]
+async fn dry_run() {
+    let client = prepare_database!();
+    let migrator = Migrator::from_dir("tests/migrations")
+        .unwrap()
+        .with_table("schema_versions")
+        .with_dry_run(true);
+
+    assert_eq!(migrator.run(&client).await.unwrap(), [1, 2]);
+
+    let exists = client
+        .query("EXISTS TABLE schema_versions")
+        .fetch_one::<u8>()
+        .await
+        .unwrap();
+    assert_eq!(exists, 0);
+}
+
+#[tokio::test]
+async fn drift() {
+    let client = prepare_database!();
+    let migrator = Migrator::from_dir("tests/migrations").unwrap();
+    migrator.run(&client).await.unwrap();
+
+    let changed = Migrator::new([
+        Migration::new(0, "init", "SELECT 1"),
+        Migration::new(1, "create_events", "CREATE TABLE events (id UInt32)"),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        changed.drift(&client).await.unwrap(),
+        [
+            Drift::ChecksumMismatch {
+                version: 1,
+                name: "create_events".into(),
+            },
+            Drift::Missing {
+                version: 2,
+                name: "add_kind".into(),
+            },
+            Drift::OutOfOrder {
+                version: 0,
+                name: "init".into(),
+            },
+        ]
+    );
+
+    let err = changed.run(&client).await.unwrap_err();
+    assert!(matches!(err, Error::Migration(_)), "{err}");
+}
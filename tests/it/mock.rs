@@ -24,3 +24,35 @@ async fn provide() {
     tokio::time::advance(Duration::from_secs(100_000)).await;
     test_provide().await;
 }
+
+#[tokio::test]
+async fn bind_set_as_external_table() {
+    let mock = test::Mock::new();
+    let client = Client::default().with_mock(&mock).with_inline_set_limit(1);
+    let recording = mock.add(test::handlers::record_ddl());
+
+    client
+        .query("SELECT 1 WHERE 1 IN ? AND (1, 'a') IN ?")
+        .bind_set(&[1u32, 2])
+        .bind_set(&[(1u8, "a'b"), (2, "c")])
+        .execute()
+        .await
+        .unwrap();
+
+    let boundary = "--clickhouse-rs-boundary-0";
+    assert_eq!(
+        recording.query().await,
+        format!(
+            "{boundary}\r\n\
+             Content-Disposition: form-data; name=\"query\"\r\n\r\n\
+             SELECT 1 WHERE 1 IN _set1 AND (1, 'a') IN _set3\r\n\
+             {boundary}\r\n\
+             Content-Disposition: form-data; name=\"_set1\"; filename=\"_set1\"\r\n\r\n\
+             (1),(2)\r\n\
+             {boundary}\r\n\
+             Content-Disposition: form-data; name=\"_set3\"; filename=\"_set3\"\r\n\r\n\
+             (1,'a\\'b'),(2,'c')\r\n\
+             {boundary}--\r\n"
+        )
+    );
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use clickhouse::{error::Error, Row};
@@ -123,6 +125,177 @@ async fn server_side_param() {
     assert_eq!(result, &["a", "bc"]);
 }
 
+#[tokio::test]
+async fn maps_and_structs() {
+    #[derive(Serialize)]
+    struct Labels<'a> {
+        name: &'a str,
+        no: u32,
+    }
+
+    let client = prepare_database!();
+
+    let labels = HashMap::from([("env", "prod"), ("region", "eu'1")]);
+    let label = Labels {
+        name: "a'b",
+        no: 42,
+    };
+
+    let result = client
+        .query("SELECT ?['region'] || tupleElement(?, 'name') || toString(tupleElement(?, 'no'))")
+        .bind(&labels)
+        .bind(&label)
+        .bind(&label)
+        .fetch_one::<String>()
+        .await
+        .unwrap();
+    assert_eq!(result, "eu'1a'b42");
+
+    let (names, data_type) = client
+        .query("SELECT tupleNames(?), toTypeName(?)")
+        .bind(&label)
+        .bind(&label)
+        .fetch_one::<(Vec<String>, String)>()
+        .await
+        .unwrap();
+    assert_eq!(names, ["name", "no"]);
+    assert_eq!(data_type, "Tuple(name String, no UInt32)");
+
+    let result = client
+        .query(
+            "SELECT {labels: Map(String, String)}['region'] \
+             || {label: Tuple(name String, no UInt32)}.name",
+        )
+        .param("labels", &labels)
+        .param("label", &label)
+        .fetch_one::<String>()
+        .await
+        .unwrap();
+    assert_eq!(result, "eu'1a'b");
+}
+
+#[tokio::test]
+async fn named_args() {
+    let client = prepare_database!();
+
+    let result = client
+        .query("SELECT ?{a} + ? + ?{a} * ?{b}")
+        .bind_named("b", 10)
+        .bind(1)
+        .bind_named("a", 100)
+        .fetch_one::<i64>()
+        .await
+        .unwrap();
+    assert_eq!(result, 1201);
+
+    let err = client
+        .query("SELECT ?{a} + ?{b}")
+        .bind_named("a", 1)
+        .fetch_one::<i64>()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidParams(_)));
+    assert!(err.to_string().contains("?{b}"), "{err}");
+}
+
+#[tokio::test]
+async fn typed_params() {
+    let client = prepare_database!();
+
+    let result = client
+        .query("SELECT arrayStringConcat(?{words}, ?param) || ?param || toString(?{n} * 2)")
+        .param_typed("words", vec!["a'b", "c\\d"])
+        .bind_param(" ")
+        .param_typed("n", 21u8)
+        .bind_param("\t")
+        .fetch_one::<String>()
+        .await
+        .unwrap();
+    assert_eq!(result, "a'b c\\d\t42");
+
+    let result = client
+        .query("SELECT {m: Map(String, UInt32)}['b'] + ifNull(?param, 40)")
+        .param_typed(
+            "m",
+            std::collections::BTreeMap::from([("a", 1u32), ("b", 2)]),
+        )
+        .bind_param(None::<u32>)
+        .fetch_one::<u64>()
+        .await
+        .unwrap();
+    assert_eq!(result, 42);
+}
+
+#[tokio::test]
+async fn bind_set() {
+    let client = prepare_database!().with_inline_set_limit(3);
+
+    // 200k values take more than 1MiB, which exceeds both `max_query_size`
+    // and `http_max_uri_size`, so they must be sent in the body.
+    for n in [3u32, 1000, 200_000] {
+        let set = (0..n).collect::<Vec<_>>();
+        let result = client
+            .query("SELECT count() FROM numbers(?) WHERE number IN ?")
+            .bind(300_000)
+            .bind_set(&set)
+            .fetch_one::<u64>()
+            .await
+            .unwrap();
+        assert_eq!(result, u64::from(n));
+    }
+
+    let set = ["a'b", "c\\d", "e\nf", "g"].map(String::from).to_vec();
+    let pairs = set.iter().cloned().zip(1u32..).collect::<Vec<_>>();
+    let result = client
+        .query(
+            "SELECT count() FROM (SELECT arrayJoin(?) AS s) \
+             WHERE s IN ? AND (s, length(s)) IN ?",
+        )
+        .bind(&set)
+        .bind_set(&set)
+        .bind_set(&pairs)
+        .fetch_one::<u64>()
+        .await
+        .unwrap();
+    // Only `("e\nf", 3)` matches by length.
+    assert_eq!(result, 1);
+}
+
+#[tokio::test]
+async fn execute_script() {
+    let client = prepare_database!();
+
+    client
+        .execute_script(
+            "CREATE TABLE test(s String) ENGINE = MergeTree ORDER BY s;
+             -- inserts;
+             INSERT INTO test VALUES ('a;b'), ('?');
+             INSERT INTO test VALUES ($$c;$$); /* ; */",
+        )
+        .await
+        .unwrap();
+
+    let result = client
+        .query("SELECT groupArray(s) FROM (SELECT s FROM test ORDER BY s)")
+        .fetch_one::<Vec<String>>()
+        .await
+        .unwrap();
+    assert_eq!(result, ["?", "a;b", "c;"]);
+
+    let err = client
+        .execute_script("INSERT INTO test VALUES ('d'); SELEC 1; INSERT INTO test VALUES ('e')")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Script { index: 1, .. }), "{err}");
+
+    let count = client
+        .query("SELECT count() FROM test")
+        .fetch_one::<u64>()
+        .await
+        .unwrap();
+    assert_eq!(count, 4);
+}
+
 // See #19.
 #[tokio::test]
 async fn long_query() {
@@ -184,6 +357,49 @@ async fn big_borrowed_str() {
     assert_eq!(row.body, long_string);
 }
 
+#[tokio::test]
+async fn interned_strings() {
+    use std::sync::Arc;
+
+    let client = prepare_database!();
+
+    #[derive(Debug, Row, Deserialize)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::interned")]
+        name: Arc<str>,
+        #[serde(with = "clickhouse::serde::interned::option")]
+        label: Option<Arc<str>>,
+    }
+
+    let mut cursor = client
+        .query(
+            "
+            SELECT
+                toLowCardinality(['foo', 'bar'][number % 2 + 1]) AS name,
+                if(number < 2, NULL, 'baz') :: Nullable(String) AS label
+            FROM numbers(4)
+            ",
+        )
+        .fetch::<MyRow>()
+        .unwrap()
+        .with_string_interner();
+
+    let mut rows = Vec::new();
+    while let Some(row) = cursor.next().await.unwrap() {
+        rows.push(row);
+    }
+
+    let names = rows.iter().map(|row| &*row.name).collect::<Vec<_>>();
+    assert_eq!(names, ["foo", "bar", "foo", "bar"]);
+    assert!(Arc::ptr_eq(&rows[0].name, &rows[2].name));
+    assert!(Arc::ptr_eq(&rows[1].name, &rows[3].name));
+    assert_eq!(rows[1].label, None);
+    assert!(Arc::ptr_eq(
+        rows[2].label.as_ref().unwrap(),
+        rows[3].label.as_ref().unwrap()
+    ));
+}
+
 // See #31.
 #[tokio::test]
 async fn all_floats() {
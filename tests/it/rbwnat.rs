@@ -771,6 +771,98 @@ async fn enums() {
     assert_eq!(result, expected);
 }
 
+#[tokio::test]
+async fn enums_by_name() {
+    // Written by discriminants, read by names.
+    #[derive(Debug, PartialEq, Serialize_repr, Deserialize)]
+    #[repr(i8)]
+    enum Season {
+        Spring = 0,
+        Summer = 100,
+        #[serde(other)]
+        Unknown = -1,
+    }
+
+    #[derive(Debug, PartialEq, Row, Serialize, Deserialize)]
+    struct Data {
+        id: u16,
+        season: Season,
+        seasons: Vec<Season>,
+    }
+
+    let table_name = "test_rbwnat_enum_by_name";
+
+    let client = prepare_database!();
+    client
+        .query(
+            "
+            CREATE OR REPLACE TABLE ?
+            (
+                id      UInt16,
+                season  Enum8('Unknown' = -1, 'Spring' = 0, 'Summer' = 100),
+                seasons Array(Enum8('Unknown' = -1, 'Spring' = 0, 'Summer' = 100))
+            ) ENGINE MergeTree ORDER BY id
+            ",
+        )
+        .bind(Identifier(table_name))
+        .execute()
+        .await
+        .unwrap();
+
+    let mut insert = client.insert::<Data>(table_name).unwrap();
+    insert
+        .write(&Data {
+            id: 1,
+            season: Season::Summer,
+            seasons: vec![Season::Spring, Season::Summer],
+        })
+        .await
+        .unwrap();
+    insert.end().await.unwrap();
+
+    // A new value is added on the server, unknown to the client.
+    client
+        .query(
+            "
+            ALTER TABLE ? MODIFY COLUMN season
+            Enum8('Unknown' = -1, 'Spring' = 0, 'Summer' = 100, 'Autumn' = 127)
+            ",
+        )
+        .bind(Identifier(table_name))
+        .execute()
+        .await
+        .unwrap();
+    client
+        .query("INSERT INTO ? VALUES (2, 'Autumn', ['Spring'])")
+        .bind(Identifier(table_name))
+        .execute()
+        .await
+        .unwrap();
+
+    let result = client
+        .query("SELECT * FROM ? ORDER BY id ASC")
+        .bind(Identifier(table_name))
+        .fetch_all::<Data>()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result,
+        vec![
+            Data {
+                id: 1,
+                season: Season::Summer,
+                seasons: vec![Season::Spring, Season::Summer],
+            },
+            Data {
+                id: 2,
+                season: Season::Unknown,
+                seasons: vec![Season::Spring],
+            },
+        ]
+    );
+}
+
 #[tokio::test]
 async fn nullable() {
     #[derive(Debug, Row, Serialize, Deserialize, PartialEq)]
@@ -1183,6 +1275,169 @@ async fn tuple_too_many_elements_in_the_struct() {
     );
 }
 
+#[tokio::test]
+async fn named_tuple() {
+    #[derive(Debug, Row, Serialize, Deserialize, PartialEq)]
+    struct Data {
+        a: (u32, String),
+    }
+
+    let client = get_client();
+    let result = client
+        .query("SELECT (42, 'foo') :: Tuple(id UInt32, name String) AS a")
+        .fetch_one::<Data>()
+        .await;
+
+    assert_eq!(
+        result.unwrap(),
+        Data {
+            a: (42, "foo".to_string()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn named_tuple_as_struct() {
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Location {
+        lon: f64,
+        lat: f64,
+    }
+
+    #[derive(Debug, Row, Serialize, Deserialize, PartialEq)]
+    struct Data {
+        id: u32,
+        location: Location,
+        history: Vec<Location>,
+    }
+
+    let client = get_client();
+    let result = client
+        .query(
+            "
+            SELECT
+                42           :: UInt32                                 AS id,
+                (52.5, 4.5)  :: Tuple(lat Float64, lon Float64)        AS location,
+                [(1.5, 2.5)] :: Array(Tuple(lat Float64, lon Float64)) AS history
+            ",
+        )
+        .fetch_one::<Data>()
+        .await;
+
+    assert_eq!(
+        result.unwrap(),
+        Data {
+            id: 42,
+            location: Location {
+                lat: 52.5,
+                lon: 4.5
+            },
+            history: vec![Location { lat: 1.5, lon: 2.5 }],
+        }
+    );
+}
+
+#[tokio::test]
+async fn named_tuple_as_struct_mismatched_names() {
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Location {
+        lon: f64,
+        lat: f64,
+    }
+
+    #[derive(Debug, Row, Serialize, Deserialize, PartialEq)]
+    struct Data {
+        location: Location,
+    }
+
+    assert_panic_on_fetch!(
+        &[
+            "Data.location",
+            "Tuple(lat Float64, longitude Float64)",
+            "elements lat, longitude do not match the struct fields lon, lat"
+        ],
+        "SELECT (52.5, 4.5) :: Tuple(lat Float64, longitude Float64) AS location"
+    );
+}
+
+#[tokio::test]
+async fn simple_aggregate_function_and_interval() {
+    #[derive(Debug, Row, Serialize, Deserialize, PartialEq)]
+    struct Data {
+        a: u64,
+        b: Vec<String>,
+        c: i64,
+        d: i64,
+    }
+
+    let client = get_client();
+    let result = client
+        .query(
+            "
+            SELECT
+                42      :: SimpleAggregateFunction(any, UInt64)                  AS a,
+                ['foo'] :: SimpleAggregateFunction(groupArrayArray, Array(String)) AS b,
+                INTERVAL 3 DAY                                                     AS c,
+                INTERVAL -5 SECOND                                                 AS d
+            ",
+        )
+        .fetch_one::<Data>()
+        .await;
+
+    assert_eq!(
+        result.unwrap(),
+        Data {
+            a: 42,
+            b: vec!["foo".to_string()],
+            c: 3,
+            d: -5,
+        }
+    );
+}
+
+#[tokio::test]
+async fn nested_not_flattened() {
+    #[derive(Debug, Row, Serialize, Deserialize, PartialEq)]
+    struct Data {
+        id: u32,
+        items: Vec<(String, u32)>,
+    }
+
+    let client = prepare_database!().with_option("flatten_nested", "0");
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                id      UInt32,
+                items   Nested(name String, count UInt32)
+            )
+            ENGINE = MergeTree ORDER BY id
+            ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    client
+        .query("INSERT INTO test VALUES (1, [('foo', 1), ('bar', 5)])")
+        .execute()
+        .await
+        .unwrap();
+
+    let result = client
+        .query("SELECT ?fields FROM test")
+        .fetch_one::<Data>()
+        .await;
+
+    assert_eq!(
+        result.unwrap(),
+        Data {
+            id: 1,
+            items: vec![("foo".to_string(), 1), ("bar".to_string(), 5)],
+        }
+    );
+}
+
 #[tokio::test]
 async fn deeply_nested_validation_incorrect_fixed_string() {
     #[derive(Debug, Row, Serialize, Deserialize, PartialEq)]
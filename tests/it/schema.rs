@@ -0,0 +1,74 @@
+use clickhouse::{
+    schema::DefaultKind,
+    types::{Column, DataTypeNode},
+};
+
+#[tokio::test]
+async fn introspection() {
+    let client = prepare_database!();
+    let database = client
+        .query("SELECT currentDatabase()")
+        .fetch_one::<String>()
+        .await
+        .unwrap();
+
+    client
+        .execute_script(
+            "CREATE TABLE events (
+                 id UInt64 CODEC(Delta(8), ZSTD(1)),
+                 kind LowCardinality(String) DEFAULT 'click' COMMENT 'the kind',
+                 ts DateTime64(3, 'UTC') TTL toDateTime(ts) + INTERVAL 1 YEAR,
+                 day Date MATERIALIZED toDate(ts)
+             ) ENGINE = ReplacingMergeTree PARTITION BY day ORDER BY id;
+             INSERT INTO events (id, ts) VALUES (1, '2024-01-01 00:00:00'), (2, '2024-01-02 00:00:00');
+             INSERT INTO events (id, ts) VALUES (3, '2024-01-02 00:00:00');
+             CREATE VIEW events_view AS SELECT id FROM events;",
+        )
+        .await
+        .unwrap();
+
+    let columns = client.describe_table(&database, "events").await.unwrap();
+    assert_eq!(
+        columns
+            .iter()
+            .map(|info| info.column.clone())
+            .collect::<Vec<_>>(),
+        [
+            Column::new("id".into(), DataTypeNode::UInt64),
+            Column::new(
+                "kind".into(),
+                DataTypeNode::LowCardinality(Box::new(DataTypeNode::String))
+            ),
+            Column::new(
+                "ts".into(),
+                DataTypeNode::new("DateTime64(3, 'UTC')").unwrap()
+            ),
+            Column::new("day".into(), DataTypeNode::Date),
+        ]
+    );
+
+    assert_eq!(columns[0].codec.as_deref(), Some("Delta(8), ZSTD(1)"));
+    assert_eq!(columns[1].default_kind, Some(DefaultKind::Default));
+    assert_eq!(columns[1].default_expression.as_deref(), Some("'click'"));
+    assert_eq!(columns[1].comment.as_deref(), Some("the kind"));
+    assert!(columns[2].ttl.is_some());
+    assert_eq!(columns[3].default_kind, Some(DefaultKind::Materialized));
+    assert_eq!(columns[0].default_kind, None);
+
+    let databases = client.list_databases().await.unwrap();
+    assert!(databases.contains(&database));
+    assert!(databases.contains(&"system".to_string()));
+
+    let tables = client.list_tables(&database).await.unwrap();
+    assert_eq!(tables, ["events", "events_view"]);
+
+    let engine = client.table_engine(&database, "events").await.unwrap();
+    assert_eq!(engine.name, "ReplacingMergeTree");
+    assert!(engine.full.contains("ORDER BY id"), "{}", engine.full);
+
+    let partitions = client.partitions(&database, "events").await.unwrap();
+    assert_eq!(partitions.len(), 2);
+    assert_eq!(partitions[0].partition, "2024-01-01");
+    assert_eq!((partitions[0].parts, partitions[0].rows), (1, 1));
+    assert_eq!((partitions[1].parts, partitions[1].rows), (2, 2));
+}
@@ -4,7 +4,10 @@ use std::ops::Range;
 
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use time::{macros::datetime, Date, OffsetDateTime};
+use time::{
+    macros::{datetime, time},
+    Date, OffsetDateTime, Time,
+};
 
 use clickhouse::Row;
 
@@ -444,3 +447,169 @@ async fn time64_negative_roundtrip() {
     assert_eq!(fetched_rows.len(), 1);
     assert_eq!(fetched_rows[0], row);
 }
+
+#[tokio::test]
+async fn time_of_day_roundtrip() {
+    let client = prepare_database!();
+
+    client
+        .query(
+            r#"
+            CREATE TABLE test_time_of_day (
+                t         Time,
+                t_opt     Nullable(Time),
+                t64s      Time64(0),
+                t64s_opt  Nullable(Time64(0)),
+                t64ms     Time64(3),
+                t64us     Time64(6),
+                t64ns     Time64(9),
+                t64ns_opt Nullable(Time64(9))
+            ) ENGINE = MergeTree ORDER BY tuple()
+            SETTINGS enable_time_time64_type = 1;
+            "#,
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, Row)]
+    struct TimeRow {
+        #[serde(with = "clickhouse::serde::time::time_of_day")]
+        t: Time,
+        #[serde(with = "clickhouse::serde::time::time_of_day::option")]
+        t_opt: Option<Time>,
+        #[serde(with = "clickhouse::serde::time::time_of_day::secs")]
+        t64s: Time,
+        #[serde(with = "clickhouse::serde::time::time_of_day::secs::option")]
+        t64s_opt: Option<Time>,
+        #[serde(with = "clickhouse::serde::time::time_of_day::millis")]
+        t64ms: Time,
+        #[serde(with = "clickhouse::serde::time::time_of_day::micros")]
+        t64us: Time,
+        #[serde(with = "clickhouse::serde::time::time_of_day::nanos")]
+        t64ns: Time,
+        #[serde(with = "clickhouse::serde::time::time_of_day::nanos::option")]
+        t64ns_opt: Option<Time>,
+    }
+
+    let row = TimeRow {
+        t: time!(12:34:56),
+        t_opt: None,
+        t64s: Time::MIDNIGHT,
+        t64s_opt: Some(time!(23:59:59)),
+        t64ms: time!(01:02:03.456),
+        t64us: time!(01:02:03.456789),
+        t64ns: time!(23:59:59.999999999),
+        t64ns_opt: Some(time!(00:00:00.000000001)),
+    };
+
+    let mut insert = client.insert::<TimeRow>("test_time_of_day").unwrap();
+    insert.write(&row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let fetched = client
+        .query("SELECT ?fields FROM test_time_of_day")
+        .fetch_one::<TimeRow>()
+        .await
+        .unwrap();
+    assert_eq!(fetched, row);
+
+    let as_str = client
+        .query("SELECT toString(t64ms) FROM test_time_of_day")
+        .fetch_one::<String>()
+        .await
+        .unwrap();
+    assert_eq!(as_str, "01:02:03.456");
+
+    // Values out of a day cannot be represented as `time::Time`.
+    #[derive(Debug, Deserialize, Row)]
+    struct Data {
+        #[serde(with = "clickhouse::serde::time::time_of_day")]
+        #[allow(dead_code)]
+        t: Time,
+    }
+
+    let err = client
+        .query("SELECT '25:00:00'::Time AS t")
+        .with_option("enable_time_time64_type", "1")
+        .fetch_one::<Data>()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+}
+
+#[cfg(feature = "time-tz")]
+#[tokio::test]
+async fn datetime_tz() {
+    use time::{macros::offset, UtcOffset};
+
+    let client = prepare_database!();
+
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Row)]
+    struct MyRow {
+        #[serde(with = "clickhouse::serde::time::datetime_tz")]
+        dt: OffsetDateTime,
+        #[serde(with = "clickhouse::serde::time::datetime_tz::option")]
+        dt_opt: Option<OffsetDateTime>,
+        #[serde(with = "clickhouse::serde::time::datetime64_tz::secs")]
+        dt64s: OffsetDateTime,
+        #[serde(with = "clickhouse::serde::time::datetime64_tz::millis")]
+        dt64ms: OffsetDateTime,
+        #[serde(with = "clickhouse::serde::time::datetime64_tz::micros::option")]
+        dt64us_opt: Option<OffsetDateTime>,
+        #[serde(with = "clickhouse::serde::time::datetime64_tz::nanos")]
+        dt64ns: OffsetDateTime,
+    }
+
+    client
+        .query(
+            "
+            CREATE TABLE test(
+                dt          DateTime('Asia/Tokyo'),
+                dt_opt      Nullable(DateTime),
+                dt64s       DateTime64(0, 'Europe/Berlin'),
+                dt64ms      DateTime64(3, 'America/New_York'),
+                dt64us_opt  Nullable(DateTime64(6, 'Asia/Kolkata')),
+                dt64ns      DateTime64(9, 'Australia/Sydney')
+            )
+            ENGINE = MergeTree ORDER BY dt
+        ",
+        )
+        .execute()
+        .await
+        .unwrap();
+
+    // Written values are converted to UTC, their offsets don't matter.
+    let original_row = MyRow {
+        dt: datetime!(2022-11-13 18:27:42 +3),
+        dt_opt: Some(datetime!(2022-11-13 18:27:42 +3)),
+        dt64s: datetime!(2022-11-13 18:27:42 +3),
+        dt64ms: datetime!(2022-11-13 18:27:42.123 +3),
+        dt64us_opt: Some(datetime!(2022-11-13 18:27:42.123456 +3)),
+        dt64ns: datetime!(2022-11-13 18:27:42.123456789 +3),
+    };
+
+    let mut insert = client.insert::<MyRow>("test").unwrap();
+    insert.write(&original_row).await.unwrap();
+    insert.end().await.unwrap();
+
+    let (row, dt_str, dt64ms_str) = client
+        .query("SELECT ?fields, toString(dt), toString(dt64ms) FROM test")
+        .fetch_one::<(MyRow, String, String)>()
+        .await
+        .unwrap();
+
+    // `OffsetDateTime` values are equal if they're the same instant.
+    assert_eq!(row, original_row);
+
+    // Read values have offsets of the columns' time zones, UTC if not specified.
+    assert_eq!(row.dt.offset(), offset!(+9));
+    assert_eq!(row.dt_opt.unwrap().offset(), UtcOffset::UTC);
+    assert_eq!(row.dt64s.offset(), offset!(+1));
+    assert_eq!(row.dt64ms.offset(), offset!(-5));
+    assert_eq!(row.dt64us_opt.unwrap().offset(), offset!(+5:30));
+    assert_eq!(row.dt64ns.offset(), offset!(+11));
+
+    assert_eq!(dt_str, "2022-11-14 00:27:42");
+    assert_eq!(dt64ms_str, "2022-11-13 10:27:42.123");
+}
@@ -105,3 +105,75 @@ async fn variant_data_type() {
 
     assert_eq!(result_rows, rows)
 }
+
+#[tokio::test]
+async fn variant_matched_by_types() {
+    let client = prepare_database!();
+
+    // Declared in arbitrary order.
+    #[clickhouse::variant]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum MyRowVariant {
+        UInt64(u64),
+        String(String),
+        #[clickhouse(data_type = "Array(UInt16)")]
+        Numbers(Vec<u16>),
+        #[serde(with = "clickhouse::serde::time::date")]
+        Date(time::Date),
+    }
+
+    #[derive(Debug, PartialEq, Row, Serialize, Deserialize)]
+    struct MyRow {
+        id: u32,
+        var: MyRowVariant,
+    }
+
+    client
+        .query(
+            "
+            CREATE OR REPLACE TABLE test_var
+            (
+                `id`  UInt32,
+                `var` Variant(String, UInt64, Date, Array(UInt16))
+            )
+            ENGINE = MergeTree
+            ORDER BY id",
+        )
+        .with_option("allow_experimental_variant_type", "1")
+        .execute()
+        .await
+        .unwrap();
+
+    let rows = [
+        MyRowVariant::UInt64(42),
+        MyRowVariant::String("foo".into()),
+        MyRowVariant::Numbers(vec![1, 2]),
+        MyRowVariant::Date(time::Date::from_calendar_date(2021, January, 1).unwrap()),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(id, var)| MyRow { id: id as u32, var })
+    .collect::<Vec<_>>();
+
+    let mut insert = client.insert::<MyRow>("test_var").unwrap();
+    for row in &rows {
+        insert.write(row).await.unwrap();
+    }
+    insert.end().await.unwrap();
+
+    // Adding a type on the server shifts the discriminators of the following types.
+    client
+        .query("ALTER TABLE test_var MODIFY COLUMN var Variant(String, UInt64, Date, Array(UInt16), Bool)")
+        .with_option("allow_experimental_variant_type", "1")
+        .execute()
+        .await
+        .unwrap();
+
+    let result_rows = client
+        .query("SELECT ?fields FROM test_var ORDER BY id")
+        .fetch_all::<MyRow>()
+        .await
+        .unwrap();
+
+    assert_eq!(result_rows, rows)
+}
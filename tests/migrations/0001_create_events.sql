@@ -0,0 +1,4 @@
+CREATE TABLE events (id UInt64) ENGINE = MergeTree ORDER BY id;
+
+-- Comments with ; are fine.
+INSERT INTO events VALUES (1), (2);
@@ -0,0 +1 @@
+ALTER TABLE events ADD COLUMN kind String DEFAULT 'a;b';
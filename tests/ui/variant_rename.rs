@@ -0,0 +1,24 @@
+#[clickhouse::variant]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum Renamed {
+    #[serde(rename = "Int32")]
+    Number(i32),
+    String(String),
+}
+
+#[clickhouse::variant]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum RenamedOnSerialize {
+    #[serde(alias = "n", rename(serialize = "Int32"))]
+    Number(i32),
+    String(String),
+}
+
+#[clickhouse::variant]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Value")]
+enum RenamedEnum {
+    String(String),
+}
+
+fn main() {}
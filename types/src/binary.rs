@@ -0,0 +1,564 @@
+use crate::data_types::{
+    DataTypeNode, DateTimePrecision, DecimalType, EnumType, IntervalKind, JsonType,
+};
+use crate::decoders::{ensure_size, read_string};
+use crate::error::TypesError;
+use crate::leb128::{put_leb128, read_leb128};
+use bytes::{Buf, BufMut};
+use std::collections::HashMap;
+
+// See https://clickhouse.com/docs/sql-reference/data-types/data-types-binary-encoding
+const NOTHING: u8 = 0x00;
+const UINT8: u8 = 0x01;
+const UINT16: u8 = 0x02;
+const UINT32: u8 = 0x03;
+const UINT64: u8 = 0x04;
+const UINT128: u8 = 0x05;
+const UINT256: u8 = 0x06;
+const INT8: u8 = 0x07;
+const INT16: u8 = 0x08;
+const INT32: u8 = 0x09;
+const INT64: u8 = 0x0A;
+const INT128: u8 = 0x0B;
+const INT256: u8 = 0x0C;
+const FLOAT32: u8 = 0x0D;
+const FLOAT64: u8 = 0x0E;
+const DATE: u8 = 0x0F;
+const DATE32: u8 = 0x10;
+const DATETIME: u8 = 0x11;
+const DATETIME_WITH_TZ: u8 = 0x12;
+const DATETIME64: u8 = 0x13;
+const DATETIME64_WITH_TZ: u8 = 0x14;
+const STRING: u8 = 0x15;
+const FIXED_STRING: u8 = 0x16;
+const ENUM8: u8 = 0x17;
+const ENUM16: u8 = 0x18;
+const DECIMAL32: u8 = 0x19;
+const DECIMAL64: u8 = 0x1A;
+const DECIMAL128: u8 = 0x1B;
+const DECIMAL256: u8 = 0x1C;
+const UUID: u8 = 0x1D;
+const ARRAY: u8 = 0x1E;
+const TUPLE: u8 = 0x1F;
+const NAMED_TUPLE: u8 = 0x20;
+const INTERVAL: u8 = 0x22;
+const NULLABLE: u8 = 0x23;
+const LOW_CARDINALITY: u8 = 0x26;
+const MAP: u8 = 0x27;
+const IPV4: u8 = 0x28;
+const IPV6: u8 = 0x29;
+const VARIANT: u8 = 0x2A;
+const DYNAMIC: u8 = 0x2B;
+const CUSTOM: u8 = 0x2C;
+const BOOL: u8 = 0x2D;
+const NESTED: u8 = 0x2F;
+const JSON: u8 = 0x30;
+const BFLOAT16: u8 = 0x31;
+const TIME: u8 = 0x32;
+const TIME64: u8 = 0x34;
+
+/// The default limit of types in `Dynamic`, used if it is not specified.
+const DEFAULT_MAX_DYNAMIC_TYPES: u8 = 32;
+/// The default limit of dynamic paths in `JSON`, used if it is not specified.
+const DEFAULT_MAX_DYNAMIC_PATHS: u64 = 1024;
+/// The default limit of types in a single `JSON` path, used if it is not specified.
+const DEFAULT_JSON_MAX_DYNAMIC_TYPES: u8 = 16;
+const JSON_SERIALIZATION_VERSION: u8 = 0;
+/// Interval kinds in the order of their binary encoding, starting from `0x00`.
+const INTERVAL_KINDS: [IntervalKind; 11] = [
+    IntervalKind::Nanosecond,
+    IntervalKind::Microsecond,
+    IntervalKind::Millisecond,
+    IntervalKind::Second,
+    IntervalKind::Minute,
+    IntervalKind::Hour,
+    IntervalKind::Day,
+    IntervalKind::Week,
+    IntervalKind::Month,
+    IntervalKind::Quarter,
+    IntervalKind::Year,
+];
+
+/// Reads a data type encoded in the binary format,
+/// which is used to prefix the values of `Dynamic` and `JSON` types.
+pub fn read_data_type(mut buffer: impl Buf) -> Result<DataTypeNode, TypesError> {
+    read_data_type_impl(&mut buffer)
+}
+
+/// Puts a data type encoded in the binary format into the provided buffer.
+/// See also [`read_data_type`].
+pub fn put_data_type(mut buffer: impl BufMut, data_type: &DataTypeNode) -> Result<(), TypesError> {
+    put_data_type_impl(&mut buffer, data_type)
+}
+
+fn read_data_type_impl<B: Buf>(buffer: &mut B) -> Result<DataTypeNode, TypesError> {
+    let tag = read_u8(buffer)?;
+    let data_type = match tag {
+        NOTHING => DataTypeNode::Nothing,
+        UINT8 => DataTypeNode::UInt8,
+        UINT16 => DataTypeNode::UInt16,
+        UINT32 => DataTypeNode::UInt32,
+        UINT64 => DataTypeNode::UInt64,
+        UINT128 => DataTypeNode::UInt128,
+        UINT256 => DataTypeNode::UInt256,
+        INT8 => DataTypeNode::Int8,
+        INT16 => DataTypeNode::Int16,
+        INT32 => DataTypeNode::Int32,
+        INT64 => DataTypeNode::Int64,
+        INT128 => DataTypeNode::Int128,
+        INT256 => DataTypeNode::Int256,
+        FLOAT32 => DataTypeNode::Float32,
+        FLOAT64 => DataTypeNode::Float64,
+        BFLOAT16 => DataTypeNode::BFloat16,
+        DATE => DataTypeNode::Date,
+        DATE32 => DataTypeNode::Date32,
+        DATETIME => DataTypeNode::DateTime(None),
+        DATETIME_WITH_TZ => DataTypeNode::DateTime(Some(read_string(&mut *buffer)?)),
+        DATETIME64 => DataTypeNode::DateTime64(read_precision(buffer)?, None),
+        DATETIME64_WITH_TZ => {
+            let precision = read_precision(buffer)?;
+            DataTypeNode::DateTime64(precision, Some(read_string(&mut *buffer)?))
+        }
+        TIME => DataTypeNode::Time,
+        TIME64 => DataTypeNode::Time64(read_precision(buffer)?),
+        STRING => DataTypeNode::String,
+        FIXED_STRING => DataTypeNode::FixedString(read_size(buffer)?),
+        ENUM8 | ENUM16 => {
+            let count = read_size(buffer)?;
+            let mut values = HashMap::with_capacity(count);
+            for _ in 0..count {
+                let name = read_string(&mut *buffer)?;
+                let index = if tag == ENUM8 {
+                    i16::from(read_u8(buffer)? as i8)
+                } else {
+                    ensure_size(&mut *buffer, 2)?;
+                    buffer.get_i16_le()
+                };
+                values.insert(index, name);
+            }
+            let enum_type = if tag == ENUM8 {
+                EnumType::Enum8
+            } else {
+                EnumType::Enum16
+            };
+            DataTypeNode::Enum(enum_type, values)
+        }
+        DECIMAL32 | DECIMAL64 | DECIMAL128 | DECIMAL256 => {
+            let precision = read_u8(buffer)?;
+            let scale = read_u8(buffer)?;
+            DataTypeNode::Decimal(precision, scale, DecimalType::new(precision)?)
+        }
+        UUID => DataTypeNode::UUID,
+        IPV4 => DataTypeNode::IPv4,
+        IPV6 => DataTypeNode::IPv6,
+        BOOL => DataTypeNode::Bool,
+        ARRAY => DataTypeNode::Array(Box::new(read_data_type_impl(buffer)?)),
+        NULLABLE => DataTypeNode::Nullable(Box::new(read_data_type_impl(buffer)?)),
+        LOW_CARDINALITY => DataTypeNode::LowCardinality(Box::new(read_data_type_impl(buffer)?)),
+        MAP => {
+            let key = read_data_type_impl(buffer)?;
+            let value = read_data_type_impl(buffer)?;
+            DataTypeNode::Map([Box::new(key), Box::new(value)])
+        }
+        TUPLE => {
+            let count = read_size(buffer)?;
+            let mut elements = Vec::with_capacity(count);
+            for _ in 0..count {
+                elements.push(read_data_type_impl(buffer)?);
+            }
+            DataTypeNode::Tuple(elements)
+        }
+        NAMED_TUPLE | NESTED => {
+            let count = read_size(buffer)?;
+            let mut names = Vec::with_capacity(count);
+            let mut types = Vec::with_capacity(count);
+            for _ in 0..count {
+                names.push(read_string(&mut *buffer)?);
+                types.push(read_data_type_impl(buffer)?);
+            }
+            if tag == NESTED {
+                DataTypeNode::Nested(names, types)
+            } else {
+                DataTypeNode::NamedTuple(names, types)
+            }
+        }
+        INTERVAL => {
+            let kind = read_u8(buffer)?;
+            match INTERVAL_KINDS.get(usize::from(kind)) {
+                Some(kind) => DataTypeNode::Interval(*kind),
+                None => {
+                    return Err(TypesError::TypeParsingError(format!(
+                        "Invalid binary encoded Interval kind: 0x{kind:02X}"
+                    )))
+                }
+            }
+        }
+        VARIANT => {
+            let count = read_size(buffer)?;
+            let mut variants = Vec::with_capacity(count);
+            for _ in 0..count {
+                variants.push(read_data_type_impl(buffer)?);
+            }
+            DataTypeNode::Variant(variants)
+        }
+        DYNAMIC => {
+            read_u8(buffer)?; // max_types
+            DataTypeNode::Dynamic
+        }
+        JSON => DataTypeNode::JSON(read_json_type(buffer)?),
+        CUSTOM => {
+            let name = read_string(&mut *buffer)?;
+            match name.as_str() {
+                "Point" | "Ring" | "LineString" | "MultiLineString" | "Polygon"
+                | "MultiPolygon" => DataTypeNode::new(&name)?,
+                _ => {
+                    return Err(TypesError::TypeParsingError(format!(
+                        "Unsupported binary encoded custom data type: {name}"
+                    )))
+                }
+            }
+        }
+        tag => {
+            return Err(TypesError::TypeParsingError(format!(
+                "Unsupported binary encoded data type: 0x{tag:02X}"
+            )))
+        }
+    };
+    Ok(data_type)
+}
+
+fn read_json_type<B: Buf>(buffer: &mut B) -> Result<JsonType, TypesError> {
+    let version = read_u8(buffer)?;
+    if version != JSON_SERIALIZATION_VERSION {
+        return Err(TypesError::TypeParsingError(format!(
+            "Unsupported binary encoded JSON serialization version: {version}"
+        )));
+    }
+
+    let max_dynamic_paths = read_leb128(&mut *buffer)?;
+    let max_dynamic_types = read_u8(buffer)?;
+
+    let mut json = JsonType {
+        max_dynamic_paths: (max_dynamic_paths != DEFAULT_MAX_DYNAMIC_PATHS)
+            .then_some(max_dynamic_paths),
+        max_dynamic_types: (max_dynamic_types != DEFAULT_JSON_MAX_DYNAMIC_TYPES)
+            .then_some(max_dynamic_types),
+        ..JsonType::default()
+    };
+
+    for _ in 0..read_size(buffer)? {
+        let path = read_string(&mut *buffer)?;
+        json.typed_paths.push((path, read_data_type_impl(buffer)?));
+    }
+    for _ in 0..read_size(buffer)? {
+        json.skip_paths.push(read_string(&mut *buffer)?);
+    }
+    for _ in 0..read_size(buffer)? {
+        json.skip_regexps.push(read_string(&mut *buffer)?);
+    }
+    Ok(json)
+}
+
+fn put_data_type_impl<B: BufMut>(
+    buffer: &mut B,
+    data_type: &DataTypeNode,
+) -> Result<(), TypesError> {
+    match data_type {
+        DataTypeNode::Nothing => buffer.put_u8(NOTHING),
+        DataTypeNode::UInt8 => buffer.put_u8(UINT8),
+        DataTypeNode::UInt16 => buffer.put_u8(UINT16),
+        DataTypeNode::UInt32 => buffer.put_u8(UINT32),
+        DataTypeNode::UInt64 => buffer.put_u8(UINT64),
+        DataTypeNode::UInt128 => buffer.put_u8(UINT128),
+        DataTypeNode::UInt256 => buffer.put_u8(UINT256),
+        DataTypeNode::Int8 => buffer.put_u8(INT8),
+        DataTypeNode::Int16 => buffer.put_u8(INT16),
+        DataTypeNode::Int32 => buffer.put_u8(INT32),
+        DataTypeNode::Int64 => buffer.put_u8(INT64),
+        DataTypeNode::Int128 => buffer.put_u8(INT128),
+        DataTypeNode::Int256 => buffer.put_u8(INT256),
+        DataTypeNode::Float32 => buffer.put_u8(FLOAT32),
+        DataTypeNode::Float64 => buffer.put_u8(FLOAT64),
+        DataTypeNode::BFloat16 => buffer.put_u8(BFLOAT16),
+        DataTypeNode::Date => buffer.put_u8(DATE),
+        DataTypeNode::Date32 => buffer.put_u8(DATE32),
+        DataTypeNode::DateTime(None) => buffer.put_u8(DATETIME),
+        DataTypeNode::DateTime(Some(tz)) => {
+            buffer.put_u8(DATETIME_WITH_TZ);
+            put_string(buffer, tz);
+        }
+        DataTypeNode::DateTime64(precision, None) => {
+            buffer.put_u8(DATETIME64);
+            buffer.put_u8(precision_to_u8(precision));
+        }
+        DataTypeNode::DateTime64(precision, Some(tz)) => {
+            buffer.put_u8(DATETIME64_WITH_TZ);
+            buffer.put_u8(precision_to_u8(precision));
+            put_string(buffer, tz);
+        }
+        DataTypeNode::Time => buffer.put_u8(TIME),
+        DataTypeNode::Time64(precision) => {
+            buffer.put_u8(TIME64);
+            buffer.put_u8(precision_to_u8(precision));
+        }
+        DataTypeNode::String => buffer.put_u8(STRING),
+        DataTypeNode::FixedString(size) => {
+            buffer.put_u8(FIXED_STRING);
+            put_leb128(&mut *buffer, *size as u64);
+        }
+        DataTypeNode::Enum(enum_type, values) => {
+            let mut values = values.iter().collect::<Vec<_>>();
+            values.sort_by_key(|(index, _)| **index);
+            buffer.put_u8(match enum_type {
+                EnumType::Enum8 => ENUM8,
+                EnumType::Enum16 => ENUM16,
+            });
+            put_leb128(&mut *buffer, values.len() as u64);
+            for (index, name) in values {
+                put_string(buffer, name);
+                match enum_type {
+                    EnumType::Enum8 => buffer.put_i8(*index as i8),
+                    EnumType::Enum16 => buffer.put_i16_le(*index),
+                }
+            }
+        }
+        DataTypeNode::Decimal(precision, scale, decimal_type) => {
+            buffer.put_u8(match decimal_type {
+                DecimalType::Decimal32 => DECIMAL32,
+                DecimalType::Decimal64 => DECIMAL64,
+                DecimalType::Decimal128 => DECIMAL128,
+                DecimalType::Decimal256 => DECIMAL256,
+            });
+            buffer.put_u8(*precision);
+            buffer.put_u8(*scale);
+        }
+        DataTypeNode::UUID => buffer.put_u8(UUID),
+        DataTypeNode::IPv4 => buffer.put_u8(IPV4),
+        DataTypeNode::IPv6 => buffer.put_u8(IPV6),
+        DataTypeNode::Bool => buffer.put_u8(BOOL),
+        DataTypeNode::Array(inner) => {
+            buffer.put_u8(ARRAY);
+            put_data_type_impl(buffer, inner)?;
+        }
+        DataTypeNode::Nullable(inner) => {
+            buffer.put_u8(NULLABLE);
+            put_data_type_impl(buffer, inner)?;
+        }
+        DataTypeNode::LowCardinality(inner) => {
+            buffer.put_u8(LOW_CARDINALITY);
+            put_data_type_impl(buffer, inner)?;
+        }
+        DataTypeNode::Map([key, value]) => {
+            buffer.put_u8(MAP);
+            put_data_type_impl(buffer, key)?;
+            put_data_type_impl(buffer, value)?;
+        }
+        DataTypeNode::Tuple(elements) => {
+            buffer.put_u8(TUPLE);
+            put_leb128(&mut *buffer, elements.len() as u64);
+            for element in elements {
+                put_data_type_impl(buffer, element)?;
+            }
+        }
+        DataTypeNode::NamedTuple(names, types) | DataTypeNode::Nested(names, types) => {
+            let tag = match data_type {
+                DataTypeNode::Nested(..) => NESTED,
+                _ => NAMED_TUPLE,
+            };
+            buffer.put_u8(tag);
+            put_leb128(&mut *buffer, types.len() as u64);
+            for (name, element) in names.iter().zip(types) {
+                put_string(buffer, name);
+                put_data_type_impl(buffer, element)?;
+            }
+        }
+        DataTypeNode::Interval(kind) => {
+            buffer.put_u8(INTERVAL);
+            let index = INTERVAL_KINDS.iter().position(|k| k == kind);
+            buffer.put_u8(index.expect("all interval kinds are listed") as u8);
+        }
+        DataTypeNode::Variant(variants) => {
+            buffer.put_u8(VARIANT);
+            put_leb128(&mut *buffer, variants.len() as u64);
+            for variant in variants {
+                put_data_type_impl(buffer, variant)?;
+            }
+        }
+        DataTypeNode::Dynamic => {
+            buffer.put_u8(DYNAMIC);
+            buffer.put_u8(DEFAULT_MAX_DYNAMIC_TYPES);
+        }
+        DataTypeNode::JSON(json) => {
+            buffer.put_u8(JSON);
+            buffer.put_u8(JSON_SERIALIZATION_VERSION);
+            let max_dynamic_paths = json.max_dynamic_paths.unwrap_or(DEFAULT_MAX_DYNAMIC_PATHS);
+            put_leb128(&mut *buffer, max_dynamic_paths);
+            let max_dynamic_types = json
+                .max_dynamic_types
+                .unwrap_or(DEFAULT_JSON_MAX_DYNAMIC_TYPES);
+            buffer.put_u8(max_dynamic_types);
+            put_leb128(&mut *buffer, json.typed_paths.len() as u64);
+            for (path, data_type) in &json.typed_paths {
+                put_string(buffer, path);
+                put_data_type_impl(buffer, data_type)?;
+            }
+            put_leb128(&mut *buffer, json.skip_paths.len() as u64);
+            for path in &json.skip_paths {
+                put_string(buffer, path);
+            }
+            put_leb128(&mut *buffer, json.skip_regexps.len() as u64);
+            for regexp in &json.skip_regexps {
+                put_string(buffer, regexp);
+            }
+        }
+        DataTypeNode::Point
+        | DataTypeNode::Ring
+        | DataTypeNode::LineString
+        | DataTypeNode::MultiLineString
+        | DataTypeNode::Polygon
+        | DataTypeNode::MultiPolygon => {
+            buffer.put_u8(CUSTOM);
+            put_string(buffer, &data_type.to_string());
+        }
+        DataTypeNode::AggregateFunction(..)
+        | DataTypeNode::SimpleAggregateFunction(..)
+        | DataTypeNode::Object(_)
+        | DataTypeNode::QBit(..) => {
+            return Err(TypesError::TypeParsingError(format!(
+                "Binary encoding of {data_type} is not supported"
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn read_u8(buffer: &mut impl Buf) -> Result<u8, TypesError> {
+    ensure_size(&mut *buffer, 1)?;
+    Ok(buffer.get_u8())
+}
+
+fn read_size(buffer: &mut impl Buf) -> Result<usize, TypesError> {
+    let size = read_leb128(&mut *buffer)?;
+    usize::try_from(size).map_err(|_| TypesError::TypeParsingError(format!("Invalid size: {size}")))
+}
+
+fn read_precision(buffer: &mut impl Buf) -> Result<DateTimePrecision, TypesError> {
+    let precision = read_u8(buffer)?;
+    match precision {
+        0..=9 => DateTimePrecision::new(char::from(b'0' + precision)),
+        _ => Err(TypesError::TypeParsingError(format!(
+            "Invalid precision, expected to be within [0, 9] interval, got {precision}"
+        ))),
+    }
+}
+
+fn precision_to_u8(precision: &DateTimePrecision) -> u8 {
+    precision.to_string().as_bytes()[0] - b'0'
+}
+
+fn put_string(buffer: &mut impl BufMut, value: &str) {
+    put_leb128(&mut *buffer, value.len() as u64);
+    buffer.put_slice(value.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(type_str: &str, expected_bytes: &[u8]) {
+        let data_type = DataTypeNode::new(type_str).unwrap();
+        let mut buffer = Vec::new();
+        put_data_type(&mut buffer, &data_type).unwrap();
+        assert_eq!(buffer, expected_bytes, "encoding {type_str}");
+        let decoded = read_data_type(&mut buffer.as_slice()).unwrap();
+        assert_eq!(decoded, data_type, "decoding {type_str}");
+    }
+
+    #[test]
+    fn test_simple_types() {
+        round_trip("Nothing", &[0x00]);
+        round_trip("UInt8", &[0x01]);
+        round_trip("Int64", &[0x0A]);
+        round_trip("Float64", &[0x0E]);
+        round_trip("String", &[0x15]);
+        round_trip("Bool", &[0x2D]);
+        round_trip("UUID", &[0x1D]);
+        round_trip("IPv6", &[0x29]);
+        round_trip("Date32", &[0x10]);
+        round_trip("DateTime", &[0x11]);
+        round_trip("Time", &[0x32]);
+        round_trip("BFloat16", &[0x31]);
+    }
+
+    #[test]
+    fn test_parametrized_types() {
+        round_trip("DateTime('UTC')", &[0x12, 0x03, b'U', b'T', b'C']);
+        round_trip("DateTime64(3)", &[0x13, 0x03]);
+        round_trip(
+            "DateTime64(9, 'UTC')",
+            &[0x14, 0x09, 0x03, b'U', b'T', b'C'],
+        );
+        round_trip("Time64(6)", &[0x34, 0x06]);
+        round_trip("FixedString(16)", &[0x16, 0x10]);
+        round_trip("Decimal(18, 4)", &[0x1A, 18, 4]);
+        round_trip("Decimal(76, 10)", &[0x1C, 76, 10]);
+        round_trip(
+            "Enum8('a' = -1, 'b' = 2)",
+            &[0x17, 2, 1, b'a', 0xFF, 1, b'b', 2],
+        );
+        round_trip("Enum16('a' = 300)", &[0x18, 1, 1, b'a', 0x2C, 0x01]);
+        round_trip("Point", &[0x2C, 5, b'P', b'o', b'i', b'n', b't']);
+        round_trip("IntervalSecond", &[0x22, 0x03]);
+        round_trip("IntervalYear", &[0x22, 0x0A]);
+    }
+
+    #[test]
+    fn test_nested_types() {
+        round_trip("Array(Nullable(String))", &[0x1E, 0x23, 0x15]);
+        round_trip("LowCardinality(String)", &[0x26, 0x15]);
+        round_trip("Map(String, Array(UInt8))", &[0x27, 0x15, 0x1E, 0x01]);
+        round_trip("Tuple(UInt8, String)", &[0x1F, 2, 0x01, 0x15]);
+        round_trip("Variant(String, UInt32)", &[0x2A, 2, 0x15, 0x03]);
+        round_trip(
+            "Nested(a UInt32, b String)",
+            &[0x2F, 2, 1, b'a', 0x03, 1, b'b', 0x15],
+        );
+        round_trip("Dynamic", &[0x2B, 32]);
+        round_trip(
+            "JSON",
+            &[0x30, 0, 0x80, 0x08, 16, 0, 0, 0], // max_dynamic_paths = 1024 as LEB128
+        );
+        round_trip(
+            "JSON(max_dynamic_types=2, a UInt8, SKIP b, SKIP REGEXP 'c')",
+            &[
+                0x30, 0, 0x80, 0x08, 2, 1, 1, b'a', 0x01, 1, 1, b'b', 1, 1, b'c',
+            ],
+        );
+    }
+
+    #[test]
+    fn test_named_tuple() {
+        round_trip(
+            "Tuple(a UInt8, b String)",
+            &[0x20, 2, 1, b'a', 0x01, 1, b'b', 0x15],
+        );
+        let input = [0x20, 2, 1, b'a', 0x01, 1, b'b', 0x15];
+        assert_eq!(
+            read_data_type(&mut input.as_slice()).unwrap(),
+            DataTypeNode::NamedTuple(
+                vec!["a".to_string(), "b".to_string()],
+                vec![DataTypeNode::UInt8, DataTypeNode::String]
+            )
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(read_data_type(&mut [].as_slice()).is_err());
+        assert!(read_data_type(&mut [0x1E].as_slice()).is_err());
+        assert!(read_data_type(&mut [0xFF].as_slice()).is_err());
+        assert!(read_data_type(&mut [0x13, 10].as_slice()).is_err());
+        let custom = [0x2C, 3, b'F', b'o', b'o'];
+        assert!(read_data_type(&mut custom.as_slice()).is_err());
+    }
+}
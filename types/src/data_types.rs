@@ -27,6 +27,9 @@ impl Display for Column {
 
 /// Represents a data type in ClickHouse.
 /// See <https://clickhouse.com/docs/sql-reference/data-types>
+///
+/// The [`Display`] implementation renders the type in the same form ClickHouse does,
+/// so that `DataTypeNode::new(&t.to_string())` returns `t` back.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
 #[allow(missing_docs)]
@@ -79,19 +82,46 @@ pub enum DataTypeNode {
 
     Array(Box<DataTypeNode>),
     Tuple(Vec<DataTypeNode>),
+    /// Names and types of the elements, e.g. `Tuple(a UInt8, b String)`.
+    /// Types are defined as a separate vector, so they can be used as a slice
+    NamedTuple(Vec<String>, Vec<DataTypeNode>),
+    /// Names are kept escaped as in the definition, e.g. `f\'` for `'f\''`
     Enum(EnumType, HashMap<i16, String>),
 
     /// Key-Value pairs are defined as an array, so it can be used as a slice
     Map([Box<DataTypeNode>; 2]),
 
-    /// Function name and its arguments
+    /// Function name (with parameters, if any, e.g. `quantiles(0.5, 0.9)`) and its arguments
     AggregateFunction(String, Vec<DataTypeNode>),
+    /// Function name and the type of values, which are stored as is
+    SimpleAggregateFunction(String, Box<DataTypeNode>),
 
-    /// Contains all possible types for this variant
+    /// Names and types of the elements. In `RowBinary`, it is encoded as `Array(Tuple(..))`,
+    /// unless the column is flattened into separate `name.element` arrays (the default).
+    /// Types are defined as a separate vector, so they can be used as a slice
+    Nested(Vec<String>, Vec<DataTypeNode>),
+
+    /// Stored as an `Int64`
+    Interval(IntervalKind),
+
+    /// Contains all possible types for this variant, in the order of the definition.
+    /// ClickHouse sorts them by name, and discriminators of values are indices in this list,
+    /// so the order is preserved both when parsing and rendering.
     Variant(Vec<DataTypeNode>),
 
     Dynamic,
-    JSON,
+    JSON(JsonType),
+
+    /// The type of `NULL` literals and empty arrays, e.g. `Array(Nothing)`.
+    Nothing,
+
+    /// The deprecated `Object('json')` type with its raw parameters, e.g. `'json'`.
+    /// It cannot be used in `RowBinary`
+    Object(String),
+
+    /// Element type (`BFloat16`, `Float32` or `Float64`) and dimension of a vector.
+    /// In `RowBinary`, it is encoded as `dimension` elements without a length prefix
+    QBit(Box<DataTypeNode>, usize),
 
     Point,
     Ring,
@@ -130,7 +160,8 @@ impl DataTypeNode {
             "IPv6" => Ok(Self::IPv6),
             "Bool" => Ok(Self::Bool),
             "Dynamic" => Ok(Self::Dynamic),
-            "JSON" => Ok(Self::JSON),
+            "JSON" => Ok(Self::JSON(JsonType::default())),
+            "Nothing" => Ok(Self::Nothing),
             "Point" => Ok(Self::Point),
             "Ring" => Ok(Self::Ring),
             "LineString" => Ok(Self::LineString),
@@ -139,6 +170,14 @@ impl DataTypeNode {
             "MultiPolygon" => Ok(Self::MultiPolygon),
 
             str if str.starts_with("Decimal") => parse_decimal(str),
+            str if str.starts_with("AggregateFunction(") => parse_aggregate_function(str),
+            str if str.starts_with("SimpleAggregateFunction(") => {
+                parse_simple_aggregate_function(str)
+            }
+            str if str.starts_with("Interval") => parse_interval(str),
+            str if str.starts_with("Nested(") => parse_nested(str),
+            str if str.starts_with("Object(") => parse_object(str),
+            str if str.starts_with("QBit(") => parse_qbit(str),
             str if str.starts_with("DateTime64") => parse_datetime64(str),
             str if str.starts_with("DateTime") => parse_datetime(str),
             str if str.starts_with("Time64") => parse_time64(str),
@@ -153,6 +192,7 @@ impl DataTypeNode {
             str if str.starts_with("Map") => parse_map(str),
             str if str.starts_with("Tuple") => parse_tuple(str),
             str if str.starts_with("Variant") => parse_variant(str),
+            str if str.starts_with("JSON(") => parse_json(str),
 
             // ...
             str => Err(TypesError::TypeParsingError(format!(
@@ -223,6 +263,11 @@ impl Display for DataTypeNode {
                 }
                 write!(f, ")")
             }
+            NamedTuple(names, types) => {
+                write!(f, "Tuple(")?;
+                write_named_elements(f, names, types)?;
+                write!(f, ")")
+            }
             Map([key, value]) => {
                 write!(f, "Map({key}, {value})")
             }
@@ -242,15 +287,21 @@ impl Display for DataTypeNode {
                 write!(f, ")")
             }
             AggregateFunction(func_name, args) => {
-                write!(f, "AggregateFunction({func_name}, ")?;
-                for (i, element) in args.iter().enumerate() {
-                    if i > 0 {
-                        write!(f, ", ")?;
-                    }
-                    write!(f, "{element}")?;
+                write!(f, "AggregateFunction({func_name}")?;
+                for element in args {
+                    write!(f, ", {element}")?;
                 }
                 write!(f, ")")
             }
+            SimpleAggregateFunction(func_name, inner) => {
+                write!(f, "SimpleAggregateFunction({func_name}, {inner})")
+            }
+            Nested(names, types) => {
+                write!(f, "Nested(")?;
+                write_named_elements(f, names, types)?;
+                write!(f, ")")
+            }
+            Interval(kind) => write!(f, "Interval{kind}"),
             FixedString(size) => {
                 write!(f, "FixedString({size})")
             }
@@ -264,7 +315,10 @@ impl Display for DataTypeNode {
                 }
                 write!(f, ")")
             }
-            JSON => write!(f, "JSON"),
+            JSON(json) => write!(f, "JSON{json}"),
+            Nothing => write!(f, "Nothing"),
+            Object(params) => write!(f, "Object({params})"),
+            QBit(element, dimension) => write!(f, "QBit({element}, {dimension})"),
             Dynamic => write!(f, "Dynamic"),
             Point => write!(f, "Point"),
             Ring => write!(f, "Ring"),
@@ -276,6 +330,148 @@ impl Display for DataTypeNode {
     }
 }
 
+/// Parameters of the `JSON` data type, e.g.
+/// `JSON(max_dynamic_paths=10, a.b UInt32, SKIP a.c, SKIP REGEXP 'x.*')`.
+/// See also: <https://clickhouse.com/docs/sql-reference/data-types/newjson>
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonType {
+    /// The limit of paths stored as subcolumns, if not the default one.
+    pub max_dynamic_paths: Option<u64>,
+    /// The limit of types stored in a single dynamic path, if not the default one.
+    pub max_dynamic_types: Option<u8>,
+    /// Paths with explicitly declared types. Their values are encoded without a type prefix.
+    pub typed_paths: Vec<(String, DataTypeNode)>,
+    /// Paths that are not stored.
+    pub skip_paths: Vec<String>,
+    /// Regular expressions of paths that are not stored.
+    pub skip_regexps: Vec<String>,
+}
+
+impl JsonType {
+    /// Returns the declared type of the path, if any.
+    pub fn typed_path(&self, path: &str) -> Option<&DataTypeNode> {
+        self.typed_paths
+            .iter()
+            .find(|(typed, _)| typed == path)
+            .map(|(_, data_type)| data_type)
+    }
+
+    fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Writes the parameters in parentheses, or nothing for a plain `JSON`.
+impl Display for JsonType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_default() {
+            return Ok(());
+        }
+
+        let mut params = Vec::new();
+        if let Some(max) = self.max_dynamic_paths {
+            params.push(format!("max_dynamic_paths={max}"));
+        }
+        if let Some(max) = self.max_dynamic_types {
+            params.push(format!("max_dynamic_types={max}"));
+        }
+        for (path, data_type) in &self.typed_paths {
+            params.push(format!("{} {data_type}", quote_json_path(path)));
+        }
+        for path in &self.skip_paths {
+            params.push(format!("SKIP {}", quote_json_path(path)));
+        }
+        for regexp in &self.skip_regexps {
+            params.push(format!(
+                "SKIP REGEXP '{}'",
+                regexp.replace('\\', "\\\\").replace('\'', "\\'")
+            ));
+        }
+        write!(f, "({})", params.join(", "))
+    }
+}
+
+fn quote_json_path(path: &str) -> String {
+    let is_plain = path
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'.');
+    // Otherwise, the path would be taken for a parameter.
+    let is_keyword = path == "SKIP" || path.starts_with("max_dynamic_");
+    if is_plain && !is_keyword && !path.is_empty() {
+        path.to_string()
+    } else {
+        format!("`{}`", path.replace('\\', "\\\\").replace('`', "\\`"))
+    }
+}
+
+fn write_named_elements(
+    f: &mut Formatter<'_>,
+    names: &[String],
+    types: &[DataTypeNode],
+) -> std::fmt::Result {
+    for (i, (name, element)) in names.iter().zip(types).enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{} {element}", quote_identifier(name))?;
+    }
+    Ok(())
+}
+
+fn quote_identifier(name: &str) -> String {
+    let is_plain = name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+    if is_plain && !name.is_empty() && !name.as_bytes()[0].is_ascii_digit() {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('\\', "\\\\").replace('`', "\\`"))
+    }
+}
+
+/// The unit of an `Interval*` data type, e.g. `IntervalSecond`.
+/// See also: <https://clickhouse.com/docs/sql-reference/data-types/special-data-types/interval>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum IntervalKind {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl IntervalKind {
+    pub(crate) fn new(name: &str) -> Result<Self, TypesError> {
+        match name {
+            "Nanosecond" => Ok(Self::Nanosecond),
+            "Microsecond" => Ok(Self::Microsecond),
+            "Millisecond" => Ok(Self::Millisecond),
+            "Second" => Ok(Self::Second),
+            "Minute" => Ok(Self::Minute),
+            "Hour" => Ok(Self::Hour),
+            "Day" => Ok(Self::Day),
+            "Week" => Ok(Self::Week),
+            "Month" => Ok(Self::Month),
+            "Quarter" => Ok(Self::Quarter),
+            "Year" => Ok(Self::Year),
+            _ => Err(TypesError::TypeParsingError(format!(
+                "Invalid Interval kind: {name}"
+            ))),
+        }
+    }
+}
+
+impl Display for IntervalKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
 /// Represents the underlying integer size of an Enum type.
 #[derive(Debug, Clone, PartialEq)]
 pub enum EnumType {
@@ -368,9 +564,9 @@ impl DecimalType {
         } else if precision <= 76 {
             Ok(DecimalType::Decimal256)
         } else {
-            return Err(TypesError::TypeParsingError(format!(
+            Err(TypesError::TypeParsingError(format!(
                 "Invalid Decimal precision: {precision}"
-            )));
+            )))
         }
     }
 }
@@ -475,9 +671,9 @@ fn parse_decimal(input: &str) -> Result<DataTypeNode, TypesError> {
             })?;
         let precision = parsed[0];
         let scale = parsed[1];
-        if scale < 1 || precision < 1 {
+        if precision < 1 {
             return Err(TypesError::TypeParsingError(format!(
-                "Invalid Decimal format, expected Decimal(P, S) with P > 0 and S > 0, got {input}"
+                "Invalid Decimal format, expected Decimal(P, S) with P > 0, got {input}"
             )));
         }
         if precision < scale {
@@ -493,6 +689,106 @@ fn parse_decimal(input: &str) -> Result<DataTypeNode, TypesError> {
     )))
 }
 
+fn parse_aggregate_function(input: &str) -> Result<DataTypeNode, TypesError> {
+    let Some(inner_str) = input
+        .strip_prefix("AggregateFunction(")
+        .and_then(|str| str.strip_suffix(')'))
+    else {
+        return Err(TypesError::TypeParsingError(format!(
+            "Invalid AggregateFunction format, expected AggregateFunction(func, Type1, ...), got {input}"
+        )));
+    };
+
+    let mut elements = split_inner_types(inner_str)?.into_iter();
+    let Some(func_name) = elements.next().filter(|name| !name.is_empty()) else {
+        return Err(TypesError::TypeParsingError(format!(
+            "Expected a function name in an AggregateFunction from input {input}"
+        )));
+    };
+    let args = elements
+        .map(DataTypeNode::new)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(DataTypeNode::AggregateFunction(func_name.to_string(), args))
+}
+
+fn parse_simple_aggregate_function(input: &str) -> Result<DataTypeNode, TypesError> {
+    let elements = input
+        .strip_prefix("SimpleAggregateFunction(")
+        .and_then(|str| str.strip_suffix(')'))
+        .map(split_inner_types)
+        .transpose()?;
+    match elements.as_deref() {
+        Some([func_name, inner_type]) if !func_name.is_empty() => {
+            Ok(DataTypeNode::SimpleAggregateFunction(
+                func_name.to_string(),
+                Box::new(DataTypeNode::new(inner_type)?),
+            ))
+        }
+        _ => Err(TypesError::TypeParsingError(format!(
+            "Invalid SimpleAggregateFunction format, expected SimpleAggregateFunction(func, Type), got {input}"
+        ))),
+    }
+}
+
+fn parse_interval(input: &str) -> Result<DataTypeNode, TypesError> {
+    let kind = IntervalKind::new(&input["Interval".len()..])?;
+    Ok(DataTypeNode::Interval(kind))
+}
+
+fn parse_nested(input: &str) -> Result<DataTypeNode, TypesError> {
+    let invalid = || {
+        TypesError::TypeParsingError(format!(
+            "Invalid Nested format, expected Nested(name1 Type1, name2 Type2, ...), got {input}"
+        ))
+    };
+    let inner_str = input
+        .strip_prefix("Nested(")
+        .and_then(|str| str.strip_suffix(')'))
+        .ok_or_else(invalid)?;
+
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    for element in split_inner_types(inner_str)? {
+        let (name, type_str) = split_element_name(element).ok_or_else(invalid)?;
+        names.push(name);
+        types.push(DataTypeNode::new(type_str)?);
+    }
+    if types.is_empty() {
+        return Err(invalid());
+    }
+    Ok(DataTypeNode::Nested(names, types))
+}
+
+fn parse_object(input: &str) -> Result<DataTypeNode, TypesError> {
+    match input
+        .strip_prefix("Object(")
+        .and_then(|str| str.strip_suffix(')'))
+    {
+        Some(params) if !params.is_empty() => Ok(DataTypeNode::Object(params.to_string())),
+        _ => Err(TypesError::TypeParsingError(format!(
+            "Invalid Object format, expected Object('json'), got {input}"
+        ))),
+    }
+}
+
+fn parse_qbit(input: &str) -> Result<DataTypeNode, TypesError> {
+    let elements = input
+        .strip_prefix("QBit(")
+        .and_then(|str| str.strip_suffix(')'))
+        .map(split_inner_types)
+        .transpose()?;
+    if let Some([element_type, dimension]) = elements.as_deref() {
+        if let Ok(dimension) = dimension.parse::<usize>() {
+            let element_type = DataTypeNode::new(element_type)?;
+            return Ok(DataTypeNode::QBit(Box::new(element_type), dimension));
+        }
+    }
+    Err(TypesError::TypeParsingError(format!(
+        "Invalid QBit format, expected QBit(ElementType, dimension), got {input}"
+    )))
+}
+
 fn parse_datetime64(input: &str) -> Result<DataTypeNode, TypesError> {
     if input.len() >= 13 {
         let mut chars = input[11..input.len() - 1].chars();
@@ -570,13 +866,34 @@ fn parse_map(input: &str) -> Result<DataTypeNode, TypesError> {
 fn parse_tuple(input: &str) -> Result<DataTypeNode, TypesError> {
     if input.len() > 7 {
         let inner_types_str = &input[6..input.len() - 1];
-        let inner_types = parse_inner_types(inner_types_str)?;
-        if inner_types.is_empty() {
+        let elements = split_inner_types(inner_types_str)?;
+        if elements.is_empty() {
             return Err(TypesError::TypeParsingError(format!(
                 "Expected at least one inner element in a Tuple from input {input}"
             )));
         }
-        return Ok(DataTypeNode::Tuple(inner_types));
+
+        // Either all elements are named, or none of them
+        let mut names = Vec::new();
+        let mut types = Vec::with_capacity(elements.len());
+        for element in elements {
+            match split_element_name(element) {
+                Some((name, type_str)) if names.len() == types.len() => {
+                    names.push(name);
+                    types.push(DataTypeNode::new(type_str)?);
+                }
+                None if names.is_empty() => types.push(DataTypeNode::new(element)?),
+                _ => {
+                    return Err(TypesError::TypeParsingError(format!(
+                        "Expected either all or none of the Tuple elements to be named, got {input}"
+                    )))
+                }
+            }
+        }
+        if names.is_empty() {
+            return Ok(DataTypeNode::Tuple(types));
+        }
+        return Ok(DataTypeNode::NamedTuple(names, types));
     }
     Err(TypesError::TypeParsingError(format!(
         "Invalid Tuple format, expected Tuple(Type1, Type2, ...), got {input}"
@@ -594,19 +911,121 @@ fn parse_variant(input: &str) -> Result<DataTypeNode, TypesError> {
     )))
 }
 
-/// Considers the element type parsed once we reach a comma outside of parens AND after an unescaped tick.
+fn parse_json(input: &str) -> Result<DataTypeNode, TypesError> {
+    let Some(params_str) = input
+        .strip_prefix("JSON(")
+        .and_then(|str| str.strip_suffix(')'))
+    else {
+        return Err(TypesError::TypeParsingError(format!(
+            "Invalid JSON format, expected JSON(params), got {input}"
+        )));
+    };
+
+    let invalid_param = |param: &str| {
+        TypesError::TypeParsingError(format!("Invalid JSON parameter `{param}` in {input}"))
+    };
+
+    let mut json = JsonType::default();
+    for param in split_inner_types(params_str)? {
+        if let Some(value) = param.strip_prefix("max_dynamic_paths") {
+            let value = value.trim_start().trim_start_matches('=').trim_start();
+            json.max_dynamic_paths = Some(value.parse().map_err(|_| invalid_param(param))?);
+        } else if let Some(value) = param.strip_prefix("max_dynamic_types") {
+            let value = value.trim_start().trim_start_matches('=').trim_start();
+            json.max_dynamic_types = Some(value.parse().map_err(|_| invalid_param(param))?);
+        } else if let Some(regexp) = param.strip_prefix("SKIP REGEXP ") {
+            let regexp = unquote(regexp.trim(), '\'').ok_or_else(|| invalid_param(param))?;
+            json.skip_regexps.push(regexp);
+        } else if let Some(path) = param.strip_prefix("SKIP ") {
+            let (path, _) = parse_json_path(path.trim()).ok_or_else(|| invalid_param(param))?;
+            json.skip_paths.push(path);
+        } else {
+            let (path, rest) = parse_json_path(param).ok_or_else(|| invalid_param(param))?;
+            let data_type = DataTypeNode::new(rest.trim_start())?;
+            json.typed_paths.push((path, data_type));
+        }
+    }
+
+    Ok(DataTypeNode::JSON(json))
+}
+
+/// Parses a possibly backquoted path, returning it and the rest of the input.
+fn parse_json_path(input: &str) -> Option<(String, &str)> {
+    if input.starts_with('`') {
+        let end = find_closing_quote(input, '`')?;
+        Some((unquote(&input[..=end], '`')?, &input[end + 1..]))
+    } else {
+        let end = input.find(' ').unwrap_or(input.len());
+        Some((input[..end].to_string(), &input[end..]))
+    }
+}
+
+/// Splits an element of a named tuple or `Nested` into its (possibly backquoted) name and type,
+/// e.g. `a UInt8` or `` `a b` UInt8 ``. Returns `None` if the element has no name.
+fn split_element_name(element: &str) -> Option<(String, &str)> {
+    if element.starts_with('`') {
+        let (name, rest) = parse_json_path(element)?;
+        let type_str = rest.strip_prefix(' ')?;
+        return Some((name, type_str));
+    }
+
+    let (name, type_str) = element.split_once(' ')?;
+    let is_name = !name.is_empty() && !name.contains(['(', '\'', ',']);
+    is_name.then(|| (name.to_string(), type_str))
+}
+
+/// Returns the index of the closing (unescaped) quote, given that the input starts with a quote.
+fn find_closing_quote(input: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (i, char) in input.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if char == '\\' {
+            escaped = true;
+        } else if char == quote {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn unquote(input: &str, quote: char) -> Option<String> {
+    let inner = input.strip_prefix(quote)?.strip_suffix(quote)?;
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(char) = chars.next() {
+        if char == '\\' {
+            result.push(chars.next()?);
+        } else {
+            result.push(char);
+        }
+    }
+    Some(result)
+}
+
+/// Considers the element type parsed once we reach a comma outside of parens AND outside of quotes
+/// (either ticks or backticks).
 /// The most complicated cases are values names in the self-defined Enum types:
 /// ```
 ///  let input1 = "Tuple(Enum8('f\'()' = 1))";  // the result is  `f\'()`
 ///  let input2 = "Tuple(Enum8('(' = 1))";       // the result is  `(`
 /// ```
 fn parse_inner_types(input: &str) -> Result<Vec<DataTypeNode>, TypesError> {
-    let mut inner_types: Vec<DataTypeNode> = Vec::new();
+    split_inner_types(input)?
+        .into_iter()
+        .map(DataTypeNode::new)
+        .collect()
+}
+
+/// Splits the comma-separated list of inner types (or other parameters) using the same rules
+/// as [`parse_inner_types`], without parsing the elements.
+fn split_inner_types(input: &str) -> Result<Vec<&str>, TypesError> {
+    let mut inner_types: Vec<&str> = Vec::new();
 
     let input_bytes = input.as_bytes();
 
     let mut open_parens = 0;
-    let mut quote_open = false;
+    let mut open_quote: Option<u8> = None;
     let mut char_escaped = false;
     let mut last_element_index = 0;
 
@@ -616,23 +1035,20 @@ fn parse_inner_types(input: &str) -> Result<Vec<DataTypeNode>, TypesError> {
             char_escaped = false;
         } else if input_bytes[i] == b'\\' {
             char_escaped = true;
-        } else if input_bytes[i] == b'\'' {
-            quote_open = !quote_open; // unescaped quote
-        } else if !quote_open {
+        } else if input_bytes[i] == b'\'' || input_bytes[i] == b'`' {
+            // unescaped quote, either a string or an identifier
+            match open_quote {
+                None => open_quote = Some(input_bytes[i]),
+                Some(quote) if quote == input_bytes[i] => open_quote = None,
+                Some(_) => {}
+            }
+        } else if open_quote.is_none() {
             if input_bytes[i] == b'(' {
                 open_parens += 1;
             } else if input_bytes[i] == b')' {
                 open_parens -= 1;
             } else if input_bytes[i] == b',' && open_parens == 0 {
-                let data_type_str = String::from_utf8(input_bytes[last_element_index..i].to_vec())
-                    .map_err(|_| {
-                        TypesError::TypeParsingError(format!(
-                            "Invalid UTF-8 sequence in input for the inner data type: {}",
-                            &input[last_element_index..]
-                        ))
-                    })?;
-                let data_type = DataTypeNode::new(&data_type_str)?;
-                inner_types.push(data_type);
+                inner_types.push(element_str(input, last_element_index, i)?);
                 // Skip ', ' (comma and space)
                 if i + 2 <= input_bytes.len() && input_bytes[i + 1] == b' ' {
                     i += 2;
@@ -648,20 +1064,22 @@ fn parse_inner_types(input: &str) -> Result<Vec<DataTypeNode>, TypesError> {
 
     // Push the remaining part of the type if it seems to be valid (at least all parentheses are closed)
     if open_parens == 0 && last_element_index < input_bytes.len() {
-        let data_type_str =
-            String::from_utf8(input_bytes[last_element_index..].to_vec()).map_err(|_| {
-                TypesError::TypeParsingError(format!(
-                    "Invalid UTF-8 sequence in input for the inner data type: {}",
-                    &input[last_element_index..]
-                ))
-            })?;
-        let data_type = DataTypeNode::new(&data_type_str)?;
-        inner_types.push(data_type);
+        inner_types.push(element_str(input, last_element_index, input_bytes.len())?);
     }
 
     Ok(inner_types)
 }
 
+#[inline]
+fn element_str(input: &str, start: usize, end: usize) -> Result<&str, TypesError> {
+    input.get(start..end).ok_or_else(|| {
+        TypesError::TypeParsingError(format!(
+            "Invalid UTF-8 sequence in input for the inner data type: {}",
+            String::from_utf8_lossy(&input.as_bytes()[start..])
+        ))
+    })
+}
+
 #[inline]
 fn parse_enum_index(input_bytes: &[u8], input: &str) -> Result<i16, TypesError> {
     String::from_utf8(input_bytes.to_vec())
@@ -775,6 +1193,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_data_type_new_aggregate_function() {
+        assert_eq!(
+            DataTypeNode::new("AggregateFunction(sum, UInt64)").unwrap(),
+            DataTypeNode::AggregateFunction("sum".to_string(), vec![DataTypeNode::UInt64])
+        );
+        assert_eq!(
+            DataTypeNode::new("AggregateFunction(count)").unwrap(),
+            DataTypeNode::AggregateFunction("count".to_string(), vec![])
+        );
+        assert_eq!(
+            DataTypeNode::new("AggregateFunction(quantiles(0.5, 0.9), Nullable(Float64))").unwrap(),
+            DataTypeNode::AggregateFunction(
+                "quantiles(0.5, 0.9)".to_string(),
+                vec![DataTypeNode::Nullable(Box::new(DataTypeNode::Float64))]
+            )
+        );
+        assert_eq!(
+            DataTypeNode::new("AggregateFunction(argMax, String, DateTime('UTC'))").unwrap(),
+            DataTypeNode::AggregateFunction(
+                "argMax".to_string(),
+                vec![
+                    DataTypeNode::String,
+                    DataTypeNode::DateTime(Some("UTC".to_string()))
+                ]
+            )
+        );
+        assert!(DataTypeNode::new("AggregateFunction").is_err());
+        assert!(DataTypeNode::new("AggregateFunction()").is_err());
+        assert!(DataTypeNode::new("AggregateFunction(sum, Foo)").is_err());
+    }
+
+    #[test]
+    fn test_data_type_new_simple_aggregate_function() {
+        assert_eq!(
+            DataTypeNode::new("SimpleAggregateFunction(any, UInt64)").unwrap(),
+            DataTypeNode::SimpleAggregateFunction(
+                "any".to_string(),
+                Box::new(DataTypeNode::UInt64)
+            )
+        );
+        assert_eq!(
+            DataTypeNode::new("SimpleAggregateFunction(groupUniqArrayArray, Array(String))")
+                .unwrap(),
+            DataTypeNode::SimpleAggregateFunction(
+                "groupUniqArrayArray".to_string(),
+                Box::new(DataTypeNode::Array(Box::new(DataTypeNode::String)))
+            )
+        );
+        assert!(DataTypeNode::new("SimpleAggregateFunction(any)").is_err());
+        assert!(DataTypeNode::new("SimpleAggregateFunction(any, UInt64, UInt8)").is_err());
+        assert!(DataTypeNode::new("SimpleAggregateFunction(, UInt64)").is_err());
+    }
+
+    #[test]
+    fn test_data_type_new_nested() {
+        assert_eq!(
+            DataTypeNode::new("Nested(a UInt32, b String)").unwrap(),
+            DataTypeNode::Nested(
+                vec!["a".to_string(), "b".to_string()],
+                vec![DataTypeNode::UInt32, DataTypeNode::String]
+            )
+        );
+        assert_eq!(
+            DataTypeNode::new("Nested(`a b` DateTime64(3, 'UTC'))").unwrap(),
+            DataTypeNode::Nested(
+                vec!["a b".to_string()],
+                vec![DataTypeNode::DateTime64(
+                    DateTimePrecision::Precision3,
+                    Some("UTC".to_string())
+                )]
+            )
+        );
+        assert!(DataTypeNode::new("Nested()").is_err());
+        assert!(DataTypeNode::new("Nested(UInt32)").is_err());
+        assert!(DataTypeNode::new("Nested(a Foo)").is_err());
+    }
+
+    #[test]
+    fn test_data_type_new_interval() {
+        assert_eq!(
+            DataTypeNode::new("IntervalNanosecond").unwrap(),
+            DataTypeNode::Interval(IntervalKind::Nanosecond)
+        );
+        assert_eq!(
+            DataTypeNode::new("IntervalDay").unwrap(),
+            DataTypeNode::Interval(IntervalKind::Day)
+        );
+        assert_eq!(
+            DataTypeNode::new("IntervalYear").unwrap(),
+            DataTypeNode::Interval(IntervalKind::Year)
+        );
+        assert_eq!(
+            DataTypeNode::Interval(IntervalKind::Quarter).to_string(),
+            "IntervalQuarter"
+        );
+        assert!(DataTypeNode::new("Interval").is_err());
+        assert!(DataTypeNode::new("IntervalDecade").is_err());
+    }
+
+    #[test]
+    fn test_data_type_new_object_and_qbit() {
+        assert_eq!(
+            DataTypeNode::new("Object('json')").unwrap(),
+            DataTypeNode::Object("'json'".to_string())
+        );
+        assert_eq!(
+            DataTypeNode::new("QBit(Float32, 128)").unwrap(),
+            DataTypeNode::QBit(Box::new(DataTypeNode::Float32), 128)
+        );
+        assert!(DataTypeNode::new("Object()").is_err());
+        assert!(DataTypeNode::new("QBit(Float32)").is_err());
+        assert!(DataTypeNode::new("QBit(Float32, x)").is_err());
+    }
+
     #[test]
     fn test_tuple_display() {
         let empty = DataTypeNode::Tuple(vec![]);
@@ -885,7 +1418,11 @@ mod tests {
         assert_eq!(DataTypeNode::new("IPv6").unwrap(), DataTypeNode::IPv6);
         assert_eq!(DataTypeNode::new("Bool").unwrap(), DataTypeNode::Bool);
         assert_eq!(DataTypeNode::new("Dynamic").unwrap(), DataTypeNode::Dynamic);
-        assert_eq!(DataTypeNode::new("JSON").unwrap(), DataTypeNode::JSON);
+        assert_eq!(
+            DataTypeNode::new("JSON").unwrap(),
+            DataTypeNode::JSON(JsonType::default())
+        );
+        assert_eq!(DataTypeNode::new("Nothing").unwrap(), DataTypeNode::Nothing);
         assert!(DataTypeNode::new("SomeUnknownType").is_err());
     }
 
@@ -955,6 +1492,10 @@ mod tests {
             DataTypeNode::new("Decimal(42, 8)").unwrap(),
             DataTypeNode::Decimal(42, 8, DecimalType::Decimal256)
         );
+        assert_eq!(
+            DataTypeNode::new("Decimal(10, 0)").unwrap(),
+            DataTypeNode::Decimal(10, 0, DecimalType::Decimal64)
+        );
         assert!(DataTypeNode::new("Decimal").is_err());
         assert!(DataTypeNode::new("Decimal(").is_err());
         assert!(DataTypeNode::new("Decimal()").is_err());
@@ -1288,6 +1829,71 @@ mod tests {
         assert!(DataTypeNode::new("Tuple(Int32, String, X)").is_err());
     }
 
+    #[test]
+    fn test_data_type_new_named_tuple() {
+        assert_eq!(
+            DataTypeNode::new("Tuple(a UInt8, b String)").unwrap(),
+            DataTypeNode::NamedTuple(
+                vec!["a".to_string(), "b".to_string()],
+                vec![DataTypeNode::UInt8, DataTypeNode::String]
+            )
+        );
+        assert_eq!(
+            DataTypeNode::new("Tuple(`a b` Nullable(String), c Tuple(d DateTime('UTC')))").unwrap(),
+            DataTypeNode::NamedTuple(
+                vec!["a b".to_string(), "c".to_string()],
+                vec![
+                    DataTypeNode::Nullable(Box::new(DataTypeNode::String)),
+                    DataTypeNode::NamedTuple(
+                        vec!["d".to_string()],
+                        vec![DataTypeNode::DateTime(Some("UTC".to_string()))]
+                    )
+                ]
+            )
+        );
+        assert_eq!(
+            DataTypeNode::NamedTuple(
+                vec!["lat".to_string(), "1st".to_string()],
+                vec![DataTypeNode::Float64, DataTypeNode::Float64]
+            )
+            .to_string(),
+            "Tuple(lat Float64, `1st` Float64)"
+        );
+        assert!(DataTypeNode::new("Tuple(a Foo)").is_err());
+        assert!(DataTypeNode::new("Tuple(a UInt8, String)").is_err());
+        assert!(DataTypeNode::new("Tuple(UInt8, b String)").is_err());
+    }
+
+    #[test]
+    fn test_data_type_new_json() {
+        assert_eq!(
+            DataTypeNode::new(
+                "JSON(max_dynamic_paths=10, max_dynamic_types=3, a.b UInt32, \
+                 `c d` Array(Nullable(String)), SKIP x.y, SKIP `z,`, SKIP REGEXP 'r\\'.*')"
+            )
+            .unwrap(),
+            DataTypeNode::JSON(JsonType {
+                max_dynamic_paths: Some(10),
+                max_dynamic_types: Some(3),
+                typed_paths: vec![
+                    ("a.b".to_string(), DataTypeNode::UInt32),
+                    (
+                        "c d".to_string(),
+                        DataTypeNode::Array(Box::new(DataTypeNode::Nullable(Box::new(
+                            DataTypeNode::String
+                        ))))
+                    ),
+                ],
+                skip_paths: vec!["x.y".to_string(), "z,".to_string()],
+                skip_regexps: vec!["r'.*".to_string()],
+            })
+        );
+        assert!(DataTypeNode::new("JSON(").is_err());
+        assert!(DataTypeNode::new("JSON(a.b)").is_err());
+        assert!(DataTypeNode::new("JSON(a.b X)").is_err());
+        assert!(DataTypeNode::new("JSON(max_dynamic_paths=x)").is_err());
+    }
+
     #[test]
     fn test_data_type_new_enum() {
         assert_eq!(
@@ -1379,7 +1985,8 @@ mod tests {
         assert_eq!(DataTypeNode::IPv6.to_string(), "IPv6");
         assert_eq!(DataTypeNode::Bool.to_string(), "Bool");
         assert_eq!(DataTypeNode::Dynamic.to_string(), "Dynamic");
-        assert_eq!(DataTypeNode::JSON.to_string(), "JSON");
+        assert_eq!(DataTypeNode::JSON(JsonType::default()).to_string(), "JSON");
+        assert_eq!(DataTypeNode::Nothing.to_string(), "Nothing");
         assert_eq!(DataTypeNode::String.to_string(), "String");
     }
 
@@ -1549,6 +2156,36 @@ mod tests {
         assert_eq!(data_type_string, "Array(Int32)");
     }
 
+    #[test]
+    fn test_data_type_to_string_json() {
+        let json = JsonType {
+            max_dynamic_paths: Some(10),
+            max_dynamic_types: None,
+            typed_paths: vec![
+                ("a.b".to_string(), DataTypeNode::UInt32),
+                ("c d".to_string(), DataTypeNode::String),
+            ],
+            skip_paths: vec!["x".to_string()],
+            skip_regexps: vec!["r'.*".to_string()],
+        };
+        let str =
+            "JSON(max_dynamic_paths=10, a.b UInt32, `c d` String, SKIP x, SKIP REGEXP 'r\\'.*')";
+        assert_eq!(DataTypeNode::JSON(json.clone()).to_string(), str);
+        assert_eq!(DataTypeNode::new(str).unwrap(), DataTypeNode::JSON(json));
+
+        // Paths that look like parameters are quoted.
+        let json = JsonType {
+            typed_paths: vec![
+                ("max_dynamic_paths".to_string(), DataTypeNode::UInt32),
+                ("SKIP".to_string(), DataTypeNode::String),
+            ],
+            ..JsonType::default()
+        };
+        let str = "JSON(`max_dynamic_paths` UInt32, `SKIP` String)";
+        assert_eq!(DataTypeNode::JSON(json.clone()).to_string(), str);
+        assert_eq!(DataTypeNode::new(str).unwrap(), DataTypeNode::JSON(json));
+    }
+
     #[test]
     fn test_data_type_to_string_geo() {
         assert_eq!(DataTypeNode::Point.to_string(), "Point");
@@ -6,6 +6,7 @@
 //!
 //! Note that this crate is not intended for public usage,
 //! as it might introduce internal breaking changes not following semver.
+//! Use the `clickhouse::types` module instead, which re-exports the stable part of the API.
 
 pub use crate::data_types::{Column, DataTypeNode};
 use crate::decoders::read_string;
@@ -17,6 +18,8 @@ use bytes::{Buf, BufMut};
 pub use crate::leb128::put_leb128;
 pub use crate::leb128::read_leb128;
 
+/// Binary encoding of data types, used by the `Dynamic` and `JSON` data types.
+pub mod binary;
 /// ClickHouse data types AST and utilities to parse it from strings.
 pub mod data_types;
 /// Required decoders to parse the columns definitions from the header of the response.
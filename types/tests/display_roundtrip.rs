@@ -0,0 +1,225 @@
+//! Checks that every `DataTypeNode` is parsed back from its string representation.
+
+use std::collections::HashMap;
+
+use clickhouse_types::data_types::{
+    DataTypeNode, DateTimePrecision, DecimalType, EnumType, IntervalKind, JsonType,
+};
+use proptest::prelude::*;
+
+fn precision() -> impl Strategy<Value = DateTimePrecision> {
+    prop_oneof![
+        Just(DateTimePrecision::Precision0),
+        Just(DateTimePrecision::Precision1),
+        Just(DateTimePrecision::Precision2),
+        Just(DateTimePrecision::Precision3),
+        Just(DateTimePrecision::Precision4),
+        Just(DateTimePrecision::Precision5),
+        Just(DateTimePrecision::Precision6),
+        Just(DateTimePrecision::Precision7),
+        Just(DateTimePrecision::Precision8),
+        Just(DateTimePrecision::Precision9),
+    ]
+}
+
+fn timezone() -> impl Strategy<Value = Option<String>> {
+    proptest::option::of(
+        prop_oneof![
+            Just("UTC"),
+            Just("Europe/Amsterdam"),
+            Just("America/New_York")
+        ]
+        .prop_map(String::from),
+    )
+}
+
+fn decimal() -> impl Strategy<Value = DataTypeNode> {
+    (1u8..=76)
+        .prop_flat_map(|precision| (Just(precision), 0..=precision))
+        .prop_map(|(precision, scale)| {
+            let size = match precision {
+                1..=9 => DecimalType::Decimal32,
+                10..=18 => DecimalType::Decimal64,
+                19..=38 => DecimalType::Decimal128,
+                _ => DecimalType::Decimal256,
+            };
+            DataTypeNode::Decimal(precision, scale, size)
+        })
+}
+
+fn enum_name() -> impl Strategy<Value = String> {
+    // Names are stored escaped, the same way as they are written in the definition.
+    "[a-zA-Z0-9 ='(),`\\\\]{0,8}".prop_map(|name| name.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn enum_type() -> impl Strategy<Value = DataTypeNode> {
+    let enum8 = proptest::collection::hash_map(any::<i8>().prop_map(i16::from), enum_name(), 1..5)
+        .prop_map(|values| DataTypeNode::Enum(EnumType::Enum8, values));
+    let enum16 = proptest::collection::hash_map(any::<i16>(), enum_name(), 1..5)
+        .prop_map(|values| DataTypeNode::Enum(EnumType::Enum16, values));
+    prop_oneof![enum8, enum16]
+}
+
+fn interval() -> impl Strategy<Value = DataTypeNode> {
+    prop_oneof![
+        Just(IntervalKind::Nanosecond),
+        Just(IntervalKind::Microsecond),
+        Just(IntervalKind::Millisecond),
+        Just(IntervalKind::Second),
+        Just(IntervalKind::Minute),
+        Just(IntervalKind::Hour),
+        Just(IntervalKind::Day),
+        Just(IntervalKind::Week),
+        Just(IntervalKind::Month),
+        Just(IntervalKind::Quarter),
+        Just(IntervalKind::Year),
+    ]
+    .prop_map(DataTypeNode::Interval)
+}
+
+fn leaf() -> impl Strategy<Value = DataTypeNode> {
+    prop_oneof![
+        prop_oneof![
+            Just(DataTypeNode::Bool),
+            Just(DataTypeNode::UInt8),
+            Just(DataTypeNode::UInt16),
+            Just(DataTypeNode::UInt32),
+            Just(DataTypeNode::UInt64),
+            Just(DataTypeNode::UInt128),
+            Just(DataTypeNode::UInt256),
+            Just(DataTypeNode::Int8),
+            Just(DataTypeNode::Int16),
+            Just(DataTypeNode::Int32),
+            Just(DataTypeNode::Int64),
+            Just(DataTypeNode::Int128),
+            Just(DataTypeNode::Int256),
+            Just(DataTypeNode::Float32),
+            Just(DataTypeNode::Float64),
+            Just(DataTypeNode::BFloat16),
+        ],
+        prop_oneof![
+            Just(DataTypeNode::String),
+            Just(DataTypeNode::UUID),
+            Just(DataTypeNode::Date),
+            Just(DataTypeNode::Date32),
+            Just(DataTypeNode::Time),
+            Just(DataTypeNode::IPv4),
+            Just(DataTypeNode::IPv6),
+            Just(DataTypeNode::Dynamic),
+            Just(DataTypeNode::Nothing),
+            Just(DataTypeNode::Point),
+            Just(DataTypeNode::Ring),
+            Just(DataTypeNode::LineString),
+            Just(DataTypeNode::MultiLineString),
+            Just(DataTypeNode::Polygon),
+            Just(DataTypeNode::MultiPolygon),
+        ],
+        decimal(),
+        (1usize..=1024).prop_map(DataTypeNode::FixedString),
+        timezone().prop_map(DataTypeNode::DateTime),
+        (precision(), timezone()).prop_map(|(p, tz)| DataTypeNode::DateTime64(p, tz)),
+        precision().prop_map(DataTypeNode::Time64),
+        enum_type(),
+        interval(),
+        Just(DataTypeNode::Object("'json'".to_string())),
+    ]
+}
+
+fn json_path() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-z_][a-z0-9_.]{0,8}",
+        "[a-z .`\\\\]{0,8}",
+        Just("SKIP".to_string())
+    ]
+}
+
+fn json(inner: impl Strategy<Value = DataTypeNode>) -> impl Strategy<Value = DataTypeNode> {
+    (
+        proptest::option::of(any::<u64>()),
+        proptest::option::of(any::<u8>()),
+        proptest::collection::vec((json_path(), inner), 0..3),
+        proptest::collection::vec(json_path(), 0..3),
+        proptest::collection::vec("[a-z.*'\\\\]{0,6}", 0..2),
+    )
+        .prop_map(
+            |(max_dynamic_paths, max_dynamic_types, typed_paths, skip_paths, skip_regexps)| {
+                DataTypeNode::JSON(JsonType {
+                    max_dynamic_paths,
+                    max_dynamic_types,
+                    typed_paths,
+                    skip_paths,
+                    skip_regexps,
+                })
+            },
+        )
+}
+
+fn data_type() -> impl Strategy<Value = DataTypeNode> {
+    leaf().prop_recursive(4, 32, 4, |inner| {
+        let function = prop_oneof![
+            Just("sum"),
+            Just("count"),
+            Just("uniqExact"),
+            Just("quantiles(0.5, 0.9)"),
+            Just("sumIf"),
+        ];
+        prop_oneof![
+            inner
+                .clone()
+                .prop_map(|t| DataTypeNode::Nullable(Box::new(t))),
+            inner
+                .clone()
+                .prop_map(|t| DataTypeNode::LowCardinality(Box::new(t))),
+            inner.clone().prop_map(|t| DataTypeNode::Array(Box::new(t))),
+            proptest::collection::vec(inner.clone(), 1..4).prop_map(DataTypeNode::Tuple),
+            (inner.clone(), inner.clone())
+                .prop_map(|(k, v)| DataTypeNode::Map([Box::new(k), Box::new(v)])),
+            proptest::collection::vec(inner.clone(), 1..4).prop_map(DataTypeNode::Variant),
+            (
+                function.clone(),
+                proptest::collection::vec(inner.clone(), 0..3)
+            )
+                .prop_map(|(function, args)| DataTypeNode::AggregateFunction(
+                    function.to_string(),
+                    args
+                )),
+            (function, inner.clone()).prop_map(|(function, t)| {
+                DataTypeNode::SimpleAggregateFunction(function.to_string(), Box::new(t))
+            }),
+            proptest::collection::vec(("[a-z0-9 _`\\\\]{0,6}", inner.clone()), 1..4).prop_map(
+                |elements| {
+                    let (names, types) = elements.into_iter().unzip();
+                    DataTypeNode::Nested(names, types)
+                }
+            ),
+            proptest::collection::vec(("[a-z0-9 _`\\\\]{0,6}", inner.clone()), 1..4).prop_map(
+                |elements| {
+                    let (names, types) = elements.into_iter().unzip();
+                    DataTypeNode::NamedTuple(names, types)
+                }
+            ),
+            (inner.clone(), 1usize..=2048)
+                .prop_map(|(t, dimension)| DataTypeNode::QBit(Box::new(t), dimension)),
+            json(inner),
+        ]
+    })
+}
+
+proptest! {
+    #[test]
+    fn display_roundtrip(data_type in data_type()) {
+        let rendered = data_type.to_string();
+        prop_assert_eq!(DataTypeNode::new(&rendered).unwrap(), data_type, "{}", rendered);
+    }
+}
+
+#[test]
+fn display_roundtrip_enum_with_escaping() {
+    let data_type = DataTypeNode::Enum(
+        EnumType::Enum8,
+        HashMap::from([(-1, "it\\'s".to_string()), (2, "a\\\\b".to_string())]),
+    );
+    let rendered = data_type.to_string();
+    assert_eq!(rendered, "Enum8('it\\'s' = -1, 'a\\\\b' = 2)");
+    assert_eq!(DataTypeNode::new(&rendered).unwrap(), data_type);
+}